@@ -0,0 +1,56 @@
+use crate::pe::address::Address;
+use crate::x86::instruction::Instruction;
+
+/// A sequence of instructions that always execute one after the other: control enters from the
+/// first instruction and leaves from the last one
+#[derive(Clone, Debug, Default)]
+pub struct BasicBlock {
+    /// address of the first instruction
+    pub start: Address,
+    /// instructions of the block, sorted by address
+    pub instructions: Vec<Instruction>,
+}
+
+impl BasicBlock {
+    /// Creates a block with a single instruction
+    pub fn new(instruction: Instruction) -> Self {
+        Self {
+            start: instruction.address,
+            instructions: vec![instruction],
+        }
+    }
+
+    /// Address of the first byte after the last instruction
+    pub fn end(&self) -> Address {
+        self.instructions
+            .last()
+            .map_or(self.start, |instruction| instruction.next_address())
+    }
+
+    /// Last instruction of the block: the one that decides the successors
+    pub fn last_instruction(&self) -> Option<&Instruction> {
+        self.instructions.last()
+    }
+
+    /// Returns if an instruction of the block starts at the address
+    pub fn contains(&self, address: Address) -> bool {
+        self.instructions
+            .binary_search_by_key(&address, |instruction| instruction.address)
+            .is_ok()
+    }
+
+    /// Split the block before the instruction at `address`: the block keeps the instructions
+    /// before it, the returned block starts at `address`. Returns `None` if no instruction of the
+    /// block other than the first one starts at the address.
+    pub fn split_at(&mut self, address: Address) -> Option<BasicBlock> {
+        let index = self
+            .instructions
+            .binary_search_by_key(&address, |instruction| instruction.address)
+            .ok()
+            .filter(|index| *index > 0)?;
+        Some(BasicBlock {
+            start: address,
+            instructions: self.instructions.split_off(index),
+        })
+    }
+}
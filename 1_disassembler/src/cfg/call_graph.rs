@@ -0,0 +1,373 @@
+use crate::cfg::dot::escape;
+use crate::cfg::program::ProgramGraph;
+use crate::pe::address::Address;
+use crate::x86::branch::BranchKind;
+use anyhow::Result;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter, Write};
+use std::fs;
+use std::path::Path;
+
+/// A node of the call graph: something that can be called
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CallGraphNode {
+    /// a function found by the disassembler, identified by its entry
+    Function(Address),
+    /// a function imported from a DLL through the IAT
+    Import {
+        /// name of the DLL (e.g. KERNEL32.dll)
+        dll: String,
+        /// name of the function, or `#ordinal` for functions imported by ordinal
+        name: String,
+    },
+    /// an indirect call whose target is unknown, identified by the address of the call
+    Indirect(Address),
+}
+
+impl CallGraphNode {
+    /// Unique identifier of the node, used by the exporters
+    pub fn id(&self) -> String {
+        match self {
+            CallGraphNode::Function(address) => format!("0x{:x}", address),
+            CallGraphNode::Import { dll, name } => format!("{}!{}", dll, name),
+            CallGraphNode::Indirect(address) => format!("indirect_0x{:x}", address),
+        }
+    }
+
+    /// Returns if the node is the imported function with the name, whatever the DLL
+    pub fn is_import(&self, function_name: &str) -> bool {
+        matches!(self, CallGraphNode::Import { name, .. } if name == function_name)
+    }
+}
+
+impl Display for CallGraphNode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CallGraphNode::Function(address) => write!(f, "sub_{:x}", address),
+            CallGraphNode::Import { dll, name } => write!(f, "{}!{}", dll, name),
+            CallGraphNode::Indirect(address) => write!(f, "indirect call at 0x{:x}", address),
+        }
+    }
+}
+
+/// Calls from a function to a node of the call graph
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CallEdge {
+    /// entry of the calling function
+    pub caller: Address,
+    /// what is called
+    pub callee: CallGraphNode,
+    /// address of every instruction of the caller that performs the call, sorted
+    pub call_sites: Vec<Address>,
+}
+
+/// Paths of calls found by a query on the call graph
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CallPaths {
+    /// every path found, from the first function to the target
+    pub paths: Vec<Vec<CallGraphNode>>,
+    /// the search stopped at `MAXIMUM_PATHS` or `MAXIMUM_PATH_STEPS`: other paths may exist
+    pub truncated: bool,
+}
+
+/// Whole-program call graph: which function calls which function, imported function or unknown
+/// target. Tail calls (jumps to the entry of another function or to an IAT slot) are calls too.
+#[derive(Clone, Debug, Default)]
+pub struct CallGraph {
+    /// every node, functions included even when they call nothing
+    pub nodes: BTreeSet<CallGraphNode>,
+    /// calls, one edge for every caller and callee
+    pub edges: Vec<CallEdge>,
+}
+
+impl CallGraph {
+    /// Build the call graph from the control flow graph of every function
+    pub fn build(program: &ProgramGraph) -> Self {
+        let mut nodes = BTreeSet::new();
+        let mut calls = BTreeMap::<(Address, CallGraphNode), BTreeSet<Address>>::new();
+
+        for graph in program.functions.values() {
+            nodes.insert(CallGraphNode::Function(graph.entry));
+            let instructions = graph
+                .blocks
+                .values()
+                .flat_map(|block| block.instructions.iter());
+            for instruction in instructions {
+                let target = instruction.get_target().ok().flatten();
+                let import = instruction
+                    .import
+                    .as_ref()
+                    .map(|import| CallGraphNode::Import {
+                        dll: import.dll.clone(),
+                        name: import.name.to_string(),
+                    });
+                let callee = match instruction.branch_kind() {
+                    Some(BranchKind::CallDirect) => target.map(CallGraphNode::Function),
+                    Some(BranchKind::UnconditionalDirect) => target
+                        .filter(|target| {
+                            *target != graph.entry && program.functions.contains_key(target)
+                        })
+                        .map(CallGraphNode::Function),
+                    Some(BranchKind::CallIndirect) => {
+                        import.or(Some(CallGraphNode::Indirect(instruction.address)))
+                    }
+                    Some(BranchKind::UnconditionalIndirect) => import,
+                    _ => None,
+                };
+                if let Some(callee) = callee {
+                    nodes.insert(callee.clone());
+                    calls
+                        .entry((graph.entry, callee))
+                        .or_default()
+                        .insert(instruction.address);
+                }
+            }
+        }
+
+        let edges = calls
+            .into_iter()
+            .map(|((caller, callee), call_sites)| CallEdge {
+                caller,
+                callee,
+                call_sites: call_sites.into_iter().collect(),
+            })
+            .collect();
+        Self { nodes, edges }
+    }
+
+    /// Calls performed by a function
+    pub fn callees(&self, function: Address) -> impl Iterator<Item = &CallEdge> {
+        self.edges
+            .iter()
+            .filter(move |edge| edge.caller == function)
+    }
+
+    /// Calls that reach a node
+    pub fn callers<'a>(&'a self, node: &'a CallGraphNode) -> impl Iterator<Item = &'a CallEdge> {
+        self.edges.iter().filter(move |edge| edge.callee == *node)
+    }
+
+    /// Nodes of the functions imported with the name, from any DLL
+    pub fn imports<'a>(
+        &'a self,
+        function_name: &'a str,
+    ) -> impl Iterator<Item = &'a CallGraphNode> {
+        self.nodes
+            .iter()
+            .filter(move |node| node.is_import(function_name))
+    }
+
+    /// Every path of calls from the function at `from` to `to` that does not go through the same
+    /// function twice (e.g. from the entrypoint to `CreateProcessW`). Every path starts with
+    /// `from` and ends with `to`. At most `MAXIMUM_PATHS` paths are returned, and the search
+    /// stops after `MAXIMUM_PATH_STEPS` calls are followed: the result then tells that it is
+    /// truncated.
+    pub fn paths(&self, from: Address, to: &CallGraphNode) -> CallPaths {
+        let mut paths = CallPaths::default();
+        let reaching = self.reaching(to);
+        if !reaching.contains(&from) {
+            return paths;
+        }
+
+        // only the calls that can still reach the target are followed
+        let mut callees = BTreeMap::<Address, Vec<&CallGraphNode>>::new();
+        for edge in &self.edges {
+            let useful = edge.callee == *to
+                || matches!(edge.callee, CallGraphNode::Function(callee) if reaching.contains(&callee));
+            if useful && reaching.contains(&edge.caller) {
+                callees.entry(edge.caller).or_default().push(&edge.callee);
+            }
+        }
+
+        let mut path = vec![CallGraphNode::Function(from)];
+        let mut steps = 0;
+        Self::extend_paths(&callees, &mut path, to, &mut steps, &mut paths);
+        paths
+    }
+
+    /// Functions from which a chain of calls reaches the node
+    fn reaching(&self, to: &CallGraphNode) -> BTreeSet<Address> {
+        let mut callers = BTreeMap::<&CallGraphNode, Vec<Address>>::new();
+        for edge in &self.edges {
+            callers.entry(&edge.callee).or_default().push(edge.caller);
+        }
+
+        let mut reaching = BTreeSet::new();
+        let mut worklist = callers.get(to).cloned().unwrap_or_default();
+        while let Some(function) = worklist.pop() {
+            if reaching.insert(function) {
+                let node = CallGraphNode::Function(function);
+                worklist.extend(callers.get(&node).into_iter().flatten());
+            }
+        }
+        reaching
+    }
+
+    /// Depth-first search of the paths that continue `path` up to `to`, following `callees`
+    fn extend_paths(
+        callees: &BTreeMap<Address, Vec<&CallGraphNode>>,
+        path: &mut Vec<CallGraphNode>,
+        to: &CallGraphNode,
+        steps: &mut usize,
+        paths: &mut CallPaths,
+    ) {
+        let Some(CallGraphNode::Function(caller)) = path.last().cloned() else {
+            return;
+        };
+        for callee in callees.get(&caller).into_iter().flatten() {
+            if paths.paths.len() >= MAXIMUM_PATHS || *steps >= MAXIMUM_PATH_STEPS {
+                paths.truncated = true;
+                return;
+            }
+            *steps += 1;
+            if *callee == to {
+                let mut found = path.clone();
+                found.push(to.clone());
+                paths.paths.push(found);
+            } else if !path.contains(callee) {
+                path.push((*callee).clone());
+                Self::extend_paths(callees, path, to, steps, paths);
+                path.pop();
+            }
+        }
+    }
+
+    /// The call graph in the Graphviz DOT language: functions are boxes, imported functions are
+    /// blue ellipses, unresolved indirect calls are grey diamonds, and every edge is labeled
+    /// with its call sites. `labels` gives the names of known addresses.
+    pub fn to_dot(&self, labels: &BTreeMap<Address, String>) -> String {
+        let mut dot = String::new();
+        dot.push_str("digraph call_graph {\n");
+        dot.push_str("    node [fontname=\"monospace\"];\n");
+        for node in &self.nodes {
+            let style = match node {
+                CallGraphNode::Function(_) => "shape=box",
+                CallGraphNode::Import { .. } => "shape=ellipse, style=filled, fillcolor=lightblue",
+                CallGraphNode::Indirect(_) => "shape=diamond, style=filled, fillcolor=lightgrey",
+            };
+            let _ = writeln!(
+                dot,
+                "    \"{}\" [label=\"{}\", {}];",
+                escape(&node.id()),
+                escape(&Self::node_name(node, labels)),
+                style
+            );
+        }
+        for edge in &self.edges {
+            let call_sites = edge
+                .call_sites
+                .iter()
+                .map(|address| format!("0x{:x}", address))
+                .collect::<Vec<String>>()
+                .join("\\n");
+            let _ = writeln!(
+                dot,
+                "    \"0x{:x}\" -> \"{}\" [label=\"{}\"];",
+                edge.caller,
+                escape(&edge.callee.id()),
+                call_sites
+            );
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// The call graph in JSON: a list of nodes (`id`, `kind`, `name`, and `address` for
+    /// functions and indirect calls) and a list of edges (`caller`, `callee`, `call_sites`)
+    pub fn to_json(&self, labels: &BTreeMap<Address, String>) -> String {
+        let nodes = self
+            .nodes
+            .iter()
+            .map(|node| {
+                let (kind, address) = match node {
+                    CallGraphNode::Function(address) => ("function", Some(*address)),
+                    CallGraphNode::Import { .. } => ("import", None),
+                    CallGraphNode::Indirect(address) => ("indirect", Some(*address)),
+                };
+                let mut json = format!(
+                    "{{\"id\": \"{}\", \"kind\": \"{}\", \"name\": \"{}\"",
+                    json_escape(&node.id()),
+                    kind,
+                    json_escape(&Self::node_name(node, labels))
+                );
+                if let Some(address) = address {
+                    let _ = write!(json, ", \"address\": \"0x{:x}\"", address);
+                }
+                json.push('}');
+                json
+            })
+            .collect::<Vec<String>>();
+        let edges = self
+            .edges
+            .iter()
+            .map(|edge| {
+                let call_sites = edge
+                    .call_sites
+                    .iter()
+                    .map(|address| format!("\"0x{:x}\"", address))
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                format!(
+                    "{{\"caller\": \"0x{:x}\", \"callee\": \"{}\", \"call_sites\": [{}]}}",
+                    edge.caller,
+                    json_escape(&edge.callee.id()),
+                    call_sites
+                )
+            })
+            .collect::<Vec<String>>();
+
+        format!(
+            "{{\n  \"nodes\": [\n    {}\n  ],\n  \"edges\": [\n    {}\n  ]\n}}\n",
+            nodes.join(",\n    "),
+            edges.join(",\n    ")
+        )
+    }
+
+    /// Write the call graph in a DOT file
+    pub fn write_dot(&self, labels: &BTreeMap<Address, String>, path: &Path) -> Result<()> {
+        fs::write(path, self.to_dot(labels))?;
+        Ok(())
+    }
+
+    /// Write the call graph in a JSON file
+    pub fn write_json(&self, labels: &BTreeMap<Address, String>, path: &Path) -> Result<()> {
+        fs::write(path, self.to_json(labels))?;
+        Ok(())
+    }
+
+    /// Name of a node: functions use their label, if any
+    pub fn node_name(node: &CallGraphNode, labels: &BTreeMap<Address, String>) -> String {
+        match node {
+            CallGraphNode::Function(address) => labels
+                .get(address)
+                .cloned()
+                .unwrap_or_else(|| node.to_string()),
+            node => node.to_string(),
+        }
+    }
+}
+
+/// Upper bound for the number of paths returned by a query: the number of paths can grow
+/// exponentially with the number of functions
+pub const MAXIMUM_PATHS: usize = 1024;
+
+/// Upper bound for the number of calls followed by a path query, which bounds its time even when
+/// the functions that reach the target are densely connected
+pub const MAXIMUM_PATH_STEPS: usize = 1_000_000;
+
+/// Escape a string for a JSON string literal
+fn json_escape(text: &str) -> String {
+    let mut escaped = String::new();
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(escaped, "\\u{:04x}", c as u32);
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
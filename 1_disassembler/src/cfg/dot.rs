@@ -0,0 +1,143 @@
+use crate::cfg::edge::EdgeKind;
+use crate::cfg::graph::ControlFlowGraph;
+use crate::cfg::program::ProgramGraph;
+use crate::pe::address::Address;
+use anyhow::Result;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Writes control flow graphs in the Graphviz DOT language: every basic block is a node labeled
+/// with its disassembly, the entry block is green, blocks that leave the function are red, taken
+/// edges are green and not-taken edges are red. Calls and returns are not drawn.
+pub struct DotExporter {}
+
+impl DotExporter {
+    /// Graph of a single function. `labels` gives the names of known addresses (e.g. exports).
+    pub fn function(graph: &ControlFlowGraph, labels: &BTreeMap<Address, String>) -> String {
+        let mut dot = String::new();
+        let name = Self::function_name(graph.entry, labels);
+        let _ = writeln!(dot, "digraph \"{}\" {{", escape(&name));
+        let _ = writeln!(dot, "    label=\"{}\";", escape(&name));
+        let _ = writeln!(dot, "    labelloc=t;");
+        Self::write_body(&mut dot, graph, "", "    ");
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Graph of every function in a single file, with a cluster for each function
+    pub fn program(program: &ProgramGraph, labels: &BTreeMap<Address, String>) -> String {
+        let mut dot = String::new();
+        dot.push_str("digraph program {\n");
+        for graph in program.functions.values() {
+            let name = Self::function_name(graph.entry, labels);
+            let _ = writeln!(dot, "    subgraph \"cluster_{:x}\" {{", graph.entry);
+            let _ = writeln!(dot, "        label=\"{}\";", escape(&name));
+            // a block can belong to several functions: node names are unique per cluster
+            Self::write_body(&mut dot, graph, &format!("{:x}_", graph.entry), "        ");
+            dot.push_str("    }\n");
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Write one DOT file per function inside `directory`, named after the function. Returns
+    /// the paths of the files written.
+    pub fn write_functions(
+        program: &ProgramGraph,
+        labels: &BTreeMap<Address, String>,
+        directory: &Path,
+    ) -> Result<Vec<PathBuf>> {
+        fs::create_dir_all(directory)?;
+        let mut paths = vec![];
+        for graph in program.functions.values() {
+            let name = Self::function_name(graph.entry, labels)
+                .chars()
+                .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+                .collect::<String>();
+            let path = directory.join(format!("{}.dot", name));
+            fs::write(&path, Self::function(graph, labels))?;
+            paths.push(path);
+        }
+        Ok(paths)
+    }
+
+    /// Write the graph of every function in a single DOT file
+    pub fn write_program(
+        program: &ProgramGraph,
+        labels: &BTreeMap<Address, String>,
+        path: &Path,
+    ) -> Result<()> {
+        fs::write(path, Self::program(program, labels))?;
+        Ok(())
+    }
+
+    /// Nodes and edges of a function. Node names start with `prefix`.
+    fn write_body(dot: &mut String, graph: &ControlFlowGraph, prefix: &str, indent: &str) {
+        let _ = writeln!(
+            dot,
+            "{}node [shape=box, fontname=\"monospace\", style=filled, fillcolor=white];",
+            indent
+        );
+
+        let exits = graph
+            .exits()
+            .map(|block| block.start)
+            .collect::<Vec<Address>>();
+        for block in graph.blocks.values() {
+            // every line is left aligned
+            let mut label = String::new();
+            for instruction in &block.instructions {
+                let mut line = format!("0x{:x}: {}", instruction.address, instruction);
+                if let Some(import) = &instruction.import {
+                    let _ = write!(line, " ; {}", import);
+                }
+                label.push_str(&escape(&line));
+                label.push_str("\\l");
+            }
+
+            let fill = if block.start == graph.entry {
+                ", fillcolor=palegreen"
+            } else if exits.contains(&block.start) {
+                ", fillcolor=lightpink"
+            } else {
+                ""
+            };
+            let _ = writeln!(
+                dot,
+                "{}\"{}{:x}\" [label=\"{}\"{}];",
+                indent, prefix, block.start, label, fill
+            );
+        }
+
+        for edge in graph.edges.iter().filter(|edge| !edge.is_interprocedural()) {
+            let style = match edge.kind {
+                EdgeKind::Taken => " [color=darkgreen]",
+                EdgeKind::NotTaken => " [color=red]",
+                EdgeKind::JumpTableCase => " [color=blue, style=dashed]",
+                _ => "",
+            };
+            let _ = writeln!(
+                dot,
+                "{}\"{}{:x}\" -> \"{}{:x}\"{};",
+                indent, prefix, edge.source, prefix, edge.target, style
+            );
+        }
+    }
+
+    /// Name of the function: its label, if any, or its address
+    fn function_name(entry: Address, labels: &BTreeMap<Address, String>) -> String {
+        labels
+            .get(&entry)
+            .cloned()
+            .unwrap_or_else(|| format!("sub_{:x}", entry))
+    }
+}
+
+/// Escape a string for a quoted DOT identifier
+pub(crate) fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
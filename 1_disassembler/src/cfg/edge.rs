@@ -0,0 +1,61 @@
+use crate::pe::address::Address;
+use std::fmt::{Display, Formatter};
+
+/// Why control can move from a basic block to another
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EdgeKind {
+    /// the block ends without a branch (e.g. another block starts there) or with a call that
+    /// returns: the next block executes
+    FallThrough,
+    /// target of a jump: always for unconditional jumps, when the condition holds otherwise
+    Taken,
+    /// the condition of a conditional jump does not hold: the next block executes
+    NotTaken,
+    /// one of the targets of an indirect jump through a jump table
+    JumpTableCase,
+    /// from the block ending with a call to the first block of the callee
+    Call,
+    /// from a block ending with a return to the block that follows a call of the function
+    Return,
+}
+
+impl Display for EdgeKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            EdgeKind::FallThrough => "fall-through",
+            EdgeKind::Taken => "taken",
+            EdgeKind::NotTaken => "not-taken",
+            EdgeKind::JumpTableCase => "jump-table-case",
+            EdgeKind::Call => "call",
+            EdgeKind::Return => "return",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// An edge between two basic blocks, identified by their first address
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Edge {
+    /// first address of the block control leaves from
+    pub source: Address,
+    /// first address of the block control moves to
+    pub target: Address,
+    /// why control moves
+    pub kind: EdgeKind,
+}
+
+impl Edge {
+    /// Creates a new edge
+    pub fn new(source: Address, target: Address, kind: EdgeKind) -> Self {
+        Self {
+            source,
+            target,
+            kind,
+        }
+    }
+
+    /// Returns if the edge leaves the function: calls and returns
+    pub fn is_interprocedural(&self) -> bool {
+        matches!(self.kind, EdgeKind::Call | EdgeKind::Return)
+    }
+}
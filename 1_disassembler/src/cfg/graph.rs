@@ -0,0 +1,224 @@
+use crate::cfg::basic_block::BasicBlock;
+use crate::cfg::edge::{Edge, EdgeKind};
+use crate::disassembler::disassembly::Disassembly;
+use crate::pe::address::Address;
+use crate::x86::branch::BranchKind;
+use crate::x86::instruction::Instruction;
+use std::collections::BTreeMap;
+
+/// Control flow graph of a function: the basic blocks reachable from its entry without going
+/// through calls, and the edges between them. Calls and returns are also edges, but their
+/// target belongs to another function.
+#[derive(Clone, Debug, Default)]
+pub struct ControlFlowGraph {
+    /// first address of the function
+    pub entry: Address,
+    /// basic blocks, keyed by their first address
+    pub blocks: BTreeMap<Address, BasicBlock>,
+    /// edges that leave the blocks of the function
+    pub edges: Vec<Edge>,
+}
+
+impl ControlFlowGraph {
+    /// Build the graph of the function starting at `entry` from the instructions found by the
+    /// disassembler, keyed by address
+    pub fn build(
+        entry: Address,
+        instructions: &BTreeMap<Address, &Instruction>,
+        disassembly: &Disassembly,
+    ) -> Self {
+        let mut graph = Self {
+            entry,
+            blocks: BTreeMap::new(),
+            edges: vec![],
+        };
+
+        let mut worklist = vec![entry];
+        while let Some(start) = worklist.pop() {
+            if graph.blocks.contains_key(&start) || graph.split_block(start) {
+                continue;
+            }
+            let Some(first) = instructions.get(&start) else {
+                continue;
+            };
+
+            let mut block = BasicBlock::new((*first).clone());
+            let edges = loop {
+                let Some(instruction) = block.last_instruction() else {
+                    break vec![];
+                };
+                if let Some(edges) = graph.block_end(start, instruction, instructions, disassembly)
+                {
+                    break edges;
+                }
+
+                // straight-line code: the block goes on until another block or function starts
+                let next = instruction.next_address();
+                match instructions.get(&next) {
+                    Some(_) if graph.block_containing(next).is_some() => {
+                        graph.split_block(next);
+                        break vec![Edge::new(start, next, EdgeKind::FallThrough)];
+                    }
+                    Some(_) if disassembly.functions.contains(&next) => break vec![],
+                    Some(next_instruction) => block.instructions.push((*next_instruction).clone()),
+                    None => break vec![],
+                }
+            };
+
+            graph.blocks.insert(start, block);
+            for edge in edges {
+                if !edge.is_interprocedural() {
+                    worklist.push(edge.target);
+                }
+                graph.edges.push(edge);
+            }
+        }
+
+        graph
+    }
+
+    /// Edges that leave the block starting at `source` and ending with `instruction`, if the
+    /// instruction ends a block. Edges inside the function only reach instructions that were
+    /// disassembled.
+    fn block_end(
+        &self,
+        source: Address,
+        instruction: &Instruction,
+        instructions: &BTreeMap<Address, &Instruction>,
+        disassembly: &Disassembly,
+    ) -> Option<Vec<Edge>> {
+        let next = instruction.next_address();
+        let target = instruction.get_target().ok().flatten();
+        let local = |address: &Address| instructions.contains_key(address);
+
+        let mut edges = vec![];
+        match instruction.branch_kind()? {
+            BranchKind::Conditional => {
+                edges.extend(
+                    target
+                        .filter(local)
+                        .map(|target| Edge::new(source, target, EdgeKind::Taken)),
+                );
+                edges.extend(
+                    Some(next)
+                        .filter(local)
+                        .map(|next| Edge::new(source, next, EdgeKind::NotTaken)),
+                );
+            }
+            BranchKind::UnconditionalDirect => {
+                // a jump to the entry of another function is a tail call
+                let kind = match target {
+                    Some(target)
+                        if target != self.entry && disassembly.functions.contains(&target) =>
+                    {
+                        EdgeKind::Call
+                    }
+                    _ => EdgeKind::Taken,
+                };
+                edges.extend(
+                    target
+                        .filter(local)
+                        .map(|target| Edge::new(source, target, kind)),
+                );
+            }
+            BranchKind::CallDirect | BranchKind::CallIndirect => {
+                edges.extend(target.map(|target| Edge::new(source, target, EdgeKind::Call)));
+                if !disassembly.no_return_calls.contains(&instruction.address) {
+                    edges.extend(
+                        Some(next)
+                            .filter(local)
+                            .map(|next| Edge::new(source, next, EdgeKind::FallThrough)),
+                    );
+                }
+            }
+            BranchKind::UnconditionalIndirect => {
+                if let Some(jump_table) = disassembly.jump_tables.get(&instruction.address) {
+                    edges.extend(
+                        jump_table
+                            .cases()
+                            .into_iter()
+                            .filter(local)
+                            .map(|case| Edge::new(source, case, EdgeKind::JumpTableCase)),
+                    );
+                }
+            }
+            // system calls and breakpoints resume with the next instruction
+            BranchKind::Interrupt if instruction.falls_through() => return None,
+            BranchKind::Return | BranchKind::Interrupt | BranchKind::Halt => {}
+        }
+        Some(edges)
+    }
+
+    /// Split the block that contains an instruction starting at `address`, so that a block
+    /// starts there: the edges that left the block now leave the second half, and the first
+    /// half falls through to it. Returns if a block was split.
+    pub fn split_block(&mut self, address: Address) -> bool {
+        let Some((start, block)) = self.blocks.range_mut(..address).next_back() else {
+            return false;
+        };
+        let start = *start;
+        let Some(second_half) = block.split_at(address) else {
+            return false;
+        };
+
+        self.blocks.insert(address, second_half);
+        for edge in self.edges.iter_mut().filter(|edge| edge.source == start) {
+            edge.source = address;
+        }
+        self.edges
+            .push(Edge::new(start, address, EdgeKind::FallThrough));
+        true
+    }
+
+    /// Add an edge that leaves the block starting at `edge.source` (e.g. a case of a jump
+    /// table). A block is split if the target of an edge inside the function lands in its
+    /// middle.
+    pub fn add_edge(&mut self, edge: Edge) {
+        if !edge.is_interprocedural() {
+            self.split_block(edge.target);
+        }
+        if !self.edges.contains(&edge) {
+            self.edges.push(edge);
+        }
+    }
+
+    /// Returns the block that contains an instruction starting at the address
+    pub fn block_containing(&self, address: Address) -> Option<&BasicBlock> {
+        self.blocks
+            .range(..=address)
+            .next_back()
+            .map(|(_, block)| block)
+            .filter(|block| block.contains(address))
+    }
+
+    /// Edges that leave the block starting at `block`
+    pub fn successors(&self, block: Address) -> impl Iterator<Item = &Edge> {
+        self.edges.iter().filter(move |edge| edge.source == block)
+    }
+
+    /// Edges that reach the block starting at `block` from a block of the function
+    pub fn predecessors(&self, block: Address) -> impl Iterator<Item = &Edge> {
+        self.edges
+            .iter()
+            .filter(move |edge| edge.target == block && self.blocks.contains_key(&edge.source))
+    }
+
+    /// Blocks that leave the function: returns, tail calls, calls that do not return and
+    /// indirect jumps whose targets are unknown
+    pub fn exits(&self) -> impl Iterator<Item = &BasicBlock> {
+        self.blocks.values().filter(|block| {
+            !self
+                .successors(block.start)
+                .any(|edge| !edge.is_interprocedural())
+        })
+    }
+
+    /// Blocks that end with a return to the caller
+    pub fn return_blocks(&self) -> impl Iterator<Item = &BasicBlock> {
+        self.blocks.values().filter(|block| {
+            block
+                .last_instruction()
+                .is_some_and(|instruction| instruction.is_ret())
+        })
+    }
+}
@@ -0,0 +1,12 @@
+/// Exports basic blocks
+pub mod basic_block;
+/// Exports the whole-program call graph
+pub mod call_graph;
+/// Exports the Graphviz DOT export of control flow graphs
+pub mod dot;
+/// Exports the edges between basic blocks
+pub mod edge;
+/// Exports the control flow graph of functions
+pub mod graph;
+/// Exports the control flow graphs of every function
+pub mod program;
@@ -0,0 +1,79 @@
+use crate::cfg::edge::{Edge, EdgeKind};
+use crate::cfg::graph::ControlFlowGraph;
+use crate::disassembler::disassembly::Disassembly;
+use crate::pe::address::Address;
+use std::collections::BTreeMap;
+
+/// Control flow graphs of every function found by the disassembler, linked by call and return
+/// edges
+#[derive(Clone, Debug, Default)]
+pub struct ProgramGraph {
+    /// graph of every function, keyed by its entry
+    pub functions: BTreeMap<Address, ControlFlowGraph>,
+}
+
+impl ProgramGraph {
+    /// Build the graph of every function of the disassembly. Every block that returns from a
+    /// function gets a return edge to the block that follows each call of the function.
+    pub fn build(disassembly: &Disassembly) -> Self {
+        let instructions = disassembly.instructions_by_address();
+        let mut functions = disassembly
+            .functions
+            .iter()
+            .filter(|entry| instructions.contains_key(entry))
+            .map(|entry| {
+                (
+                    *entry,
+                    ControlFlowGraph::build(*entry, &instructions, disassembly),
+                )
+            })
+            .collect::<BTreeMap<Address, ControlFlowGraph>>();
+
+        // the block that follows a call is where the callee returns
+        let mut return_sites = vec![];
+        for graph in functions.values() {
+            for call in graph
+                .edges
+                .iter()
+                .filter(|edge| edge.kind == EdgeKind::Call)
+            {
+                return_sites.extend(
+                    graph
+                        .successors(call.source)
+                        .filter(|edge| edge.kind == EdgeKind::FallThrough)
+                        .map(|edge| (call.target, edge.target)),
+                );
+            }
+        }
+        for (callee, return_site) in return_sites {
+            let Some(graph) = functions.get_mut(&callee) else {
+                continue;
+            };
+            let returns = graph
+                .return_blocks()
+                .map(|block| Edge::new(block.start, return_site, EdgeKind::Return))
+                .collect::<Vec<Edge>>();
+            for edge in returns {
+                graph.add_edge(edge);
+            }
+        }
+
+        Self { functions }
+    }
+
+    /// Graph of the function whose entry is the address
+    pub fn function(&self, entry: Address) -> Option<&ControlFlowGraph> {
+        self.functions.get(&entry)
+    }
+
+    /// Graphs of the functions with a block that contains an instruction starting at the
+    /// address: code can be shared by several functions
+    pub fn functions_containing(
+        &self,
+        address: Address,
+    ) -> impl Iterator<Item = &ControlFlowGraph> {
+        self.functions
+            .values()
+            .filter(move |graph| graph.block_containing(address).is_some())
+    }
+}
@@ -0,0 +1,149 @@
+use crate::disassembler::disassemble::MAXIMUM_SIZE_X86_INSTR;
+use crate::pe::address::Address;
+use crate::x86::instruction::Instruction;
+use crate::x86::opcode::X86Opcode;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+/// A trick used to confuse disassemblers, found among the instructions disassembled
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AntiDisassembly {
+    /// a branch lands inside another instruction: the bytes are decoded twice, from both
+    /// addresses, and both decodings are kept
+    OverlappingInstruction {
+        /// address of the branch
+        branch: Address,
+        /// target of the branch
+        target: Address,
+        /// first byte of the instruction the target lands in
+        instruction: Address,
+    },
+    /// two conditional jumps with opposite conditions to the same target, one after the other:
+    /// together they always jump, and the bytes after them are often not code
+    ComplementaryJumps {
+        /// address of the first jump
+        first: Address,
+        /// address of the second jump
+        second: Address,
+        /// target of both jumps
+        target: Address,
+    },
+    /// `call $+5` followed by `pop reg`: the call pushes the address of the next instruction to
+    /// read it, there is no function to call
+    CallPop {
+        /// address of the call
+        call: Address,
+        /// address of the pop that reads the return address
+        pop: Address,
+    },
+}
+
+impl AntiDisassembly {
+    /// Look for every known trick among the instructions, keyed by address. Overlapping
+    /// instructions are expected in the map, since every decoding is kept.
+    pub fn detect(instructions: &BTreeMap<Address, &Instruction>) -> Vec<Self> {
+        let mut found = vec![];
+        for instruction in instructions.values() {
+            let Some(target) = instruction.get_target().ok().flatten() else {
+                continue;
+            };
+
+            // the closest instruction before the target that covers it
+            let covering = instructions
+                .range(..target)
+                .rev()
+                .take(MAXIMUM_SIZE_X86_INSTR)
+                .find(|(_, covering)| target < covering.next_address());
+            if let Some((address, _)) = covering {
+                found.push(Self::OverlappingInstruction {
+                    branch: instruction.address,
+                    target,
+                    instruction: *address,
+                });
+            }
+
+            let next = instructions.get(&instruction.next_address());
+            if let Some(next) = next.filter(|next| Self::is_complementary(instruction, next)) {
+                found.push(Self::ComplementaryJumps {
+                    first: instruction.address,
+                    second: next.address,
+                    target,
+                });
+            }
+
+            if instruction.is_call() && target == instruction.next_address() {
+                if let Some(pop) = next.filter(|next| next.opcode == X86Opcode::Pop) {
+                    found.push(Self::CallPop {
+                        call: instruction.address,
+                        pop: pop.address,
+                    });
+                }
+            }
+        }
+        found
+    }
+
+    /// Returns if the second instruction is a conditional jump right after the first one, to
+    /// the same target, with the opposite condition: one of them is always taken
+    pub fn is_complementary(first: &Instruction, second: &Instruction) -> bool {
+        let target = |instruction: &Instruction| instruction.get_target().ok().flatten();
+        first.next_address() == second.address
+            && target(first).is_some()
+            && target(first) == target(second)
+            && OPPOSITE_JUMPS.iter().any(|(jump, opposite)| {
+                (first.opcode, second.opcode) == (*jump, *opposite)
+                    || (first.opcode, second.opcode) == (*opposite, *jump)
+            })
+    }
+
+    /// Address of the instruction that starts the trick
+    pub fn address(&self) -> Address {
+        match self {
+            Self::OverlappingInstruction { branch, .. } => *branch,
+            Self::ComplementaryJumps { first, .. } => *first,
+            Self::CallPop { call, .. } => *call,
+        }
+    }
+}
+
+impl Display for AntiDisassembly {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::OverlappingInstruction {
+                branch,
+                target,
+                instruction,
+            } => write!(
+                f,
+                "0x{:x}: branch to 0x{:x}, inside the instruction at 0x{:x}",
+                branch, target, instruction
+            ),
+            Self::ComplementaryJumps {
+                first,
+                second,
+                target,
+            } => write!(
+                f,
+                "0x{:x}: jump with the opposite condition at 0x{:x}, both to 0x{:x}",
+                first, second, target
+            ),
+            Self::CallPop { call, pop } => write!(
+                f,
+                "0x{:x}: call to the next instruction, whose address is read by the pop at 0x{:x}",
+                call, pop
+            ),
+        }
+    }
+}
+
+/// Conditional jumps and the jump with the opposite condition
+const OPPOSITE_JUMPS: [(X86Opcode, X86Opcode); 8] = [
+    (X86Opcode::Je, X86Opcode::Jne),
+    (X86Opcode::Jb, X86Opcode::Jae),
+    (X86Opcode::Jbe, X86Opcode::Ja),
+    (X86Opcode::Jl, X86Opcode::Jge),
+    (X86Opcode::Jle, X86Opcode::Jg),
+    (X86Opcode::Jo, X86Opcode::Jno),
+    (X86Opcode::Js, X86Opcode::Jns),
+    (X86Opcode::Jp, X86Opcode::Jnp),
+];
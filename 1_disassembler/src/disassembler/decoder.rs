@@ -0,0 +1,253 @@
+use crate::pe::address::Address;
+use crate::x86::instruction::Instruction;
+use crate::x86::memory::MemoryOperand;
+use crate::x86::opcode::X86Opcode;
+use crate::x86::operands::X86Operand;
+use crate::x86::prefix::X86Prefix;
+use crate::x86::register::X86Register;
+use anyhow::Result;
+use iced_x86::{Code, Decoder, DecoderOptions, Mnemonic, OpKind, Register};
+
+#[cfg(feature = "nyxstone")]
+use crate::disassembler::instruction_parser::InstructionParser;
+#[cfg(feature = "nyxstone")]
+use nyxstone::{Nyxstone, NyxstoneConfig};
+
+/// How raw bytes are turned into instructions
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DecoderBackend {
+    /// Decode the bytes directly (prefixes, ModRM, SIB, displacement, immediates) into an
+    /// instruction: every valid x86-64 instruction is represented
+    #[default]
+    Structured,
+    /// Disassemble the bytes to assembly text with LLVM and parse the text back with the pest
+    /// grammar: only the mnemonics and registers known by the grammar are represented
+    #[cfg(feature = "nyxstone")]
+    Text,
+}
+
+/// Decodes one x86-64 instruction at a time with the selected backend
+#[derive(Default)]
+pub struct InstructionDecoder {
+    #[cfg(feature = "nyxstone")]
+    nyxstone: Option<Nyxstone>,
+}
+
+impl InstructionDecoder {
+    /// Creates a new decoder for the backend
+    pub fn new(backend: DecoderBackend) -> Result<Self> {
+        match backend {
+            DecoderBackend::Structured => Ok(Self {
+                #[cfg(feature = "nyxstone")]
+                nyxstone: None,
+            }),
+            #[cfg(feature = "nyxstone")]
+            DecoderBackend::Text => Ok(Self {
+                nyxstone: Some(Nyxstone::new("x86_64", NyxstoneConfig::default())?),
+            }),
+        }
+    }
+
+    /// Decode the instruction that starts at the first byte of the buffer, located at `address`.
+    /// Returns `None` when the bytes are not a valid instruction.
+    pub fn decode(&self, bytes: &[u8], address: Address) -> Option<Instruction> {
+        #[cfg(feature = "nyxstone")]
+        if let Some(nyxstone) = &self.nyxstone {
+            return Self::decode_text(nyxstone, bytes, address);
+        }
+
+        Self::decode_structured(bytes, address)
+    }
+
+    /// Decode the bytes without going through a textual representation
+    fn decode_structured(bytes: &[u8], address: Address) -> Option<Instruction> {
+        let mut decoder = Decoder::with_ip(64, bytes, address, DecoderOptions::NONE);
+        let decoded = decoder.decode();
+        if decoded.is_invalid() {
+            return None;
+        }
+
+        let operands = (0..decoded.op_count())
+            .filter_map(|index| Self::operand(&decoded, index))
+            .collect::<Vec<X86Operand>>();
+        let mut instruction =
+            Instruction::new(address, Self::opcode(&decoded), operands, decoded.len());
+        instruction.prefixes = Self::prefixes(&decoded);
+        Some(instruction)
+    }
+
+    /// Opcode of a decoded instruction
+    fn opcode(decoded: &iced_x86::Instruction) -> X86Opcode {
+        // moves of 64-bit immediates and of absolute 64-bit memory offsets are known as movabs
+        let is_movabs = matches!(
+            decoded.code(),
+            Code::Mov_r64_imm64
+                | Code::Mov_AL_moffs8
+                | Code::Mov_AX_moffs16
+                | Code::Mov_EAX_moffs32
+                | Code::Mov_RAX_moffs64
+                | Code::Mov_moffs8_AL
+                | Code::Mov_moffs16_AX
+                | Code::Mov_moffs32_EAX
+                | Code::Mov_moffs64_RAX
+        );
+        if is_movabs {
+            return X86Opcode::Movabs;
+        }
+
+        X86Opcode::from_iced(decoded.mnemonic())
+    }
+
+    /// Prefixes of a decoded instruction: repeat prefixes are only meaningful for string
+    /// instructions, elsewhere they are mandatory prefixes that select the opcode
+    fn prefixes(decoded: &iced_x86::Instruction) -> Vec<X86Prefix> {
+        let mut prefixes = vec![];
+        if decoded.has_lock_prefix() {
+            prefixes.push(X86Prefix::Lock);
+        }
+        if decoded.is_string_instruction() {
+            let compares = matches!(
+                decoded.mnemonic(),
+                Mnemonic::Cmpsb
+                    | Mnemonic::Cmpsw
+                    | Mnemonic::Cmpsd
+                    | Mnemonic::Cmpsq
+                    | Mnemonic::Scasb
+                    | Mnemonic::Scasw
+                    | Mnemonic::Scasd
+                    | Mnemonic::Scasq
+            );
+            if decoded.has_repne_prefix() {
+                prefixes.push(X86Prefix::Repne);
+            } else if decoded.has_repe_prefix() && compares {
+                prefixes.push(X86Prefix::Repe);
+            } else if decoded.has_rep_prefix() {
+                prefixes.push(X86Prefix::Rep);
+            }
+        }
+        prefixes
+    }
+
+    /// Operand of a decoded instruction
+    fn operand(decoded: &iced_x86::Instruction, index: u32) -> Option<X86Operand> {
+        let operand = match decoded.op_kind(index) {
+            OpKind::Register => Self::register(decoded.op_register(index))?,
+            // the displacement of a relative branch is encoded from the end of the instruction
+            OpKind::NearBranch16 | OpKind::NearBranch32 | OpKind::NearBranch64 => {
+                let target = decoded.near_branch_target();
+                X86Operand::Relative(target.wrapping_sub(decoded.next_ip()) as i64)
+            }
+            OpKind::FarBranch16 => X86Operand::Immediate(decoded.far_branch16() as i128),
+            OpKind::FarBranch32 => X86Operand::Immediate(decoded.far_branch32() as i128),
+            OpKind::Immediate8
+            | OpKind::Immediate8_2nd
+            | OpKind::Immediate16
+            | OpKind::Immediate32
+            | OpKind::Immediate64 => X86Operand::Immediate(decoded.immediate(index) as i128),
+            // sign extended immediates
+            OpKind::Immediate8to16
+            | OpKind::Immediate8to32
+            | OpKind::Immediate8to64
+            | OpKind::Immediate32to64 => {
+                X86Operand::Immediate(decoded.immediate(index) as i64 as i128)
+            }
+            // implicit operands of string instructions: the destination is always in es, the
+            // segment of the source can be overridden
+            OpKind::MemorySegSI
+            | OpKind::MemorySegESI
+            | OpKind::MemorySegRSI
+            | OpKind::MemoryESDI
+            | OpKind::MemoryESEDI
+            | OpKind::MemoryESRDI
+            | OpKind::MemorySegDI
+            | OpKind::MemorySegEDI
+            | OpKind::MemorySegRDI => {
+                let (segment, base) = match decoded.op_kind(index) {
+                    OpKind::MemorySegSI => (decoded.segment_prefix(), Register::SI),
+                    OpKind::MemorySegESI => (decoded.segment_prefix(), Register::ESI),
+                    OpKind::MemorySegRSI => (decoded.segment_prefix(), Register::RSI),
+                    OpKind::MemorySegDI => (decoded.segment_prefix(), Register::DI),
+                    OpKind::MemorySegEDI => (decoded.segment_prefix(), Register::EDI),
+                    OpKind::MemorySegRDI => (decoded.segment_prefix(), Register::RDI),
+                    OpKind::MemoryESDI => (Register::ES, Register::DI),
+                    OpKind::MemoryESEDI => (Register::ES, Register::EDI),
+                    _ => (Register::ES, Register::RDI),
+                };
+                let mut memory =
+                    MemoryOperand::new(Self::x86_register(base), 0, Self::memory_size(decoded));
+                memory.segment = Self::x86_register(segment);
+                X86Operand::Memory(memory)
+            }
+            OpKind::Memory => X86Operand::Memory(Self::memory(decoded)),
+        };
+        Some(operand)
+    }
+
+    /// Explicit memory operand of a decoded instruction
+    fn memory(decoded: &iced_x86::Instruction) -> MemoryOperand {
+        let rip_relative = decoded.is_ip_rel_memory_operand();
+        let displacement = if rip_relative {
+            // the decoder resolves RIP-relative operands, we keep the encoded displacement (32
+            // bits, also when the address is truncated to eip)
+            decoded
+                .ip_rel_memory_address()
+                .wrapping_sub(decoded.next_ip()) as i32 as i64
+        } else {
+            decoded.memory_displacement64() as i64
+        };
+
+        MemoryOperand {
+            segment: Self::x86_register(decoded.segment_prefix()),
+            base: Self::x86_register(decoded.memory_base()),
+            index: Self::x86_register(decoded.memory_index()),
+            scale: decoded.memory_index_scale() as u8,
+            displacement,
+            size: Self::memory_size(decoded),
+            rip_relative,
+        }
+    }
+
+    /// Size in bits of the memory accessed by a decoded instruction
+    fn memory_size(decoded: &iced_x86::Instruction) -> u64 {
+        decoded.memory_size().size() as u64 * 8
+    }
+
+    /// Register operand
+    fn register(register: Register) -> Option<X86Operand> {
+        Self::x86_register(register).map(X86Operand::Register)
+    }
+
+    /// Translate a register of the decoder. Registers that do not exist in 64-bit mode (e.g. test
+    /// registers) have no translation.
+    fn x86_register(register: Register) -> Option<X86Register> {
+        X86Register::from_iced(register)
+    }
+
+    /// Decode the bytes with LLVM and parse the assembly text. Text that the grammar does not
+    /// understand is reported and kept as an instruction with an invalid opcode, so that the
+    /// disassembler still moves past it.
+    #[cfg(feature = "nyxstone")]
+    fn decode_text(nyxstone: &Nyxstone, bytes: &[u8], address: Address) -> Option<Instruction> {
+        let decoded = nyxstone
+            .disassemble_to_instructions(bytes, address, 1)
+            .ok()?;
+        let decoded = decoded.first()?;
+        match InstructionParser::parse(&decoded.assembly, decoded.address, decoded.bytes.len()) {
+            Ok(instruction) => Some(instruction),
+            Err(error) => {
+                log::warn!(
+                    "Cannot parse {:?} at 0x{:x}: {}",
+                    decoded.assembly,
+                    address,
+                    error
+                );
+                Some(Instruction::new(
+                    address,
+                    X86Opcode::Invalid,
+                    vec![],
+                    decoded.bytes.len(),
+                ))
+            }
+        }
+    }
+}
@@ -1,13 +1,23 @@
-use crate::disassembler::instruction_parser::InstructionParser;
-use crate::pe::address::Address;
+use crate::disassembler::anti_disassembly::AntiDisassembly;
+use crate::disassembler::decoder::InstructionDecoder;
+use crate::disassembler::disassembly::Disassembly;
+use crate::disassembler::function_finder::{FunctionCandidate, FunctionFinder};
+use crate::disassembler::hybrid::GapScanner;
+use crate::disassembler::pointer_scan::{DataReference, PointerScanner};
+use crate::disassembler::recursive_traversal::RecursiveTraversal;
+use crate::disassembler::superset::{Superset, ACCEPTANCE_PROBABILITY};
+use crate::disassembler::xrefs::XrefDatabase;
+use crate::pe::address::{Address, VirtualAddress};
 use crate::pe::file_read::FileRead;
+use crate::pe::relocations::RelocationType;
+use crate::pe::section::ExecutableRegion;
+use crate::x86::branch::BranchKind;
 use crate::x86::instruction::Instruction;
-use crate::x86::opcode::X86Opcode;
-use anyhow::{Result};
-use std::io::{Cursor, Read, Seek, SeekFrom};
-use nyxstone::{Nyxstone, NyxstoneConfig};
+use crate::x86::operands::X86Operand;
+use anyhow::Result;
+use std::collections::{BTreeMap, BTreeSet};
 
-/// Implements linear sweep and recursive traversal disassembler.
+/// Implements linear sweep, recursive traversal, hybrid and superset disassembler.
 pub struct Disassembler {}
 
 impl Disassembler {
@@ -15,49 +25,146 @@ impl Disassembler {
     pub fn extract(
         file_read: FileRead,
         disassembler_strategy: DisassemblerStrategy,
-    ) -> Result<Vec<Instruction>> {
-        let nyxstone = Nyxstone::new("x86_64", NyxstoneConfig::default())?;
-        match disassembler_strategy {
-            DisassemblerStrategy::LinearSweep => Self::extract_linear(file_read, &nyxstone),
-            DisassemblerStrategy::RecursiveTraversal => Self::extract_recursive(file_read, &nyxstone),
+    ) -> Result<Disassembly> {
+        Self::extract_with_decoder(
+            file_read,
+            disassembler_strategy,
+            &InstructionDecoder::default(),
+        )
+    }
+
+    /// Extract the instructions, decoding them with a specific decoder
+    pub fn extract_with_decoder(
+        file_read: FileRead,
+        disassembler_strategy: DisassemblerStrategy,
+        decoder: &InstructionDecoder,
+    ) -> Result<Disassembly> {
+        Self::extract_with_options(
+            file_read,
+            disassembler_strategy,
+            decoder,
+            DisassemblerOptions::default(),
+        )
+    }
+
+    /// Extract the instructions, decoding them with a specific decoder and with options that
+    /// apply to every strategy
+    pub fn extract_with_options(
+        file_read: FileRead,
+        disassembler_strategy: DisassemblerStrategy,
+        decoder: &InstructionDecoder,
+        options: DisassemblerOptions,
+    ) -> Result<Disassembly> {
+        // the hybrid strategy scores the gaps with the pointers, whatever the options
+        let scan_data_pointers = options.scan_data_pointers
+            || options.seed_data_pointers
+            || matches!(disassembler_strategy, DisassemblerStrategy::Hybrid);
+        let data_references = if scan_data_pointers {
+            PointerScanner::new(&file_read, decoder).scan()
+        } else {
+            vec![]
+        };
+        let seeds = if options.seed_data_pointers {
+            data_references
+                .iter()
+                .map(|reference| reference.target)
+                .collect()
+        } else {
+            BTreeSet::new()
+        };
+
+        // the tables of the binary name addresses, e.g. for the cross-references
+        let imports = file_read
+            .imports
+            .iter()
+            .map(|import| {
+                let slot = file_read.translator.rva_to_va(import.iat_slot).0;
+                (slot, import.clone())
+            })
+            .collect();
+        let exports = file_read
+            .exported_functions()
+            .map(|(address, export)| (address, export.clone()))
+            .collect();
+
+        // superset candidates overlap by design
+        let detect_tricks = !matches!(disassembler_strategy, DisassemblerStrategy::Superset);
+        let mut disassembly = match disassembler_strategy {
+            DisassemblerStrategy::LinearSweep => Self::extract_linear(file_read, decoder),
+            DisassemblerStrategy::RecursiveTraversal => {
+                Self::extract_recursive(file_read, decoder, &seeds, options.find_functions)
+            }
+            DisassemblerStrategy::Hybrid => {
+                Self::extract_hybrid(file_read, decoder, &seeds, &data_references)
+            }
+            DisassemblerStrategy::Superset => Self::extract_superset(file_read, decoder, &seeds),
+        }?;
+
+        disassembly.imports = imports;
+        disassembly.exports = exports;
+        disassembly.data_references = data_references;
+
+        if detect_tricks {
+            disassembly.anti_disassembly =
+                AntiDisassembly::detect(&disassembly.instructions_by_address());
         }
+
+        disassembly.xrefs = XrefDatabase::build(&disassembly);
+        Ok(disassembly)
     }
 
     /// Implement linear sweep: one instruction at a time, and the first byte of the new disassembled
-    /// instruction is based on the last one successfully disassembled.
-    fn extract_linear(file_read: FileRead, nyxstone: &Nyxstone) -> Result<Vec<Instruction>> {
+    /// instruction is based on the last one successfully disassembled. Every executable region is
+    /// swept from its first byte to its last one.
+    fn extract_linear(file_read: FileRead, decoder: &InstructionDecoder) -> Result<Disassembly> {
+        let mut disassembly = Disassembly::new(&file_read.executable_regions);
+        disassembly.labels = Self::export_labels(&file_read);
 
+        for region in disassembly.regions.iter_mut() {
+            region.instructions = Self::linear_disassemble(&file_read, &region.region, decoder)?;
+        }
+
+        // the sweep does not follow branches, but direct calls still tell where functions start
+        disassembly.functions = disassembly
+            .instructions()
+            .filter(|instruction| instruction.branch_kind() == Some(BranchKind::CallDirect))
+            .filter_map(|instruction| instruction.get_target().ok().flatten())
+            .filter(|target| file_read.executable_region(*target).is_some())
+            .collect();
+        disassembly.functions.insert(file_read.entrypoint);
+
+        Ok(disassembly)
+    }
+
+    /// Linear sweep of a single executable region
+    fn linear_disassemble(
+        file_read: &FileRead,
+        region: &ExecutableRegion,
+        decoder: &InstructionDecoder,
+    ) -> Result<Vec<Instruction>> {
         let mut instructions = vec![];
 
-        // implement the linear sweep
-        let end_address = file_read.instr_address as usize + file_read.size;
-        let mut cursor = Cursor::new(&file_read.bytes[..end_address]);
+        // implement the linear sweep on the mapped image: the executable section is read through
+        // its virtual addresses, zero-filled bytes included
+        let image = &file_read.image;
 
         // this is our current ground truth!!
-        let mut current_address = file_read.instr_address;
-        let mut buffer_instruction = [0; MAXIMUM_SIZE_X86_INSTR];
+        let mut current_address = region.start;
+        let end_address = region.end();
 
-        while current_address < (end_address - MAXIMUM_SIZE_X86_INSTR) as u64 {
+        while current_address < end_address {
             let mut instruction_size = 1;
+            let max_size = MAXIMUM_SIZE_X86_INSTR.min((end_address - current_address) as usize);
 
-            if cursor.seek(SeekFrom::Start(current_address)).is_ok() {
-                cursor.read_exact(&mut buffer_instruction)?;
-
-                let i = nyxstone
-                    .disassemble_to_instructions(buffer_instruction.as_slice(), current_address, 1);
-                match i {
-                    Ok(i) if i.len() == 1 => {
-                        println!("Addr: 0x{:x}\tInstr: {}", i[0].address, i[0].assembly);
-                        instruction_size = i[0].bytes.len();
-
-                        let instruction_parsed = InstructionParser::parse(
-                            i[0].assembly.as_str(),
-                            i[0].address,
-                            i[0].bytes.len(),
-                        )?;
-                        instructions.push(instruction_parsed);
+            if let Ok(buffer_instruction) = image.read_bounded(current_address, max_size) {
+                match decoder.decode(buffer_instruction, current_address) {
+                    Some(mut instruction) => {
+                        instruction_size = instruction.instruction_size;
+
+                        Self::annotate(file_read, &mut instruction);
+                        instructions.push(instruction);
                     }
-                    _ => println!("We have no instructions for address: {:x}", current_address),
+                    None => log::warn!("No instruction decoded at 0x{:x}", current_address),
                 }
             }
             current_address += instruction_size as u64;
@@ -68,106 +175,236 @@ impl Disassembler {
 
     /// Implement recursive traversal: when an instruction that changes the control flow have an
     /// address as operand. Due to over approximation of the disassembler phase, we can have
-    /// addresses that point to invalid instructions.
-    fn extract_recursive(file_read: FileRead, nyxstone: &Nyxstone) -> Result<Vec<Instruction>> {
-        let mut instructions = vec![];
-        let mut cursor =
-            Cursor::new(&file_read.bytes[..file_read.instr_address as usize + file_read.size]);
+    /// addresses that point to invalid instructions. The traversal can move across every
+    /// executable region. If asked, functions that no branch reaches are then looked for from
+    /// their prologue.
+    fn extract_recursive(
+        file_read: FileRead,
+        decoder: &InstructionDecoder,
+        seeds: &BTreeSet<Address>,
+        find_functions: bool,
+    ) -> Result<Disassembly> {
+        let mut disassembly = Disassembly::new(&file_read.executable_regions);
+        let mut traversal = Self::start_traversal(&file_read, decoder, seeds, &mut disassembly);
+        traversal.run()?;
+        if find_functions {
+            Self::find_functions(&file_read, decoder, &mut traversal, &mut disassembly)?;
+        }
+        Self::finish_traversal(traversal, &mut disassembly);
+        Ok(disassembly)
+    }
 
-        // global list to understand if, given an address, was already disassembled
-        let mut worklist = vec![];
+    /// Implement the hybrid strategy: recursive traversal first, then a linear scan of the gaps
+    /// it leaves inside the executable regions. Code found in the gaps is accepted only when it
+    /// looks like a function, and every region gets a confidence.
+    fn extract_hybrid(
+        file_read: FileRead,
+        decoder: &InstructionDecoder,
+        seeds: &BTreeSet<Address>,
+        data_references: &[DataReference],
+    ) -> Result<Disassembly> {
+        let mut disassembly = Disassembly::new(&file_read.executable_regions);
+        let mut traversal = Self::start_traversal(&file_read, decoder, seeds, &mut disassembly);
+        traversal.run()?;
+        let functions =
+            Self::find_functions(&file_read, decoder, &mut traversal, &mut disassembly)?;
+        disassembly.code_regions = GapScanner::new(&file_read, decoder, data_references)
+            .scan(&mut traversal, &functions)?;
+        Self::finish_traversal(traversal, &mut disassembly);
+        Ok(disassembly)
+    }
 
-        Self::recursive_disassemble(
-            &mut cursor,
-            file_read.entrypoint,
-            &mut worklist,
-            &mut instructions,
-            nyxstone,
-        )?;
+    /// Implement the superset strategy: a candidate instruction at every byte offset, pruned with
+    /// the instructions confirmed by the recursive traversal. Every byte gets the probability that
+    /// an instruction starts there, and the candidates likely enough are kept as instructions,
+    /// overlapping ones included.
+    fn extract_superset(
+        file_read: FileRead,
+        decoder: &InstructionDecoder,
+        seeds: &BTreeSet<Address>,
+    ) -> Result<Disassembly> {
+        let mut disassembly = Disassembly::new(&file_read.executable_regions);
+        let mut traversal = Self::start_traversal(&file_read, decoder, seeds, &mut disassembly);
+        traversal.run()?;
+        let superset = Superset::new(
+            &file_read,
+            decoder,
+            traversal.instructions(),
+            traversal.no_return_calls(),
+        );
+        Self::traversal_results(&traversal, &mut disassembly);
 
-        Ok(instructions)
+        for region in disassembly.regions.iter_mut() {
+            region.probabilities = superset.probabilities(region.region.start, region.region.end());
+            for (offset, probability) in region.probabilities.iter().enumerate() {
+                let address = region.region.start + offset as Address;
+                if *probability < ACCEPTANCE_PROBABILITY {
+                    continue;
+                }
+                let mut instruction = superset.candidates()[&address].clone();
+                Self::annotate(&file_read, &mut instruction);
+                region.instructions.push(instruction);
+            }
+        }
+        Ok(disassembly)
     }
 
-    /// Function for calling the recursive disassembling
-    fn recursive_disassemble(
-        cursor: &mut Cursor<&[u8]>,
-        start_address: Address,
-        global_list: &mut Vec<Address>,
-        instructions: &mut Vec<Instruction>,
-        nyxstone: &Nyxstone,
-    ) -> Result<()> {
-
-        // Add the instruction address to the one already seen
-        global_list.push(start_address);
-
-        let mut continue_to_disassemble = true;
-        let mut current_address = start_address;
-        let mut buffer_instruction = [0; MAXIMUM_SIZE_X86_INSTR];
-
-        while continue_to_disassemble {
-            if cursor.seek(SeekFrom::Start(current_address)).is_ok() {
-                cursor.read_exact(&mut buffer_instruction)?;
-
-                let instruction = nyxstone
-                    .disassemble_to_instructions(buffer_instruction.as_slice(), current_address, 1);
-                match instruction {
-                    Ok(instruction) if instruction.len() == 1 => {
-                        println!("{:x}, {:?}", current_address, instruction[0].assembly);
-                        let instr_parsed = InstructionParser::parse(
-                            instruction[0].assembly.as_str(),
-                            instruction[0].address,
-                            instruction[0].bytes.len(),
-                        )?;
-
-                        if instr_parsed.change_cfg() {
-                            if let Some(target) = instr_parsed.get_target()? {
-                                let current_target =
-                                    target + instr_parsed.instruction_size as Address;
-                                if !global_list.contains(&current_target) {
-                                    Self::recursive_disassemble(
-                                        cursor,
-                                        current_target,
-                                        global_list,
-                                        instructions,
-                                        nyxstone
-                                    )?;
-                                }
-                            }
-
-                            if instr_parsed.is_conditional_jump() {
-                                Self::recursive_disassemble(
-                                    cursor,
-                                    current_address + instr_parsed.instruction_size as Address,
-                                    global_list,
-                                    instructions,
-                                    nyxstone
-                                )?;
-                            }
-                        }
-
-                        if instr_parsed.is_ret() || instr_parsed.opcode == X86Opcode::Jmp {
-                            continue_to_disassemble = false;
-                        }
-                        current_address += instr_parsed.instruction_size as Address;
-                        instructions.push(instr_parsed);
-                    }
-                    _ => {
-                        println!("We have no instructions for address: {:x}", current_address);
-                        current_address += 1;
-                        continue_to_disassemble = false;
-                    }
-                }
+    /// Creates the recursive traversal with its roots: the entrypoint, every function exported
+    /// by the binary (for DLLs, exports are where the interesting code is) and every runtime
+    /// function. Chained runtime functions are portions of another function (e.g. cold code),
+    /// not functions on their own. The seeds are other functions to start from (e.g. pointers to
+    /// code found in data).
+    fn start_traversal<'a>(
+        file_read: &'a FileRead,
+        decoder: &'a InstructionDecoder,
+        seeds: &BTreeSet<Address>,
+        disassembly: &mut Disassembly,
+    ) -> RecursiveTraversal<'a> {
+        disassembly.labels = Self::export_labels(file_read);
+
+        // functions described by the exception directory are the ground truth for function
+        // boundaries on x64
+        disassembly.function_extents = file_read
+            .exceptions
+            .iter()
+            .map(|entry| {
+                (
+                    file_read.translator.rva_to_va(entry.function.begin).0,
+                    file_read.translator.rva_to_va(entry.function.end).0,
+                )
+            })
+            .collect();
+
+        let mut traversal = RecursiveTraversal::new(file_read, decoder);
+        traversal.add_function(file_read.entrypoint);
+        for (address, _) in file_read.exported_functions() {
+            traversal.add_function(address);
+        }
+        for entry in &file_read.exceptions {
+            let address = file_read.translator.rva_to_va(entry.function.begin).0;
+            if entry.is_chained() {
+                traversal.add_root(address);
             } else {
-                continue_to_disassemble = false;
+                traversal.add_function(address);
+            }
+        }
+        for seed in seeds {
+            traversal.add_function(*seed);
+        }
+        traversal
+    }
+
+    /// Look for the functions the traversal missed from their prologue, and disassemble them
+    fn find_functions(
+        file_read: &FileRead,
+        decoder: &InstructionDecoder,
+        traversal: &mut RecursiveTraversal,
+        disassembly: &mut Disassembly,
+    ) -> Result<Vec<FunctionCandidate>> {
+        let functions = FunctionFinder::new(file_read, decoder).scan(traversal)?;
+        disassembly.function_confidence = functions
+            .iter()
+            .map(|function| (function.start, function.confidence))
+            .collect();
+        Ok(functions)
+    }
+
+    /// Move the results of the traversal into the disassembly
+    fn finish_traversal(traversal: RecursiveTraversal, disassembly: &mut Disassembly) {
+        Self::traversal_results(&traversal, disassembly);
+
+        // instructions are stored by address, whatever the order of the traversal
+        for instruction in traversal.into_instructions().into_values() {
+            disassembly.push(instruction);
+        }
+    }
+
+    /// Copy what the traversal found besides the instructions: functions, calls that do not
+    /// return and jump tables
+    fn traversal_results(traversal: &RecursiveTraversal, disassembly: &mut Disassembly) {
+        // functions reached only through calls get a generic name
+        disassembly.functions = traversal.functions().clone();
+        disassembly.no_return_calls = traversal.no_return_calls().clone();
+        disassembly.jump_tables = traversal.jump_tables().clone();
+        for address in &disassembly.functions {
+            disassembly
+                .labels
+                .entry(*address)
+                .or_insert_with(|| format!("sub_{:x}", address));
+        }
+    }
+
+    /// Annotate the instruction with what we know about the addresses it references
+    pub(crate) fn annotate(file_read: &FileRead, instruction: &mut Instruction) {
+        Self::annotate_import(file_read, instruction);
+        Self::annotate_pointers(file_read, instruction);
+    }
+
+    /// Annotate the instruction with the imported function whose IAT slot is read through a
+    /// RIP-relative memory operand (e.g. `call qword ptr [rip + 0x1234]`)
+    fn annotate_import(file_read: &FileRead, instruction: &mut Instruction) {
+        instruction.import = instruction
+            .memory_target()
+            .and_then(|target| file_read.import_at(target))
+            .cloned();
+    }
+
+    /// Mark as pointers the immediate operands patched by a base relocation (e.g. the immediate
+    /// of `movabs rax, 0x140003000`): they are absolute addresses, not plain constants
+    fn annotate_pointers(file_read: &FileRead, instruction: &mut Instruction) {
+        let Ok(rva) = file_read
+            .translator
+            .va_to_rva(VirtualAddress(instruction.address))
+        else {
+            return;
+        };
+
+        let relocations = file_read
+            .relocations
+            .in_range(rva, instruction.instruction_size as u32);
+        for (relocation_rva, kind) in relocations {
+            let location = file_read.translator.rva_to_va(relocation_rva).0;
+            let value = match kind {
+                RelocationType::Dir64 => file_read.image.read_u64(location),
+                RelocationType::HighLow => file_read.image.read_u32(location).map(u64::from),
+                _ => continue,
+            };
+            let Ok(value) = value else {
+                continue;
+            };
+
+            for operand in instruction.operands.iter_mut() {
+                if matches!(operand, X86Operand::Immediate(imm) if *imm as u64 == value) {
+                    *operand = X86Operand::Pointer(value);
+                }
             }
         }
+    }
 
-        Ok(())
+    /// Label every exported function with its export name
+    fn export_labels(file_read: &FileRead) -> BTreeMap<Address, String> {
+        file_read
+            .exported_functions()
+            .map(|(address, export)| (address, export.to_string()))
+            .collect()
     }
 }
 
 /// Maximum size for an x86 instruction
-const MAXIMUM_SIZE_X86_INSTR: usize = 15;
+pub(crate) const MAXIMUM_SIZE_X86_INSTR: usize = 15;
+
+/// Options of the disassembler that apply to every strategy
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DisassemblerOptions {
+    /// start the traversal from the pointers to code stored in data sections too, as functions
+    pub seed_data_pointers: bool,
+    /// record the pointers to code stored in data sections, e.g. for their cross-references,
+    /// without starting the traversal from them
+    pub scan_data_pointers: bool,
+    /// after the recursive traversal, look for the functions that no branch reaches from their
+    /// prologue. The hybrid strategy always does.
+    pub find_functions: bool,
+}
 
 /// What strategy to use for disassembler
 pub enum DisassemblerStrategy {
@@ -177,4 +414,10 @@ pub enum DisassemblerStrategy {
     /// Disassemble one instruction and evaluate its semantics: if it's an instruction that changes
     /// control flow
     RecursiveTraversal,
+    /// Recursive traversal, then a linear sweep of the bytes it did not reach that only accepts
+    /// what looks like code
+    Hybrid,
+    /// Decode an instruction at every byte offset, prune the candidates that cannot be code and
+    /// give every byte the probability of starting an instruction, for obfuscated binaries
+    Superset,
 }
@@ -0,0 +1,158 @@
+use crate::disassembler::anti_disassembly::AntiDisassembly;
+use crate::disassembler::jump_table::JumpTable;
+use crate::disassembler::pointer_scan::DataReference;
+use crate::disassembler::xrefs::XrefDatabase;
+use crate::pe::address::Address;
+use crate::pe::exports::Export;
+use crate::pe::imports::Import;
+use crate::pe::section::ExecutableRegion;
+use crate::x86::instruction::Instruction;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Instructions disassembled from a single executable region
+#[derive(Clone, Debug, Default)]
+pub struct RegionDisassembly {
+    /// the region disassembled
+    pub region: ExecutableRegion,
+    /// instructions found inside the region
+    pub instructions: Vec<Instruction>,
+    /// probability that an instruction starts at every byte of the region, indexed by offset
+    /// from its start. Only the superset strategy fills it.
+    pub probabilities: Vec<f64>,
+}
+
+/// Contiguous instructions found with the same confidence
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CodeRegion {
+    /// address of the first instruction
+    pub start: Address,
+    /// first byte after the last instruction
+    pub end: Address,
+    /// how likely the bytes are code, from 0 to 1
+    pub confidence: f64,
+}
+
+/// The result of a disassembler strategy, split per executable region
+#[derive(Clone, Debug, Default)]
+pub struct Disassembly {
+    /// instructions found, one entry for each executable region of the binary
+    pub regions: Vec<RegionDisassembly>,
+    /// names of known locations (e.g. exported functions), printed before their instruction
+    pub labels: BTreeMap<Address, String>,
+    /// functions imported by the binary, keyed by the virtual address of their IAT slot
+    pub imports: BTreeMap<Address, Import>,
+    /// symbols exported and implemented by the binary, keyed by virtual address
+    pub exports: BTreeMap<Address, Export>,
+    /// first byte and first byte after the end of the functions described by the exception
+    /// directory
+    pub function_extents: BTreeMap<Address, Address>,
+    /// first instruction of every function found: entrypoint, exports, runtime functions and
+    /// targets of direct calls
+    pub functions: BTreeSet<Address>,
+    /// confidence of the functions found from their prologue by the function finder: the other
+    /// functions are reached from a root or a direct call
+    pub function_confidence: BTreeMap<Address, f64>,
+    /// calls whose callee never returns (e.g. `ExitProcess`): the instruction that follows them
+    /// is not their successor
+    pub no_return_calls: BTreeSet<Address>,
+    /// jump tables used by indirect jumps, keyed by the address of the jump
+    pub jump_tables: BTreeMap<Address, JumpTable>,
+    /// confidence of every region of contiguous instructions, sorted by address. Only the
+    /// hybrid strategy fills it.
+    pub code_regions: Vec<CodeRegion>,
+    /// pointers to code stored in the sections that are not executable, sorted by location
+    pub data_references: Vec<DataReference>,
+    /// cross-references between code and data, keyed by target
+    pub xrefs: XrefDatabase,
+    /// tricks used against disassemblers found among the instructions, sorted by address
+    pub anti_disassembly: Vec<AntiDisassembly>,
+}
+
+impl Disassembly {
+    /// Creates an empty disassembly for the executable regions
+    pub fn new(regions: &[ExecutableRegion]) -> Self {
+        let regions = regions
+            .iter()
+            .map(|region| RegionDisassembly {
+                region: region.clone(),
+                instructions: vec![],
+                probabilities: vec![],
+            })
+            .collect();
+        Self {
+            regions,
+            labels: BTreeMap::new(),
+            imports: BTreeMap::new(),
+            exports: BTreeMap::new(),
+            function_extents: BTreeMap::new(),
+            functions: BTreeSet::new(),
+            function_confidence: BTreeMap::new(),
+            no_return_calls: BTreeSet::new(),
+            jump_tables: BTreeMap::new(),
+            code_regions: vec![],
+            data_references: vec![],
+            xrefs: XrefDatabase::default(),
+            anti_disassembly: vec![],
+        }
+    }
+
+    /// Add an instruction to the region that contains it. Instructions outside every region are
+    /// discarded.
+    pub fn push(&mut self, instruction: Instruction) {
+        if let Some(region) = self
+            .regions
+            .iter_mut()
+            .find(|r| r.region.contains(instruction.address))
+        {
+            region.instructions.push(instruction);
+        }
+    }
+
+    /// Instructions of every region, keyed by address
+    pub fn instructions_by_address(&self) -> BTreeMap<Address, &Instruction> {
+        self.instructions()
+            .map(|instruction| (instruction.address, instruction))
+            .collect()
+    }
+
+    /// Iterates over the instructions of every region
+    pub fn instructions(&self) -> impl Iterator<Item = &Instruction> {
+        self.regions.iter().flat_map(|r| r.instructions.iter())
+    }
+
+    /// Probability that an instruction starts at the address, when the strategy computes one
+    pub fn instruction_probability(&self, address: Address) -> Option<f64> {
+        let region = self.regions.iter().find(|r| r.region.contains(address))?;
+        region
+            .probabilities
+            .get((address - region.region.start) as usize)
+            .copied()
+    }
+
+    /// Compare the instruction starts found by two strategies
+    pub fn compare(&self, other: &Disassembly) -> Comparison {
+        let starts = |disassembly: &Disassembly| {
+            disassembly
+                .instructions()
+                .map(|instruction| instruction.address)
+                .collect::<BTreeSet<Address>>()
+        };
+        let (first, second) = (starts(self), starts(other));
+        Comparison {
+            common: first.intersection(&second).copied().collect(),
+            only_first: first.difference(&second).copied().collect(),
+            only_second: second.difference(&first).copied().collect(),
+        }
+    }
+}
+
+/// Instruction starts found by two disassemblies of the same binary
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Comparison {
+    /// instructions found by both
+    pub common: BTreeSet<Address>,
+    /// instructions found only by the first one
+    pub only_first: BTreeSet<Address>,
+    /// instructions found only by the second one
+    pub only_second: BTreeSet<Address>,
+}
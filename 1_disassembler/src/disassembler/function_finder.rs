@@ -0,0 +1,233 @@
+use crate::disassembler::decoder::InstructionDecoder;
+use crate::disassembler::disassemble::{Disassembler, MAXIMUM_SIZE_X86_INSTR};
+use crate::disassembler::recursive_traversal::RecursiveTraversal;
+use crate::pe::address::Address;
+use crate::pe::file_read::FileRead;
+use crate::x86::branch::BranchKind;
+use anyhow::Result;
+
+/// Finds the functions that no root or direct call reaches (e.g. called only through a vtable
+/// or a function pointer) from the signatures compilers leave: the bytes of the executable
+/// regions that the traversal did not reach are scanned for x64 prologues, which are more likely
+/// to start a function when they follow padding and are aligned. Every candidate accepted is
+/// added to the traversal as a function, with a confidence.
+pub struct FunctionFinder<'a> {
+    /// the binary to scan
+    file_read: &'a FileRead,
+    /// decoder used to check the candidates
+    decoder: &'a InstructionDecoder,
+}
+
+/// A function found from its prologue
+#[derive(Clone, Debug, PartialEq)]
+pub struct FunctionCandidate {
+    /// first address of the function
+    pub start: Address,
+    /// confidence that a function starts there. It stays below 1: only the traversal from the
+    /// roots proves that bytes are code.
+    pub confidence: f64,
+    /// address of every instruction disassembled from the function, and from the functions it
+    /// calls that were not known yet
+    pub instructions: Vec<Address>,
+}
+
+impl<'a> FunctionFinder<'a> {
+    /// Creates a finder for the binary
+    pub fn new(file_read: &'a FileRead, decoder: &'a InstructionDecoder) -> Self {
+        Self { file_read, decoder }
+    }
+
+    /// Scan the bytes not reached by the traversal, which already ran from the usual roots, and
+    /// disassemble every function found
+    pub fn scan(&self, traversal: &mut RecursiveTraversal) -> Result<Vec<FunctionCandidate>> {
+        let mut found = vec![];
+
+        for region in &self.file_read.executable_regions {
+            let mut address = region.start;
+            while address < region.end() {
+                if let Some(instruction) = traversal.instruction_containing(address) {
+                    address = instruction.next_address();
+                    continue;
+                }
+
+                if let Some(confidence) = self.evaluate(traversal, address, region.start) {
+                    if Self::is_plausible(
+                        self.file_read,
+                        self.decoder,
+                        traversal,
+                        address,
+                        region.end(),
+                    ) {
+                        traversal.add_function(address);
+                        let instructions = traversal.run()?;
+                        if !instructions.is_empty() {
+                            found.push(FunctionCandidate {
+                                start: address,
+                                confidence,
+                                instructions,
+                            });
+                            continue;
+                        }
+                    }
+                }
+                address += 1;
+            }
+        }
+        Ok(found)
+    }
+
+    /// Confidence that a function starts at the address, if it starts with a prologue: the
+    /// prologue is enough to accept it, padding before it and the usual alignment make it more
+    /// likely
+    fn evaluate(
+        &self,
+        traversal: &RecursiveTraversal,
+        address: Address,
+        region_start: Address,
+    ) -> Option<f64> {
+        if !Self::has_prologue(self.file_read, address) {
+            return None;
+        }
+
+        let mut confidence = PROLOGUE_CONFIDENCE;
+        // the end of the previous function is known code or padding
+        if address == region_start
+            || Self::is_padding(self.file_read, address - 1)
+            || traversal
+                .instruction_containing(address - 1)
+                .is_some_and(|instruction| !instruction.falls_through())
+        {
+            confidence += BOUNDARY_CONFIDENCE;
+        }
+        if Self::is_aligned(address) {
+            confidence += ALIGNMENT_CONFIDENCE;
+        }
+        Some(confidence)
+    }
+
+    /// Returns if the instructions that follow a candidate start decode up to a terminator
+    /// (return or jump) without privileged instructions, branches outside the executable regions
+    /// or overlaps with known code
+    pub(crate) fn is_plausible(
+        file_read: &FileRead,
+        decoder: &InstructionDecoder,
+        traversal: &RecursiveTraversal,
+        start: Address,
+        end: Address,
+    ) -> bool {
+        let mut address = start;
+        for _ in 0..CANDIDATE_MAXIMUM_INSTRUCTIONS {
+            if address >= end {
+                return false;
+            }
+            // running into known code is as good as a terminator
+            if traversal.is_visited(address) {
+                return true;
+            }
+
+            let max_size = MAXIMUM_SIZE_X86_INSTR.min((end - address) as usize);
+            let Ok(bytes) = file_read.image.read_bounded(address, max_size) else {
+                return false;
+            };
+            let Some(mut instruction) = decoder.decode(bytes, address) else {
+                return false;
+            };
+            Disassembler::annotate(file_read, &mut instruction);
+
+            let overlaps = (address + 1..instruction.next_address())
+                .any(|byte| traversal.instruction_containing(byte).is_some());
+            let wild_branch = instruction
+                .get_target()
+                .ok()
+                .flatten()
+                .is_some_and(|target| file_read.executable_region(target).is_none());
+            if overlaps || !instruction.is_user_mode() || wild_branch {
+                return false;
+            }
+
+            match instruction.branch_kind() {
+                Some(
+                    BranchKind::Return
+                    | BranchKind::UnconditionalDirect
+                    | BranchKind::UnconditionalIndirect,
+                ) => return true,
+                Some(BranchKind::Halt) => return false,
+                _ => {}
+            }
+            address = instruction.next_address();
+        }
+        false
+    }
+
+    /// Returns if the bytes at the address start like the prologue of an x64 function
+    pub(crate) fn has_prologue(file_read: &FileRead, address: Address) -> bool {
+        let Ok(bytes) = file_read.image.read_bounded(address, 4) else {
+            return false;
+        };
+        PROLOGUES.iter().any(|prologue| bytes.starts_with(prologue))
+    }
+
+    /// Returns if the address is aligned like functions usually are
+    pub(crate) fn is_aligned(address: Address) -> bool {
+        address.is_multiple_of(FUNCTION_ALIGNMENT)
+    }
+
+    /// Returns if the byte at the address is used by compilers to pad functions
+    pub(crate) fn is_padding(file_read: &FileRead, address: Address) -> bool {
+        file_read
+            .image
+            .read(address, 1)
+            .is_ok_and(|byte| PADDING.contains(&byte[0]))
+    }
+}
+
+/// Bytes that compilers put between functions: int3, nop and zero
+const PADDING: [u8; 3] = [0xcc, 0x90, 0x00];
+
+/// Common first bytes of x64 functions emitted by MSVC, clang and GCC
+const PROLOGUES: &[&[u8]] = &[
+    // mov qword ptr [rsp + x], rbx/rbp/rsi/rdi/rcx/rdx
+    &[0x48, 0x89, 0x5c, 0x24],
+    &[0x48, 0x89, 0x6c, 0x24],
+    &[0x48, 0x89, 0x74, 0x24],
+    &[0x48, 0x89, 0x7c, 0x24],
+    &[0x48, 0x89, 0x4c, 0x24],
+    &[0x48, 0x89, 0x54, 0x24],
+    // mov qword ptr [rsp + x], r8/r9
+    &[0x4c, 0x89, 0x44, 0x24],
+    &[0x4c, 0x89, 0x4c, 0x24],
+    // push rbx/rbp/rsi/rdi with a REX prefix, as MSVC emits them for hot patching
+    &[0x40, 0x53],
+    &[0x40, 0x55],
+    &[0x40, 0x56],
+    &[0x40, 0x57],
+    // push r12/r13/r14/r15
+    &[0x41, 0x54],
+    &[0x41, 0x55],
+    &[0x41, 0x56],
+    &[0x41, 0x57],
+    // push rbp; mov rbp, rsp
+    &[0x55, 0x48, 0x89, 0xe5],
+    &[0x55, 0x48, 0x8b, 0xec],
+    // sub rsp, imm8/imm32
+    &[0x48, 0x83, 0xec],
+    &[0x48, 0x81, 0xec],
+    // mov r11, rsp and mov rax, rsp: frames addressed through a copy of rsp
+    &[0x4c, 0x8b, 0xdc],
+    &[0x48, 0x8b, 0xc4],
+];
+
+/// Functions usually start at addresses aligned to 16 bytes
+const FUNCTION_ALIGNMENT: Address = 16;
+
+/// Number of instructions decoded from a candidate while looking for a terminator
+const CANDIDATE_MAXIMUM_INSTRUCTIONS: usize = 1024;
+
+/// Confidence of a candidate that starts with a prologue
+const PROLOGUE_CONFIDENCE: f64 = 0.5;
+
+/// Confidence added when the candidate follows padding or the end of known code
+const BOUNDARY_CONFIDENCE: f64 = 0.25;
+
+/// Confidence added when the candidate is aligned like functions usually are
+const ALIGNMENT_CONFIDENCE: f64 = 0.15;
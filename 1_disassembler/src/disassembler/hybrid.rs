@@ -0,0 +1,150 @@
+use crate::disassembler::decoder::InstructionDecoder;
+use crate::disassembler::disassembly::CodeRegion;
+use crate::disassembler::function_finder::{FunctionCandidate, FunctionFinder};
+use crate::disassembler::pointer_scan::DataReference;
+use crate::disassembler::recursive_traversal::RecursiveTraversal;
+use crate::pe::address::Address;
+use crate::pe::file_read::FileRead;
+use anyhow::Result;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Second phase of the hybrid strategy: once the recursive traversal is over, the bytes of the
+/// executable regions that it did not reach (the gaps) are scanned linearly. A candidate start
+/// is accepted as code only if the instructions that follow it look like a function, and the
+/// traversal then continues from there. Every instruction gets the confidence of the phase that
+/// found it.
+pub struct GapScanner<'a> {
+    /// the binary to disassemble
+    file_read: &'a FileRead,
+    /// decoder used for the candidates
+    decoder: &'a InstructionDecoder,
+    /// targets of the pointers to code stored in the data sections
+    pointers: BTreeSet<Address>,
+}
+
+impl<'a> GapScanner<'a> {
+    /// Creates a scanner for the binary, with the pointers to code found by the `PointerScanner`
+    pub fn new(
+        file_read: &'a FileRead,
+        decoder: &'a InstructionDecoder,
+        data_references: &[DataReference],
+    ) -> Self {
+        Self {
+            file_read,
+            decoder,
+            pointers: data_references
+                .iter()
+                .map(|reference| reference.target)
+                .collect(),
+        }
+    }
+
+    /// Scan the gaps left by the traversal, which already ran from the usual roots and from the
+    /// functions found from their prologue, and return the regions of contiguous instructions
+    /// with their confidence: 1 for the instructions reached from the roots, the confidence of
+    /// the function for the code found from a prologue, the score of the candidate for the code
+    /// found in the gaps
+    pub fn scan(
+        &self,
+        traversal: &mut RecursiveTraversal,
+        functions: &[FunctionCandidate],
+    ) -> Result<Vec<CodeRegion>> {
+        let mut confidence = BTreeMap::new();
+        for function in functions {
+            for instruction in &function.instructions {
+                confidence.insert(*instruction, function.confidence);
+            }
+        }
+
+        for region in &self.file_read.executable_regions {
+            let mut address = region.start;
+            while address < region.end() {
+                if let Some(instruction) = traversal.instruction_containing(address) {
+                    address = instruction.next_address();
+                    continue;
+                }
+                if FunctionFinder::is_padding(self.file_read, address) {
+                    address += 1;
+                    continue;
+                }
+
+                if let Some(score) = self.evaluate(traversal, address, region.end()) {
+                    traversal.add_function(address);
+                    let disassembled = traversal.run()?;
+                    if !disassembled.is_empty() {
+                        for instruction in disassembled {
+                            confidence.insert(instruction, score);
+                        }
+                        continue;
+                    }
+                }
+
+                // the next candidate follows padding or is aligned like functions usually are
+                address += 1;
+                while address < region.end()
+                    && !FunctionFinder::is_aligned(address)
+                    && !FunctionFinder::is_padding(self.file_read, address - 1)
+                {
+                    address += 1;
+                }
+            }
+        }
+
+        let mut regions: Vec<CodeRegion> = vec![];
+        for instruction in traversal.instructions().values() {
+            let score = confidence.get(&instruction.address).copied().unwrap_or(1.0);
+            match regions.last_mut() {
+                Some(region) if region.end == instruction.address && region.confidence == score => {
+                    region.end = instruction.next_address();
+                }
+                _ => regions.push(CodeRegion {
+                    start: instruction.address,
+                    end: instruction.next_address(),
+                    confidence: score,
+                }),
+            }
+        }
+        Ok(regions)
+    }
+
+    /// Score of a candidate start inside a gap, if it is accepted as code. The instructions that
+    /// follow the candidate must decode up to a terminator (return or jump) without privileged
+    /// instructions, branches outside the executable regions or overlaps with known code. On
+    /// top of that, a function prologue and a pointer to the candidate raise the score, which
+    /// stays below 1: only the traversal from the roots proves that bytes are code.
+    fn evaluate(
+        &self,
+        traversal: &RecursiveTraversal,
+        start: Address,
+        end: Address,
+    ) -> Option<f64> {
+        if !FunctionFinder::is_plausible(self.file_read, self.decoder, traversal, start, end) {
+            return None;
+        }
+
+        let mut score = TERMINATOR_SCORE;
+        if FunctionFinder::has_prologue(self.file_read, start) {
+            score += PROLOGUE_SCORE;
+        }
+        if self.pointers.contains(&start) {
+            score += POINTER_SCORE;
+        }
+        (score >= ACCEPTANCE_SCORE).then_some(score.min(MAXIMUM_SCORE))
+    }
+}
+
+/// Score of a candidate that decodes up to a terminator
+const TERMINATOR_SCORE: f64 = 0.3;
+
+/// Score added when the candidate starts with a function prologue
+const PROLOGUE_SCORE: f64 = 0.4;
+
+/// Score added when a pointer to the candidate is stored in the image
+const POINTER_SCORE: f64 = 0.3;
+
+/// Minimum score of a candidate accepted as code: decoding up to a terminator is not enough
+const ACCEPTANCE_SCORE: f64 = 0.5;
+
+/// Maximum score of a candidate, even when every heuristic matches: 1 is kept for the code
+/// reached from the roots
+const MAXIMUM_SCORE: f64 = 0.95;
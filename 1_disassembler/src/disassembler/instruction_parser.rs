@@ -1,7 +1,10 @@
 use crate::pe::address::Address;
 use crate::x86::instruction::Instruction;
+use crate::x86::memory::MemoryOperand;
 use crate::x86::opcode::X86Opcode;
 use crate::x86::operands::X86Operand;
+use crate::x86::prefix::X86Prefix;
+use crate::x86::register::X86Register;
 use anyhow::{anyhow, bail, Result};
 use pest::iterators::{Pair, Pairs};
 use pest::Parser;
@@ -12,9 +15,13 @@ pub struct InstructionParser {}
 
 /// The grammar itself
 #[allow(missing_docs)]
-#[derive(pest_derive::Parser)]
-#[grammar = "disassembler/grammar.pest"]
-pub struct InstrParser;
+mod grammar {
+    #[derive(pest_derive::Parser)]
+    #[grammar = "disassembler/grammar.pest"]
+    pub struct InstrParser;
+}
+
+pub use grammar::{InstrParser, Rule};
 
 impl InstructionParser {
     /// Parse an instruction
@@ -50,18 +57,22 @@ impl InstructionParser {
         address: Address,
         instruction_size: usize,
     ) -> Result<Instruction> {
+        let mut prefixes = vec![];
         let mut opcode = X86Opcode::Invalid;
         let mut operands = vec![];
         for pair in pairs {
             match pair.as_rule() {
+                Rule::prefix => {
+                    prefixes.extend(X86Prefix::from_name(pair.as_str()));
+                }
                 Rule::opcode => {
                     let op = pair.as_str();
-                    opcode = Self::retrieve_opcode(op);
+                    opcode = X86Opcode::from_mnemonic(op);
                 }
                 Rule::operands => {
                     let pair_2 = pair.into_inner();
                     for p in pair_2 {
-                        let ope = Self::build_ast(p);
+                        let ope = Self::build_ast(p)?;
                         operands.push(ope);
                     }
                 }
@@ -71,143 +82,119 @@ impl InstructionParser {
             }
         }
 
-        let instr = Instruction::new(address, opcode, operands, instruction_size);
+        let mut instr = Instruction::new(address, opcode, operands, instruction_size);
+        instr.prefixes = prefixes;
+
+        // the assembly text shows the absolute target of relative branches, the instruction
+        // encodes the displacement from its end
+        if instr.change_cfg() {
+            let next_address = instr.next_address();
+            for operand in instr.operands.iter_mut() {
+                if let X86Operand::Immediate(target) = *operand {
+                    *operand =
+                        X86Operand::Relative((target as u64).wrapping_sub(next_address) as i64);
+                }
+            }
+        }
         Ok(instr)
     }
 
-    /// Recursive function used to build an operand
-    fn build_ast(pair: Pair<Rule>) -> X86Operand {
+    /// Build an operand
+    fn build_ast(pair: Pair<Rule>) -> Result<X86Operand> {
         match pair.as_rule() {
-            Rule::register => {
-                let reg_str = pair.as_str();
-                // registers are hardcoded into grammars, so there's no need to insert a double
-                // check. However, you can potentially improve this by checking which kind of x86
-                // register is to transform some little registers (e.g. al) to concrete part of
-                // long registers (e.g. al == slice(eax, 0, 15)
-                X86Operand::Register(reg_str.to_string())
-            }
-            Rule::memory => {
-                let inners_pair = pair.into_inner();
+            Rule::register => Ok(X86Operand::Register(Self::retrieve_register(
+                pair.as_str(),
+            )?)),
+            Rule::memory => Ok(X86Operand::Memory(Self::build_memory(pair)?)),
+            Rule::immediate => Ok(X86Operand::Immediate(Self::retrieve_immediate(
+                pair.as_str(),
+            )?)),
+            unknown => bail!("unexpected operand: {:?}", unknown),
+        }
+    }
 
-                let mut params = vec![];
-                let mut length = 0;
+    /// Build a memory operand: `size ptr segment:[base + index*scale + displacement]`
+    fn build_memory(pair: Pair<Rule>) -> Result<MemoryOperand> {
+        let mut memory = MemoryOperand::new(None, 0, 0);
 
-                for p in inners_pair {
-                    match p.as_rule() {
-                        Rule::amount_mem => {
-                            length = Self::retrieve_memory_size(p.as_str()).unwrap();
-                        }
-                        Rule::immediate => params.push(Self::build_ast(p)),
-                        Rule::memory_expression => {
-                            let inners = p.into_inner();
-                            for p in inners {
-                                match p.as_rule() {
-                                    Rule::register => params.push(Self::build_ast(p)),
-                                    Rule::displacement => {
-                                        let mut multiplier = 1;
-                                        for p in p.into_inner() {
+        for p in pair.into_inner() {
+            match p.as_rule() {
+                Rule::amount_mem => memory.size = Self::retrieve_memory_size(p.as_str())?,
+                Rule::segment => memory.segment = Some(Self::retrieve_register(p.as_str())?),
+                Rule::memory_expression => {
+                    // every term is added or subtracted according to the operator before it
+                    let mut sign = 1;
+                    for p in p.into_inner() {
+                        match p.as_rule() {
+                            Rule::OP => sign = if p.as_str() == "-" { -1 } else { 1 },
+                            Rule::term => {
+                                let term = p
+                                    .into_inner()
+                                    .next()
+                                    .ok_or_else(|| anyhow!("empty memory term"))?;
+                                match term.as_rule() {
+                                    Rule::register if memory.base.is_none() => {
+                                        memory.base = Some(Self::retrieve_register(term.as_str())?)
+                                    }
+                                    Rule::register => {
+                                        memory.index = Some(Self::retrieve_register(term.as_str())?)
+                                    }
+                                    Rule::scaled_index => {
+                                        for p in term.into_inner() {
                                             match p.as_rule() {
                                                 Rule::register => {
-                                                    params.push(Self::build_ast(p));
-                                                }
-                                                Rule::OP => {
-                                                    if p.as_str() == "-" {
-                                                        multiplier = 1;
-                                                    }
-                                                }
-                                                Rule::immediate => {
-                                                    let number = Self::build_ast(p);
-                                                    let to_push = match number {
-                                                        X86Operand::Immediate(immediate) => {
-                                                            X86Operand::Immediate(
-                                                                multiplier * immediate,
-                                                            )
-                                                        }
-                                                        _ => unreachable!(),
-                                                    };
-                                                    params.push(to_push);
+                                                    memory.index =
+                                                        Some(Self::retrieve_register(p.as_str())?)
                                                 }
-                                                _ => {}
+                                                _ => memory.scale = p.as_str().parse()?,
                                             }
                                         }
                                     }
-                                    _ => {}
+                                    _ => {
+                                        let displacement = Self::retrieve_immediate(term.as_str())?;
+                                        memory.displacement += (sign * displacement) as i64;
+                                    }
                                 }
                             }
-                        }
-                        _ => {
-                            panic!("get {}", p.as_str())
+                            _ => bail!("unexpected memory parameter: {}", p.as_str()),
                         }
                     }
                 }
-                X86Operand::Memory { params, length }
-            }
-            Rule::immediate => {
-                let number = pair.as_str().trim().parse::<i128>().unwrap();
-                X86Operand::Immediate(number)
+                _ => bail!("unexpected memory parameter: {}", p.as_str()),
             }
-            unknown => panic!("err: {:?}", unknown),
         }
+
+        memory.rip_relative = memory.base == Some(X86Register::Rip);
+        Ok(memory)
     }
 
-    /// Translate a fixed string into a concrete opcode
-    fn retrieve_opcode(opcode: &str) -> X86Opcode {
-        // TODO: this list is limited to the instructions we analyze from calc.exe
-        match opcode {
-            "xor" => X86Opcode::Xor,
-            "xchg" => X86Opcode::Xchg,
-            "test" => X86Opcode::Test,
-            "sub" => X86Opcode::Sub,
-            "shr" => X86Opcode::Shr,
-            "shl" => X86Opcode::Shl,
-            "setne" => X86Opcode::Setne,
-            "sete" => X86Opcode::Sete,
-            "sar" => X86Opcode::Sar,
-            "ror" => X86Opcode::Ror,
-            "rol" => X86Opcode::Rol,
-            "ret" => X86Opcode::Ret,
-            "rcl" => X86Opcode::Rcl,
-            "out" => X86Opcode::Rut,
-            "or" => X86Opcode::Or,
-            "push" => X86Opcode::Push,
-            "pop" => X86Opcode::Pop,
-            "not" => X86Opcode::Not,
-            "nop" => X86Opcode::Nop,
-            "neg" => X86Opcode::Neg,
-            "mov" => X86Opcode::Mov,
-            "movabs" => X86Opcode::Movabs,
-            "lock" => X86Opcode::Lock,
-            "lea" => X86Opcode::Lea,
-            "jrcxz" => X86Opcode::Jrcxz,
-            "jno" => X86Opcode::Jno,
-            "jne" => X86Opcode::Jne,
-            "jmp" => X86Opcode::Jmp,
-            "je" => X86Opcode::Je,
-            "jl" => X86Opcode::Jl,
-            "jbe" => X86Opcode::Jbe,
-            "jb" => X86Opcode::Jb,
-            "ja" => X86Opcode::Ja,
-            "int3" => X86Opcode::Int3,
-            "int" => X86Opcode::Int,
-            "inc" => X86Opcode::Inc,
-            "imul" => X86Opcode::Imul,
-            "fstp" => X86Opcode::Fstp,
-            "ficomp" => X86Opcode::Ficomp,
-            "ficom" => X86Opcode::Ficom,
-            "fcom" => X86Opcode::Fcom,
-            "cmp" => X86Opcode::Cmp,
-            "cmovne" => X86Opcode::Cmovne,
-            "cdq" => X86Opcode::Cdq,
-            "call" => X86Opcode::Call,
-            "and" => X86Opcode::And,
-            "add" => X86Opcode::Add,
-            _ => X86Opcode::Invalid,
-        }
+    /// Translate a register name into a concrete register
+    fn retrieve_register(register: &str) -> Result<X86Register> {
+        X86Register::from_name(register).ok_or_else(|| anyhow!("unknown register: {}", register))
+    }
+
+    /// Translate an immediate, decimal or hexadecimal, into a number
+    fn retrieve_immediate(immediate: &str) -> Result<i128> {
+        let immediate = immediate.replace(' ', "");
+        let (sign, digits) = match immediate.strip_prefix('-') {
+            Some(digits) => (-1, digits),
+            None => (1, immediate.as_str()),
+        };
+        let number = match digits.strip_prefix("0x") {
+            Some(hex) => i128::from_str_radix(hex, 16)?,
+            None => digits.parse::<i128>()?,
+        };
+        Ok(sign * number)
     }
 
     /// Retrieve memory size for memory-based operands
     fn retrieve_memory_size(size: &str) -> Result<u64> {
         let mem_size = match size {
+            "zmmword" => 512,
+            "ymmword" => 256,
+            "xmmword" => 128,
+            "tbyte" => 80,
+            "fword" => 48,
             "qword" => 64,
             "dword" => 32,
             "word" => 16,
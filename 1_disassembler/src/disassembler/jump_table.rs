@@ -0,0 +1,274 @@
+use crate::pe::address::Address;
+use crate::pe::file_read::FileRead;
+use crate::x86::branch::BranchKind;
+use crate::x86::instruction::Instruction;
+use crate::x86::memory::MemoryOperand;
+use crate::x86::opcode::X86Opcode;
+use crate::x86::operands::X86Operand;
+use crate::x86::register::X86Register;
+use std::collections::BTreeSet;
+
+/// A table of addresses read by an indirect jump, as emitted by MSVC for `switch` statements:
+///
+/// ```text
+/// cmp     ecx, 5                          ; bound of the index
+/// ja      default
+/// movsxd  rax, ecx
+/// lea     rdx, [rip + __ImageBase]
+/// mov     ecx, dword ptr [rdx + rax*4 + table_rva]
+/// add     rcx, rdx
+/// jmp     rcx
+/// ```
+///
+/// Entries are either RVAs added to the image base (as above) or absolute addresses read by
+/// `jmp qword ptr [...]`. Sparse switches first read a byte from an index table
+/// (`movzx eax, byte ptr [rdx + rax + index_rva]`) and use it as index of the jump table.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct JumpTable {
+    /// address of the indirect jump
+    pub jump: Address,
+    /// address of the first entry of the table
+    pub table: Address,
+    /// size of every entry: 4 bytes for RVAs, 8 bytes for absolute addresses
+    pub entry_size: usize,
+    /// address of the index table read before the jump table, if any
+    pub index_table: Option<Address>,
+    /// target of every case, in the order of the table (a target can appear several times)
+    pub targets: Vec<Address>,
+}
+
+impl JumpTable {
+    /// Recognize the jump table used by an indirect jump. `previous` contains the instructions
+    /// that execute right before the jump, in order: the last one is just before the jump.
+    /// Returns `None` if the jump does not follow a known idiom or the table has no valid entry.
+    pub fn recover(
+        file_read: &FileRead,
+        jump: &Instruction,
+        previous: &[&Instruction],
+    ) -> Option<Self> {
+        if jump.branch_kind() != Some(BranchKind::UnconditionalIndirect) {
+            return None;
+        }
+
+        let image_base = file_read.image.image_base;
+        let (table, entry_size, index) = match jump.operands.first()? {
+            // jmp qword ptr [base + index*8 + disp]
+            X86Operand::Memory(memory) => {
+                let table = Self::table_address(file_read, memory, previous, previous.len())?;
+                (table, 8, memory.index?)
+            }
+            // jmp reg
+            X86Operand::Register(target) => {
+                let (position, write) = last_write(previous, *target, previous.len())?;
+                match (write.opcode, write.operands.get(1)) {
+                    // add reg, base: the entry is an RVA, either register can hold it
+                    (X86Opcode::Add, Some(X86Operand::Register(other))) => {
+                        let (table, index) =
+                            Self::rva_table(file_read, previous, *target, *other, position)
+                                .or_else(|| {
+                                    Self::rva_table(file_read, previous, *other, *target, position)
+                                })?;
+                        (table, 4, index)
+                    }
+                    // mov reg, qword ptr [...]: the entry is an absolute address
+                    (X86Opcode::Mov, Some(X86Operand::Memory(memory))) if memory.scale == 8 => {
+                        let table = Self::table_address(file_read, memory, previous, position)?;
+                        (table, 8, memory.index?)
+                    }
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        };
+
+        let bound = Self::bound(file_read, previous, index);
+        let mut jump_table = JumpTable {
+            jump: jump.address,
+            table,
+            entry_size,
+            index_table: bound.as_ref().and_then(|bound| bound.index_table),
+            targets: vec![],
+        };
+
+        let cases = match &bound {
+            // the index table tells how many entries the jump table has
+            Some(Bound {
+                cases,
+                index_table: Some(index_table),
+            }) => file_read
+                .image
+                .read(*index_table, *cases as usize)
+                .ok()?
+                .iter()
+                .max()
+                .map(|max| *max as u64 + 1)?,
+            Some(bound) => bound.cases,
+            None => MAXIMUM_CASES,
+        };
+        for case in 0..cases.min(MAXIMUM_CASES) {
+            let entry = table.wrapping_add(case * entry_size as u64);
+            let target = match entry_size {
+                4 => file_read
+                    .image
+                    .read_u32(entry)
+                    .map(|rva| image_base.wrapping_add(rva as u64)),
+                _ => file_read.image.read_u64(entry),
+            };
+            // without a bound the table ends with the first entry that is not code
+            match target {
+                Ok(target) if file_read.executable_region(target).is_some() => {
+                    jump_table.targets.push(target)
+                }
+                _ => break,
+            }
+        }
+
+        (!jump_table.targets.is_empty()).then_some(jump_table)
+    }
+
+    /// Address of a table of RVAs and register used as index, when `entry` is loaded from the
+    /// table with a displacement from `base`, which holds the image base, before the instruction
+    /// at `before` adds them
+    fn rva_table(
+        file_read: &FileRead,
+        previous: &[&Instruction],
+        entry: X86Register,
+        base: X86Register,
+        before: usize,
+    ) -> Option<(Address, X86Register)> {
+        let (_, load) = last_write(previous, entry, before)?;
+        let memory = load.memory_operand()?;
+        let is_load = matches!(load.opcode, X86Opcode::Mov | X86Opcode::Movsxd)
+            && memory.scale == 4
+            && memory.base.map(|register| register.parent()) == Some(base.parent());
+        if !is_load || !Self::is_image_base(file_read, previous, base, before) {
+            return None;
+        }
+        let table = file_read
+            .image
+            .image_base
+            .wrapping_add(memory.displacement as u64);
+        Some((table, memory.index?))
+    }
+
+    /// Distinct targets of the table
+    pub fn cases(&self) -> BTreeSet<Address> {
+        self.targets.iter().copied().collect()
+    }
+
+    /// Address of a table read through the memory operand: an absolute displacement, or a
+    /// displacement from a register that holds the image base
+    fn table_address(
+        file_read: &FileRead,
+        memory: &MemoryOperand,
+        previous: &[&Instruction],
+        before: usize,
+    ) -> Option<Address> {
+        match memory.base {
+            None => Some(memory.displacement as u64),
+            Some(base) if Self::is_image_base(file_read, previous, base, before) => Some(
+                file_read
+                    .image
+                    .image_base
+                    .wrapping_add(memory.displacement as u64),
+            ),
+            Some(_) => None,
+        }
+    }
+
+    /// Returns if the register holds the image base when the instruction at `before` executes,
+    /// because of a `lea reg, [rip + ...]` that points to the first byte of the image
+    fn is_image_base(
+        file_read: &FileRead,
+        previous: &[&Instruction],
+        register: X86Register,
+        before: usize,
+    ) -> bool {
+        last_write(previous, register, before).is_some_and(|(_, lea)| {
+            lea.opcode == X86Opcode::Lea && lea.memory_target() == Some(file_read.image.image_base)
+        })
+    }
+
+    /// Number of entries of the table, from the comparison of the index with the bound before a
+    /// conditional jump to the default case. The index can be copied (`movsxd rax, ecx`),
+    /// rebased (`sub ecx, 2`) or read from an index table.
+    fn bound(file_read: &FileRead, previous: &[&Instruction], index: X86Register) -> Option<Bound> {
+        let mut index = index.parent();
+        let mut index_table = None;
+
+        for (position, instruction) in previous.iter().enumerate().rev() {
+            let destination = match instruction.operands.first() {
+                Some(X86Operand::Register(register)) => Some(register.parent()),
+                _ => None,
+            };
+
+            if instruction.opcode == X86Opcode::Cmp && destination == Some(index) {
+                let X86Operand::Immediate(bound) = *instruction.operands.get(1)? else {
+                    return None;
+                };
+                let cases = match previous.get(position + 1).map(|jcc| jcc.opcode) {
+                    // ja/jbe: the index is valid up to the bound included
+                    Some(X86Opcode::Ja | X86Opcode::Jbe) => bound as u64 + 1,
+                    // jae/jb: the bound is excluded
+                    Some(X86Opcode::Jae | X86Opcode::Jb) => bound as u64,
+                    _ => return None,
+                };
+                return Some(Bound { cases, index_table });
+            }
+            if destination != Some(index) {
+                continue;
+            }
+
+            match (instruction.opcode, instruction.operands.get(1)) {
+                (
+                    X86Opcode::Mov | X86Opcode::Movsxd | X86Opcode::Movzx,
+                    Some(X86Operand::Register(source)),
+                ) => index = source.parent(),
+                // movzx eax, byte ptr [base + index + index_rva]
+                (X86Opcode::Movzx, Some(X86Operand::Memory(memory)))
+                    if memory.size == 8 && index_table.is_none() =>
+                {
+                    index_table = Some(Self::table_address(file_read, memory, previous, position)?);
+                    index = memory.index?.parent();
+                }
+                // switches whose first case is not zero rebase the index
+                (X86Opcode::Add | X86Opcode::Sub, Some(X86Operand::Immediate(_))) => {}
+                _ => return None,
+            }
+        }
+        None
+    }
+}
+
+/// Bound of the index of a jump table
+struct Bound {
+    /// number of valid values of the index
+    cases: u64,
+    /// table that translates the index into an index of the jump table
+    index_table: Option<Address>,
+}
+
+/// Returns the last instruction before `before` that writes the register, with its position
+fn last_write<'a>(
+    previous: &[&'a Instruction],
+    register: X86Register,
+    before: usize,
+) -> Option<(usize, &'a Instruction)> {
+    previous[..before.min(previous.len())]
+        .iter()
+        .enumerate()
+        .rev()
+        .find(|(_, instruction)| {
+            !matches!(instruction.opcode, X86Opcode::Cmp | X86Opcode::Test)
+                && matches!(
+                    instruction.operands.first(),
+                    Some(X86Operand::Register(destination))
+                        if destination.parent() == register.parent()
+                )
+        })
+        .map(|(position, instruction)| (position, *instruction))
+}
+
+/// Upper bound for the number of entries of a table: tables without a recognized bound are read
+/// until the first entry that is not code
+const MAXIMUM_CASES: u64 = 512;
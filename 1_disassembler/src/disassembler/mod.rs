@@ -1,4 +1,24 @@
+/// Exports the detection of tricks used against disassemblers
+pub mod anti_disassembly;
+/// Exports the decoder that turns bytes into instructions
+pub mod decoder;
 /// Exports disassembler
 pub mod disassemble;
+/// Exports the result of the disassembler
+pub mod disassembly;
+/// Exports the search of functions from their prologue
+pub mod function_finder;
+/// Exports the gap scanner of the hybrid strategy
+pub mod hybrid;
 /// Export instruction parser
 pub mod instruction_parser;
+/// Exports the recovery of jump tables used by indirect jumps
+pub mod jump_table;
+/// Exports the scan of data sections for pointers to code
+pub mod pointer_scan;
+/// Exports the worklist-based recursive traversal
+pub mod recursive_traversal;
+/// Exports the superset disassembly that decodes every byte offset
+pub mod superset;
+/// Exports the cross-references between code and data
+pub mod xrefs;
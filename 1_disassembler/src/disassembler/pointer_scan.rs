@@ -0,0 +1,109 @@
+use crate::disassembler::decoder::InstructionDecoder;
+use crate::disassembler::disassemble::MAXIMUM_SIZE_X86_INSTR;
+use crate::disassembler::function_finder::FunctionFinder;
+use crate::pe::address::{Address, Rva};
+use crate::pe::file_read::FileRead;
+use crate::pe::relocations::RelocationType;
+
+/// A pointer to code stored in a section that is not executable (e.g. a vtable or a table of
+/// callbacks in .rdata)
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DataReference {
+    /// address where the pointer is stored
+    pub location: Address,
+    /// address of the code it points to
+    pub target: Address,
+    /// the location is patched by a base relocation, so the value is certainly an address
+    pub relocated: bool,
+}
+
+/// Scans the sections that are not executable for pointer-sized values that point to plausible
+/// instruction starts. When the binary has base relocations, they list every absolute address
+/// stored in the image: only the relocated locations are read. Otherwise every aligned value is
+/// a candidate.
+pub struct PointerScanner<'a> {
+    /// the binary to scan
+    file_read: &'a FileRead,
+    /// decoder used to check the targets
+    decoder: &'a InstructionDecoder,
+}
+
+impl<'a> PointerScanner<'a> {
+    /// Creates a scanner for the binary
+    pub fn new(file_read: &'a FileRead, decoder: &'a InstructionDecoder) -> Self {
+        Self { file_read, decoder }
+    }
+
+    /// Pointers to code stored in the sections that are not executable, sorted by location
+    pub fn scan(&self) -> Vec<DataReference> {
+        let mut references = vec![];
+        for section in &self.file_read.translator.sections {
+            if section.is_executable() {
+                continue;
+            }
+            let start = section.virtual_address;
+            let size = section.mapped_size();
+
+            if !self.file_read.relocations.is_empty() {
+                for (rva, kind) in self.file_read.relocations.in_range(start, size) {
+                    references.extend(self.relocated_pointer(rva, kind));
+                }
+                continue;
+            }
+
+            let first = self.file_read.translator.rva_to_va(start).0;
+            let end = first + size as Address;
+            let mut location = first.next_multiple_of(POINTER_SIZE);
+            while location + POINTER_SIZE <= end {
+                match self.file_read.image.read_u64(location) {
+                    Ok(target) if self.is_code(target) => references.push(DataReference {
+                        location,
+                        target,
+                        relocated: false,
+                    }),
+                    _ => {}
+                }
+                location += POINTER_SIZE;
+            }
+        }
+        references
+    }
+
+    /// Reference stored at the location of a base relocation, if it points to code
+    fn relocated_pointer(&self, rva: Rva, kind: RelocationType) -> Option<DataReference> {
+        let location = self.file_read.translator.rva_to_va(rva).0;
+        let target = match kind {
+            RelocationType::Dir64 => self.file_read.image.read_u64(location),
+            RelocationType::HighLow => self.file_read.image.read_u32(location).map(u64::from),
+            _ => return None,
+        }
+        .ok()?;
+        self.is_code(target).then_some(DataReference {
+            location,
+            target,
+            relocated: true,
+        })
+    }
+
+    /// Returns if the address is a plausible instruction start: inside an executable region, not
+    /// padding, and decoded to an instruction that user code can execute
+    fn is_code(&self, address: Address) -> bool {
+        let Some(region) = self.file_read.executable_region(address) else {
+            return false;
+        };
+        if FunctionFinder::is_padding(self.file_read, address) {
+            return false;
+        }
+
+        let max_size = MAXIMUM_SIZE_X86_INSTR.min((region.end() - address) as usize);
+        let Ok(bytes) = self.file_read.image.read_bounded(address, max_size) else {
+            return false;
+        };
+        self.decoder
+            .decode(bytes, address)
+            .is_some_and(|instruction| instruction.is_user_mode())
+    }
+}
+
+/// Size of the pointers of a 64-bit image, which are aligned on their size
+const POINTER_SIZE: Address = 8;
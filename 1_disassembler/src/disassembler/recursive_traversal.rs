@@ -0,0 +1,335 @@
+use crate::disassembler::anti_disassembly::AntiDisassembly;
+use crate::disassembler::decoder::InstructionDecoder;
+use crate::disassembler::disassemble::{Disassembler, MAXIMUM_SIZE_X86_INSTR};
+use crate::disassembler::jump_table::JumpTable;
+use crate::pe::address::Address;
+use crate::pe::file_read::FileRead;
+use crate::x86::branch::BranchKind;
+use crate::x86::instruction::Instruction;
+use anyhow::Result;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+/// Recursive traversal driven by an explicit worklist: every address in the worklist starts a run
+/// of instructions that follows the fall-through until the control flow stops (e.g. ret, jmp),
+/// while branch targets are added to the worklist. No recursion is involved, so the depth of the
+/// control flow graph does not matter.
+///
+/// Direct calls add their target to the worklist as a new function, and the run continues after
+/// the call unless the callee never returns (e.g. `ExitProcess`). Indirect jumps through a jump
+/// table add every case to the worklist.
+///
+/// A branch can land inside an instruction already disassembled (a trick used by obfuscators):
+/// the bytes are then decoded again from the target, and both decodings are kept.
+pub struct RecursiveTraversal<'a> {
+    /// the binary to disassemble
+    file_read: &'a FileRead,
+    /// decoder used for every instruction
+    decoder: &'a InstructionDecoder,
+    /// addresses that still have to be disassembled: branch targets are pushed to the back and
+    /// disassembled first, roots are pushed to the front
+    worklist: VecDeque<Address>,
+    /// instructions disassembled so far, keyed by address. This is also the visited set: an
+    /// address is not disassembled twice.
+    instructions: BTreeMap<Address, Instruction>,
+    /// first instruction of every function found: roots that are functions and call targets
+    functions: BTreeSet<Address>,
+    /// functions already analyzed to know if they return to their caller
+    no_return: BTreeMap<Address, bool>,
+    /// calls whose callee never returns: the next instruction is not executed after them
+    no_return_calls: BTreeSet<Address>,
+    /// jump tables recovered for indirect jumps, keyed by the address of the jump
+    jump_tables: BTreeMap<Address, JumpTable>,
+}
+
+impl<'a> RecursiveTraversal<'a> {
+    /// Creates a traversal with an empty worklist
+    pub fn new(file_read: &'a FileRead, decoder: &'a InstructionDecoder) -> Self {
+        Self {
+            file_read,
+            decoder,
+            worklist: VecDeque::new(),
+            instructions: BTreeMap::new(),
+            functions: BTreeSet::new(),
+            no_return: BTreeMap::new(),
+            no_return_calls: BTreeSet::new(),
+            jump_tables: BTreeMap::new(),
+        }
+    }
+
+    /// Add an address from which the traversal starts. Roots are disassembled in the order they
+    /// are added.
+    pub fn add_root(&mut self, address: Address) {
+        self.worklist.push_front(address);
+    }
+
+    /// Add the first instruction of a function: the traversal starts from there, and the address
+    /// is recorded as a function entry
+    pub fn add_function(&mut self, address: Address) {
+        self.functions.insert(address);
+        self.add_root(address);
+    }
+
+    /// First instruction of every function found so far
+    pub fn functions(&self) -> &BTreeSet<Address> {
+        &self.functions
+    }
+
+    /// Calls found so far whose callee never returns
+    pub fn no_return_calls(&self) -> &BTreeSet<Address> {
+        &self.no_return_calls
+    }
+
+    /// Jump tables recovered so far, keyed by the address of the indirect jump
+    pub fn jump_tables(&self) -> &BTreeMap<Address, JumpTable> {
+        &self.jump_tables
+    }
+
+    /// Returns if an instruction starting at the address was already disassembled
+    pub fn is_visited(&self, address: Address) -> bool {
+        self.instructions.contains_key(&address)
+    }
+
+    /// Returns the instruction that covers the address, if any: the address is either its first
+    /// byte or one of the following ones
+    pub fn instruction_containing(&self, address: Address) -> Option<&Instruction> {
+        self.instructions
+            .range(..=address)
+            .next_back()
+            .map(|(_, instruction)| instruction)
+            .filter(|instruction| {
+                address < instruction.address + instruction.instruction_size as Address
+            })
+    }
+
+    /// Disassemble until the worklist is empty. Returns the addresses of the instructions
+    /// disassembled by this call.
+    pub fn run(&mut self) -> Result<Vec<Address>> {
+        let mut disassembled = vec![];
+        while let Some(start) = self.worklist.pop_back() {
+            self.disassemble_run(start, &mut disassembled)?;
+        }
+        Ok(disassembled)
+    }
+
+    /// Instructions disassembled so far, keyed by address
+    pub fn instructions(&self) -> &BTreeMap<Address, Instruction> {
+        &self.instructions
+    }
+
+    /// Instructions disassembled, sorted by address
+    pub fn into_instructions(self) -> BTreeMap<Address, Instruction> {
+        self.instructions
+    }
+
+    /// Disassemble the instructions that execute one after the other from `start`, until one of
+    /// them stops the fall-through or the run reaches code already disassembled
+    fn disassemble_run(&mut self, start: Address, disassembled: &mut Vec<Address>) -> Result<()> {
+        let mut current_address = start;
+        let mut previous: Option<Address> = None;
+
+        while !self.is_visited(current_address) {
+            let Some(instruction) = self.decode(current_address) else {
+                break;
+            };
+
+            // conditional jumps and calls continue with the next instruction, like any other
+            // instruction
+            let mut continue_to_disassemble = instruction.falls_through();
+
+            if instruction.change_cfg() {
+                if let Some(current_target) = instruction.get_target()? {
+                    // `call $+5` reads the instruction pointer, it does not call a function
+                    if instruction.is_call() && current_target != instruction.next_address() {
+                        self.functions.insert(current_target);
+                        if self.is_no_return_function(current_target, 0) {
+                            self.no_return_calls.insert(current_address);
+                            continue_to_disassemble = false;
+                        }
+                    }
+                    if !self.is_visited(current_target) {
+                        self.worklist.push_back(current_target);
+                    }
+                }
+            }
+
+            if instruction.branch_kind() == Some(BranchKind::UnconditionalIndirect) {
+                self.recover_jump_table(&instruction);
+            }
+
+            // a conditional jump after the one with the opposite condition is always taken: the
+            // bytes that follow are usually junk meant to be decoded by mistake
+            if previous
+                .and_then(|previous| self.instructions.get(&previous))
+                .is_some_and(|previous| AntiDisassembly::is_complementary(previous, &instruction))
+            {
+                continue_to_disassemble = false;
+            }
+
+            // calls of imported functions that terminate the process or the thread
+            if instruction.is_call() && Self::is_no_return_import(&instruction) {
+                self.no_return_calls.insert(current_address);
+                continue_to_disassemble = false;
+            }
+
+            // code never falls through the end of a runtime function: what follows is either
+            // another function or padding
+            let next_address = current_address + instruction.instruction_size as Address;
+            if self
+                .file_read
+                .runtime_function(current_address)
+                .is_some_and(|entry| {
+                    self.file_read.translator.rva_to_va(entry.function.end).0 == next_address
+                })
+            {
+                continue_to_disassemble = false;
+            }
+
+            self.instructions.insert(current_address, instruction);
+            disassembled.push(current_address);
+            if !continue_to_disassemble {
+                break;
+            }
+            previous = Some(current_address);
+            current_address = next_address;
+        }
+
+        Ok(())
+    }
+
+    /// Recover the jump table used by an indirect jump from the instructions that precede it,
+    /// and add every case to the worklist
+    fn recover_jump_table(&mut self, jump: &Instruction) {
+        // instructions that execute right before the jump, without gaps
+        let mut previous = vec![];
+        let mut next_address = jump.address;
+        for (_, instruction) in self.instructions.range(..jump.address).rev() {
+            if previous.len() == JUMP_TABLE_WINDOW || instruction.next_address() != next_address {
+                break;
+            }
+            previous.push(instruction);
+            next_address = instruction.address;
+        }
+        previous.reverse();
+
+        let Some(jump_table) = JumpTable::recover(self.file_read, jump, &previous) else {
+            return;
+        };
+        for target in jump_table.cases() {
+            if !self.is_visited(target) {
+                self.worklist.push_back(target);
+            }
+        }
+        self.jump_tables.insert(jump.address, jump_table);
+    }
+
+    /// Decode the instruction reached by the traversal at the address, if it is inside an
+    /// executable region. Bytes that do not decode are reported.
+    fn decode(&self, address: Address) -> Option<Instruction> {
+        // addresses outside of every executable region stop the traversal
+        self.file_read.executable_region(address)?;
+
+        let instruction = self.probe(address);
+        if instruction.is_none() {
+            log::warn!("No instruction decoded at 0x{:x}", address);
+        }
+        instruction
+    }
+
+    /// Decode the instruction at the address, if it is inside an executable region, without
+    /// reporting failures: used by the speculative analyses that only look ahead
+    fn probe(&self, address: Address) -> Option<Instruction> {
+        let end_address = self.file_read.executable_region(address)?.end();
+
+        let max_size = MAXIMUM_SIZE_X86_INSTR.min((end_address - address) as usize);
+        let buffer_instruction = self.file_read.image.read_bounded(address, max_size).ok()?;
+        let mut instruction = self.decoder.decode(buffer_instruction, address)?;
+        Disassembler::annotate(self.file_read, &mut instruction);
+        Some(instruction)
+    }
+
+    /// Returns if the instruction reaches, through its IAT slot, an imported function that never
+    /// returns
+    fn is_no_return_import(instruction: &Instruction) -> bool {
+        instruction
+            .import
+            .as_ref()
+            .and_then(|import| import.function_name())
+            .is_some_and(|name| NO_RETURN_IMPORTS.contains(&name))
+    }
+
+    /// Returns if the function never returns to its caller. Only the straight-line code at the
+    /// beginning of the function is analyzed, which is enough for the small thunks compilers
+    /// emit: `__fastfail` wrappers (`mov ecx, code; int 0x29`), jumps to `ExitProcess` through
+    /// the IAT, or wrappers that call another function that does not return.
+    fn is_no_return_function(&mut self, address: Address, depth: usize) -> bool {
+        if let Some(no_return) = self.no_return.get(&address) {
+            return *no_return;
+        }
+
+        let mut no_return = false;
+        let mut current_address = address;
+        for _ in 0..NO_RETURN_SCAN_LIMIT {
+            let Some(instruction) = self.probe(current_address) else {
+                break;
+            };
+
+            if instruction.is_no_return() || Self::is_no_return_import(&instruction) {
+                no_return = true;
+                break;
+            }
+
+            match instruction.branch_kind() {
+                // thunks jump to the real function, wrappers call it
+                Some(BranchKind::UnconditionalDirect | BranchKind::CallDirect) => {
+                    let target = instruction.get_target().ok().flatten();
+                    if let Some(target) = target.filter(|_| depth < NO_RETURN_MAXIMUM_DEPTH) {
+                        if self.is_no_return_function(target, depth + 1) {
+                            no_return = true;
+                            break;
+                        }
+                    }
+                }
+                Some(BranchKind::CallIndirect) | None => {}
+                Some(_) => break,
+            }
+            if !instruction.falls_through() {
+                break;
+            }
+
+            current_address += instruction.instruction_size as Address;
+        }
+
+        self.no_return.insert(address, no_return);
+        no_return
+    }
+}
+
+/// Imported functions that never return to their caller
+const NO_RETURN_IMPORTS: &[&str] = &[
+    "ExitProcess",
+    "ExitThread",
+    "FreeLibraryAndExitThread",
+    "RtlExitUserProcess",
+    "RtlExitUserThread",
+    "RaiseFailFastException",
+    "_CxxThrowException",
+    "_invalid_parameter_noinfo_noreturn",
+    "__std_terminate",
+    "_amsg_exit",
+    "_exit",
+    "_Exit",
+    "abort",
+    "exit",
+    "longjmp",
+    "quick_exit",
+    "terminate",
+];
+
+/// Number of instructions analyzed at the beginning of a function to know if it returns
+const NO_RETURN_SCAN_LIMIT: usize = 16;
+
+/// Number of thunks and wrappers followed to know if a function returns
+const NO_RETURN_MAXIMUM_DEPTH: usize = 4;
+
+/// Number of instructions before an indirect jump analyzed to recover its jump table
+const JUMP_TABLE_WINDOW: usize = 16;
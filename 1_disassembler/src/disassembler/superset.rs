@@ -0,0 +1,275 @@
+use crate::disassembler::decoder::InstructionDecoder;
+use crate::disassembler::disassemble::MAXIMUM_SIZE_X86_INSTR;
+use crate::pe::address::Address;
+use crate::pe::file_read::FileRead;
+use crate::x86::instruction::Instruction;
+use crate::x86::opcode::{OpcodeCategory, X86Opcode};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Superset disassembly: an instruction is decoded at every byte offset of the executable
+/// regions, so that the candidates overlap and the real instructions are always among them,
+/// whatever tricks are used to confuse a linear sweep. Candidates are linked to their successors
+/// (the next instruction and the direct branch target) and pruned:
+///
+/// - candidates that do not decode, or decode to instructions user code cannot execute, are
+///   invalid
+/// - a candidate whose successor is invalid is invalid too, and so on backwards
+/// - a candidate that jumps into the middle of a confirmed instruction is invalid
+///
+/// Confirmed instructions are the ones reached by the recursive traversal. The candidates left
+/// get a probability of being an instruction start from how common their opcodes are.
+pub struct Superset {
+    /// valid candidates, keyed by address: pruning removes them
+    candidates: BTreeMap<Address, Instruction>,
+    /// instructions known to be code, never pruned
+    confirmed: BTreeMap<Address, Address>,
+    /// calls whose callee never returns: the next byte is not their successor
+    no_return_calls: BTreeSet<Address>,
+    /// candidates made only of bytes that compilers use to pad functions
+    padding: BTreeSet<Address>,
+}
+
+impl Superset {
+    /// Decode a candidate at every byte offset of every executable region, and prune the ones
+    /// that cannot be instructions. `confirmed` are the instructions already known to be code.
+    pub fn new(
+        file_read: &FileRead,
+        decoder: &InstructionDecoder,
+        confirmed: &BTreeMap<Address, Instruction>,
+        no_return_calls: &BTreeSet<Address>,
+    ) -> Self {
+        let mut candidates = BTreeMap::new();
+        let mut padding = BTreeSet::new();
+        for region in &file_read.executable_regions {
+            for address in region.start..region.end() {
+                let max_size = MAXIMUM_SIZE_X86_INSTR.min((region.end() - address) as usize);
+                let Ok(bytes) = file_read.image.read_bounded(address, max_size) else {
+                    continue;
+                };
+                // the instructions reached by the traversal are code, whatever they are
+                let instruction = match confirmed.get(&address) {
+                    Some(instruction) => instruction.clone(),
+                    None => match decoder.decode(bytes, address) {
+                        Some(instruction) if instruction.is_user_mode() => instruction,
+                        _ => continue,
+                    },
+                };
+                let bytes = &bytes[..instruction.instruction_size];
+                if PADDING.iter().any(|byte| bytes.iter().all(|b| b == byte)) {
+                    padding.insert(address);
+                }
+                candidates.insert(address, instruction);
+            }
+        }
+
+        let mut superset = Self {
+            candidates,
+            confirmed: confirmed
+                .values()
+                .map(|instruction| (instruction.address, instruction.next_address()))
+                .collect(),
+            no_return_calls: no_return_calls.clone(),
+            padding,
+        };
+        superset.prune();
+        superset
+    }
+
+    /// Returns if a candidate that starts at the address survived the pruning
+    pub fn is_candidate(&self, address: Address) -> bool {
+        self.candidates.contains_key(&address)
+    }
+
+    /// Valid candidates, keyed by address
+    pub fn candidates(&self) -> &BTreeMap<Address, Instruction> {
+        &self.candidates
+    }
+
+    /// Probability that an instruction starts at every address of the region that starts at
+    /// `start` and ends before `end`, indexed by offset from `start`
+    pub fn probabilities(&self, start: Address, end: Address) -> Vec<f64> {
+        let chains = self
+            .candidates
+            .range(start..end)
+            .map(|(address, _)| (*address, self.chain_score(*address)))
+            .collect::<BTreeMap<Address, f64>>();
+
+        // candidates targeted by a likely candidate are more likely to be code
+        let referenced = chains
+            .iter()
+            .filter(|(_, score)| **score >= ACCEPTANCE_PROBABILITY)
+            .filter_map(|(address, _)| self.target(&self.candidates[address]))
+            .collect::<BTreeSet<Address>>();
+
+        (start..end)
+            .map(|address| {
+                let Some(score) = chains.get(&address) else {
+                    return 0.0;
+                };
+                if self.confirmed.contains_key(&address) {
+                    return 1.0;
+                }
+                let mut probability = *score;
+                if referenced.contains(&address) {
+                    probability += (1.0 - probability) * REFERENCE_WEIGHT;
+                }
+                // the bytes already belong to confirmed instructions
+                let instruction = &self.candidates[&address];
+                if (address..instruction.next_address()).any(|byte| self.is_confirmed_byte(byte)) {
+                    probability *= OVERLAP_PENALTY;
+                }
+                probability
+            })
+            .collect()
+    }
+
+    /// Remove the candidates whose successors are not candidates, until every candidate left
+    /// only leads to candidates
+    fn prune(&mut self) {
+        let mut predecessors: BTreeMap<Address, Vec<Address>> = BTreeMap::new();
+        let mut worklist = vec![];
+        for (address, instruction) in &self.candidates {
+            for successor in self.successors(instruction) {
+                predecessors.entry(successor).or_default().push(*address);
+                if !self.candidates.contains_key(&successor) {
+                    worklist.push(*address);
+                }
+            }
+            // jumps into the middle of an instruction that is known to be code
+            let into_confirmed = self.target(instruction).is_some_and(|target| {
+                self.is_confirmed_byte(target) && !self.confirmed.contains_key(&target)
+            });
+            if into_confirmed {
+                worklist.push(*address);
+            }
+        }
+
+        while let Some(address) = worklist.pop() {
+            if self.confirmed.contains_key(&address) || self.candidates.remove(&address).is_none() {
+                continue;
+            }
+            if let Some(predecessors) = predecessors.get(&address) {
+                worklist.extend(predecessors);
+            }
+        }
+    }
+
+    /// Addresses that execute after the candidate: the next instruction if the candidate falls
+    /// through, and its direct branch target
+    fn successors(&self, instruction: &Instruction) -> Vec<Address> {
+        let mut successors = vec![];
+        if instruction.falls_through() && !self.no_return_calls.contains(&instruction.address) {
+            successors.push(instruction.next_address());
+        }
+        successors.extend(self.target(instruction));
+        successors
+    }
+
+    /// Direct branch target of the candidate, if any
+    fn target(&self, instruction: &Instruction) -> Option<Address> {
+        instruction.get_target().ok().flatten()
+    }
+
+    /// Returns if the byte is covered by a confirmed instruction
+    fn is_confirmed_byte(&self, address: Address) -> bool {
+        self.confirmed
+            .range(..=address)
+            .next_back()
+            .is_some_and(|(_, end)| address < *end)
+    }
+
+    /// Likelihood of the candidate, averaged with the mean likelihood of the candidates executed
+    /// one after the other from it: real code is made of common instructions, while the bytes
+    /// decoded from the wrong offset soon run into unusual ones. The candidate itself weighs as
+    /// much as the ones that follow, which would otherwise hide the padding before a function.
+    fn chain_score(&self, address: Address) -> f64 {
+        let Some(first) = self.candidates.get(&address) else {
+            return 0.0;
+        };
+        let mut total = 0.0;
+        let mut count = 0;
+        let mut current = address;
+        while let Some(instruction) = self.candidates.get(&current) {
+            total += self.likelihood(instruction);
+            count += 1;
+            if count == CHAIN_LENGTH || !instruction.falls_through() {
+                break;
+            }
+            current = instruction.next_address();
+        }
+        (self.likelihood(first) + total / count as f64) / 2.0
+    }
+
+    /// Likelihood of a candidate in compiled user code, from its opcode
+    fn likelihood(&self, instruction: &Instruction) -> f64 {
+        if self.padding.contains(&instruction.address) {
+            return PADDING_LIKELIHOOD;
+        }
+        if COMMON_OPCODES.contains(&instruction.opcode) {
+            return COMMON_LIKELIHOOD;
+        }
+        match instruction.opcode.category() {
+            OpcodeCategory::General
+            | OpcodeCategory::ControlFlow
+            | OpcodeCategory::Sse
+            | OpcodeCategory::Avx => USUAL_LIKELIHOOD,
+            _ => RARE_LIKELIHOOD,
+        }
+    }
+}
+
+/// Opcodes that make up most of the code emitted by compilers
+const COMMON_OPCODES: &[X86Opcode] = &[
+    X86Opcode::Add,
+    X86Opcode::And,
+    X86Opcode::Call,
+    X86Opcode::Cmp,
+    X86Opcode::Dec,
+    X86Opcode::Imul,
+    X86Opcode::Inc,
+    X86Opcode::Je,
+    X86Opcode::Jmp,
+    X86Opcode::Jne,
+    X86Opcode::Lea,
+    X86Opcode::Mov,
+    X86Opcode::Movsxd,
+    X86Opcode::Movzx,
+    X86Opcode::Nop,
+    X86Opcode::Or,
+    X86Opcode::Pop,
+    X86Opcode::Push,
+    X86Opcode::Ret,
+    X86Opcode::Shl,
+    X86Opcode::Shr,
+    X86Opcode::Sub,
+    X86Opcode::Test,
+    X86Opcode::Xor,
+];
+
+/// Likelihood of the common opcodes
+const COMMON_LIKELIHOOD: f64 = 0.9;
+
+/// Likelihood of the other general purpose, control flow and vector opcodes
+const USUAL_LIKELIHOOD: f64 = 0.5;
+
+/// Likelihood of the opcodes rarely found in compiled code (e.g. x87, MMX, string instructions)
+const RARE_LIKELIHOOD: f64 = 0.2;
+
+/// Likelihood of the candidates made only of padding (e.g. int3, `add byte ptr [rax], al`):
+/// they are instructions, but not code that executes
+const PADDING_LIKELIHOOD: f64 = 0.1;
+
+/// Bytes that compilers put between functions: int3 and zero
+const PADDING: [u8; 2] = [0xcc, 0x00];
+
+/// Number of candidates executed one after the other that are scored together
+const CHAIN_LENGTH: usize = 8;
+
+/// How much of the missing probability a candidate gains when a likely candidate branches to it
+const REFERENCE_WEIGHT: f64 = 0.5;
+
+/// Factor applied to the probability of candidates that overlap confirmed instructions
+const OVERLAP_PENALTY: f64 = 0.1;
+
+/// Minimum probability of a candidate considered to be an instruction start
+pub const ACCEPTANCE_PROBABILITY: f64 = 0.5;
@@ -0,0 +1,282 @@
+use crate::disassembler::disassembly::Disassembly;
+use crate::pe::address::Address;
+use crate::x86::branch::BranchKind;
+use crate::x86::instruction::Instruction;
+use crate::x86::opcode::X86Opcode;
+use crate::x86::operands::X86Operand;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+/// How the source of a cross-reference uses its target
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum XrefKind {
+    /// code calls the target: a direct call, or a call through the pointer stored at the target
+    /// (e.g. the IAT slot of an imported function)
+    Call,
+    /// code jumps to the target: direct jump, conditional jump, case of a jump table or jump
+    /// through the pointer stored at the target
+    Jump,
+    /// code reads memory at the target (e.g. `mov eax, dword ptr [rip + ...]`)
+    Read,
+    /// code writes memory at the target (e.g. `mov dword ptr [rip + ...], eax`). Instructions
+    /// that read and write their destination (e.g. `inc` or `add dword ptr [rip + ...], eax`)
+    /// are both a read and a write.
+    Write,
+    /// code computes the address of the target without accessing it (`lea`, or an immediate
+    /// patched by a base relocation)
+    AddressTaken,
+    /// a pointer to the target is stored in data
+    DataPointer,
+}
+
+impl Display for XrefKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Call => "call",
+            Self::Jump => "jump",
+            Self::Read => "read",
+            Self::Write => "write",
+            Self::AddressTaken => "address-taken",
+            Self::DataPointer => "data-pointer",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// A reference from an instruction or a data location to an address
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Xref {
+    /// address of the instruction, or of the data, that references the target
+    pub source: Address,
+    /// address referenced
+    pub target: Address,
+    /// how the source uses the target
+    pub kind: XrefKind,
+}
+
+/// Every cross-reference found by the disassembler, keyed by target and by source: code to code
+/// (calls and jumps), code to data (reads, writes and addresses taken) and data to code
+/// (pointers stored in data sections)
+#[derive(Clone, Debug, Default)]
+pub struct XrefDatabase {
+    /// references to every target, sorted by source
+    by_target: BTreeMap<Address, Vec<Xref>>,
+    /// references from every source, sorted by target
+    by_source: BTreeMap<Address, Vec<Xref>>,
+}
+
+impl XrefDatabase {
+    /// Collect the cross-references of the instructions, jump tables and data references of a
+    /// disassembly
+    pub fn build(disassembly: &Disassembly) -> Self {
+        let mut database = Self::default();
+        for instruction in disassembly.instructions() {
+            for (target, kind) in Self::references(instruction) {
+                database.add(Xref {
+                    source: instruction.address,
+                    target,
+                    kind,
+                });
+            }
+        }
+        for jump_table in disassembly.jump_tables.values() {
+            for case in jump_table.cases() {
+                database.add(Xref {
+                    source: jump_table.jump,
+                    target: case,
+                    kind: XrefKind::Jump,
+                });
+            }
+        }
+        for reference in &disassembly.data_references {
+            database.add(Xref {
+                source: reference.location,
+                target: reference.target,
+                kind: XrefKind::DataPointer,
+            });
+        }
+        database
+    }
+
+    /// Add a cross-reference, unless it is already known
+    pub fn add(&mut self, xref: Xref) {
+        let xrefs = self.by_target.entry(xref.target).or_default();
+        let Err(position) = xrefs.binary_search(&xref) else {
+            return;
+        };
+        xrefs.insert(position, xref);
+
+        // the same references, ordered by target first
+        let key = |xref: &Xref| (xref.target, xref.source, xref.kind);
+        let xrefs = self.by_source.entry(xref.source).or_default();
+        let position = xrefs.partition_point(|other| key(other) < key(&xref));
+        xrefs.insert(position, xref);
+    }
+
+    /// References to the address, sorted by source
+    pub fn to(&self, target: Address) -> &[Xref] {
+        self.by_target.get(&target).map_or(&[], Vec::as_slice)
+    }
+
+    /// References from the address (an instruction or a data location), sorted by target
+    pub fn from(&self, source: Address) -> &[Xref] {
+        self.by_source.get(&source).map_or(&[], Vec::as_slice)
+    }
+
+    /// Every reference, sorted by target
+    pub fn xrefs(&self) -> impl Iterator<Item = &Xref> {
+        self.by_target.values().flatten()
+    }
+
+    /// Addresses referenced at least once
+    pub fn targets(&self) -> impl Iterator<Item = Address> + '_ {
+        self.by_target.keys().copied()
+    }
+
+    /// Instructions that call the function at the address
+    pub fn callers(&self, target: Address) -> impl Iterator<Item = Address> + '_ {
+        self.sources(target, XrefKind::Call)
+    }
+
+    /// Instructions that read the memory at the address
+    pub fn readers(&self, target: Address) -> impl Iterator<Item = Address> + '_ {
+        self.sources(target, XrefKind::Read)
+    }
+
+    /// Instructions that write the memory at the address
+    pub fn writers(&self, target: Address) -> impl Iterator<Item = Address> + '_ {
+        self.sources(target, XrefKind::Write)
+    }
+
+    /// Sources of the references of a kind to the address
+    fn sources(&self, target: Address, kind: XrefKind) -> impl Iterator<Item = Address> + '_ {
+        self.to(target)
+            .iter()
+            .filter(move |xref| xref.kind == kind)
+            .map(|xref| xref.source)
+    }
+
+    /// Addresses referenced by the instruction, with how it uses them
+    fn references(instruction: &Instruction) -> Vec<(Address, XrefKind)> {
+        let mut references = vec![];
+
+        if let Some(target) = instruction.get_target().ok().flatten() {
+            let kind = match instruction.branch_kind() {
+                Some(BranchKind::CallDirect) => XrefKind::Call,
+                _ => XrefKind::Jump,
+            };
+            references.push((target, kind));
+        }
+
+        if let Some(target) = instruction.memory_target() {
+            let destination = matches!(instruction.operands.first(), Some(X86Operand::Memory(_)));
+            let kind = if instruction.opcode == X86Opcode::Lea {
+                XrefKind::AddressTaken
+            } else if instruction.branch_kind() == Some(BranchKind::CallIndirect) {
+                XrefKind::Call
+            } else if instruction.branch_kind() == Some(BranchKind::UnconditionalIndirect) {
+                XrefKind::Jump
+            } else if destination && Self::is_store(instruction.opcode) {
+                XrefKind::Write
+            } else if destination && !NO_WRITE_OPCODES.contains(&instruction.opcode) {
+                // read-modify-write: the destination is read before it is written
+                references.push((target, XrefKind::Read));
+                XrefKind::Write
+            } else {
+                XrefKind::Read
+            };
+            references.push((target, kind));
+        }
+
+        for operand in &instruction.operands {
+            if let X86Operand::Pointer(target) = operand {
+                references.push((*target, XrefKind::AddressTaken));
+            }
+        }
+        references
+    }
+
+    /// Returns if the opcode writes its first operand without reading it: moves, stores and
+    /// conditional sets
+    fn is_store(opcode: X86Opcode) -> bool {
+        let mnemonic = opcode.mnemonic();
+        ["mov", "vmov", "set", "stos"]
+            .iter()
+            .any(|prefix| mnemonic.starts_with(prefix))
+            || STORE_OPCODES.contains(&opcode)
+    }
+}
+
+/// Address named by a symbol: an exported symbol or one of its aliases, an imported function
+/// (`ExitProcess` or `KERNEL32.dll!ExitProcess`) whose address is its IAT slot, a label (e.g.
+/// `sub_140001000`), or an address in hexadecimal (with or without `0x`)
+pub fn resolve(disassembly: &Disassembly, symbol: &str) -> Option<Address> {
+    let exported = || {
+        disassembly
+            .exports
+            .iter()
+            .find(|(_, export)| {
+                export.to_string() == symbol || export.aliases.iter().any(|alias| alias == symbol)
+            })
+            .map(|(address, _)| *address)
+    };
+    let imported = || {
+        disassembly
+            .imports
+            .iter()
+            .find(|(_, import)| {
+                import.function_name() == Some(symbol) || import.to_string() == symbol
+            })
+            .map(|(address, _)| *address)
+    };
+    let labeled = || {
+        disassembly
+            .labels
+            .iter()
+            .find(|(_, label)| label.as_str() == symbol)
+            .map(|(address, _)| *address)
+    };
+    let hexadecimal = || {
+        let digits = symbol.strip_prefix("0x").unwrap_or(symbol);
+        Address::from_str_radix(digits, 16).ok()
+    };
+
+    exported()
+        .or_else(imported)
+        .or_else(labeled)
+        .or_else(hexadecimal)
+}
+
+/// Opcodes whose first operand is only read, even when it is in memory
+const NO_WRITE_OPCODES: &[X86Opcode] = &[
+    X86Opcode::Bt,
+    X86Opcode::Cmp,
+    X86Opcode::Push,
+    X86Opcode::Test,
+];
+
+/// Opcodes that write their first operand without reading it, besides moves, stores and
+/// conditional sets
+const STORE_OPCODES: &[X86Opcode] = &[
+    X86Opcode::Fbstp,
+    X86Opcode::Fist,
+    X86Opcode::Fistp,
+    X86Opcode::Fisttp,
+    X86Opcode::Fnsave,
+    X86Opcode::Fnstcw,
+    X86Opcode::Fnstenv,
+    X86Opcode::Fnstsw,
+    X86Opcode::Fst,
+    X86Opcode::Fstcw,
+    X86Opcode::Fstp,
+    X86Opcode::Fstsw,
+    X86Opcode::Fxsave,
+    X86Opcode::Fxsave64,
+    X86Opcode::Pop,
+    X86Opcode::Sgdt,
+    X86Opcode::Sidt,
+    X86Opcode::Sldt,
+    X86Opcode::Smsw,
+    X86Opcode::Stmxcsr,
+    X86Opcode::Str,
+];
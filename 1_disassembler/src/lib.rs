@@ -1,7 +1,10 @@
+//! InsPEctor: analysis of PE executables targeting Intel x86_64, starting from disassembly
 #![warn(clippy::all)]
 #![warn(missing_docs)]
 // we don't want to scream about InsPEctor name
 #![allow(non_snake_case)]
+/// Exports control flow graphs
+pub mod cfg;
 /// Exports disassembler
 pub mod disassembler;
 /// Exports pe
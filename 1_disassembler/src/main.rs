@@ -1,18 +1,256 @@
-use anyhow::{anyhow, Result};
-use InsPEctor::disassembler::disassemble::{Disassembler, DisassemblerStrategy};
+use anyhow::{anyhow, bail, Result};
+use std::path::PathBuf;
+use InsPEctor::cfg::call_graph::{CallGraph, CallGraphNode};
+use InsPEctor::cfg::dot::DotExporter;
+use InsPEctor::cfg::program::ProgramGraph;
+use InsPEctor::disassembler::decoder::InstructionDecoder;
+use InsPEctor::disassembler::disassemble::{
+    Disassembler, DisassemblerOptions, DisassemblerStrategy,
+};
+use InsPEctor::disassembler::disassembly::Disassembly;
+use InsPEctor::disassembler::xrefs;
 use InsPEctor::pe::binary_parser::BinaryParser;
 
+const USAGE: &str = "usage: ./InsPEctor [options] [pe_executable] [xrefs <address|symbol>]
+
+    --linear                   disassemble with a linear sweep instead of a recursive traversal
+    --hybrid                   after the recursive traversal, look for code in the bytes it missed
+    --superset                 decode at every byte offset and keep the likely instruction starts
+    --seed-pointers            also start the traversal from pointers to code stored in data
+    --find-functions           after the recursive traversal, look for functions from their prologue
+    --dot <file>               write the control flow graph of every function in a single DOT file
+    --dot-dir <directory>      write one DOT file per function inside the directory
+    --call-graph-dot <file>    write the call graph in a DOT file
+    --call-graph-json <file>   write the call graph in a JSON file
+    --paths-to <function>      print the call paths from the entrypoint to an imported function
+
+    xrefs <address|symbol>     print the references to an address, a label or an imported function";
+
+/// Options given on the command line
+#[derive(Default)]
+struct Options {
+    /// the executable to analyze
+    path: Option<String>,
+    /// strategy chosen by `--linear`, `--hybrid` or `--superset`, if any
+    strategy: Option<DisassemblerStrategy>,
+    /// start the traversal from the pointers to code found in data sections
+    seed_pointers: bool,
+    /// look for the functions that no branch reaches from their prologue
+    find_functions: bool,
+    /// DOT file with the graph of every function
+    dot: Option<PathBuf>,
+    /// directory for one DOT file per function
+    dot_dir: Option<PathBuf>,
+    /// DOT file with the call graph
+    call_graph_dot: Option<PathBuf>,
+    /// JSON file with the call graph
+    call_graph_json: Option<PathBuf>,
+    /// imported function reached from the entrypoint
+    paths_to: Option<String>,
+    /// address or symbol whose cross-references are printed instead of the listing
+    xrefs: Option<String>,
+}
+
+impl Options {
+    /// Parse the command line arguments, without the name of the program
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self> {
+        let mut options = Options::default();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--linear" => options.set_strategy(DisassemblerStrategy::LinearSweep)?,
+                "--hybrid" => options.set_strategy(DisassemblerStrategy::Hybrid)?,
+                "--superset" => options.set_strategy(DisassemblerStrategy::Superset)?,
+                "--seed-pointers" => options.seed_pointers = true,
+                "--find-functions" => options.find_functions = true,
+                "--dot" => options.dot = Some(Self::value(&mut args, &arg)?.into()),
+                "--dot-dir" => options.dot_dir = Some(Self::value(&mut args, &arg)?.into()),
+                "--call-graph-dot" => {
+                    options.call_graph_dot = Some(Self::value(&mut args, &arg)?.into())
+                }
+                "--call-graph-json" => {
+                    options.call_graph_json = Some(Self::value(&mut args, &arg)?.into())
+                }
+                "--paths-to" => options.paths_to = Some(Self::value(&mut args, &arg)?),
+                // a subcommand follows the executable, which may be named like one
+                "xrefs" if options.path.is_some() && options.xrefs.is_none() => {
+                    options.xrefs = Some(Self::value(&mut args, &arg)?)
+                }
+                flag if flag.starts_with("--") => bail!("unknown option {}\n{}", flag, USAGE),
+                _ if options.path.is_some() => bail!("too many executables\n{}", USAGE),
+                _ => options.path = Some(arg),
+            }
+        }
+        if options.seed_pointers
+            && matches!(options.strategy, Some(DisassemblerStrategy::LinearSweep))
+        {
+            bail!(
+                "--seed-pointers cannot be combined with --linear\n{}",
+                USAGE
+            );
+        }
+        if options.find_functions
+            && matches!(
+                options.strategy,
+                Some(DisassemblerStrategy::LinearSweep | DisassemblerStrategy::Superset)
+            )
+        {
+            bail!(
+                "--find-functions requires the recursive traversal or --hybrid\n{}",
+                USAGE
+            );
+        }
+        Ok(options)
+    }
+
+    /// Choose the strategy: the options that choose one exclude each other
+    fn set_strategy(&mut self, strategy: DisassemblerStrategy) -> Result<()> {
+        if self.strategy.is_some() {
+            bail!(
+                "--linear, --hybrid and --superset cannot be combined\n{}",
+                USAGE
+            );
+        }
+        self.strategy = Some(strategy);
+        Ok(())
+    }
+
+    /// Value of an option that requires one
+    fn value(args: &mut impl Iterator<Item = String>, option: &str) -> Result<String> {
+        args.next()
+            .ok_or_else(|| anyhow!("{} requires a value\n{}", option, USAGE))
+    }
+}
+
 fn main() -> Result<()> {
     env_logger::init();
 
-    let args = std::env::args().collect::<Vec<String>>();
-    if args.len() < 2 {
-        return Err(anyhow!("usage: ./InsPEctor [pe_executable]"));
+    let options = Options::parse(std::env::args().skip(1))?;
+    let Some(path) = &options.path else {
+        return Err(anyhow!(USAGE));
+    };
+
+    let file_pe = BinaryParser::read(path)?;
+    let entrypoint = file_pe.entrypoint;
+    let strategy = options
+        .strategy
+        .unwrap_or(DisassemblerStrategy::RecursiveTraversal);
+    let disassembler_options = DisassemblerOptions {
+        seed_data_pointers: options.seed_pointers,
+        scan_data_pointers: options.xrefs.is_some(),
+        find_functions: options.find_functions,
+    };
+    let disassembly = Disassembler::extract_with_options(
+        file_pe,
+        strategy,
+        &InstructionDecoder::default(),
+        disassembler_options,
+    )?;
+    if options.xrefs.is_none() {
+        print_listing(&disassembly);
     }
 
-    let file_pe = BinaryParser::read(&args[1])?;
-    // let strategy = DisassemblerStrategy::LinearSweep;
-    let strategy = DisassemblerStrategy::RecursiveTraversal;
-    let _disassembled_instructions = Disassembler::extract(file_pe, strategy)?;
+    let program = ProgramGraph::build(&disassembly);
+    if let Some(dot) = &options.dot {
+        DotExporter::write_program(&program, &disassembly.labels, dot)?;
+    }
+    if let Some(directory) = &options.dot_dir {
+        DotExporter::write_functions(&program, &disassembly.labels, directory)?;
+    }
+
+    let call_graph = CallGraph::build(&program);
+    if let Some(dot) = &options.call_graph_dot {
+        call_graph.write_dot(&disassembly.labels, dot)?;
+    }
+    if let Some(json) = &options.call_graph_json {
+        call_graph.write_json(&disassembly.labels, json)?;
+    }
+    if let Some(function_name) = &options.paths_to {
+        let targets = call_graph.imports(function_name).collect::<Vec<_>>();
+        if targets.is_empty() {
+            bail!("{} is not called by the executable", function_name);
+        }
+        for target in targets {
+            let paths = call_graph.paths(entrypoint, target);
+            if paths.truncated {
+                log::warn!(
+                    "Too many paths to {}: only the first {} are printed",
+                    target,
+                    paths.paths.len()
+                );
+            }
+            for path in paths.paths {
+                let path = path
+                    .iter()
+                    .map(|node| CallGraph::node_name(node, &disassembly.labels))
+                    .collect::<Vec<String>>();
+                println!("{}", path.join(" -> "));
+            }
+        }
+    }
+    if let Some(symbol) = &options.xrefs {
+        let Some(target) = xrefs::resolve(&disassembly, symbol) else {
+            bail!("{} is neither an address nor a known symbol", symbol);
+        };
+        for xref in disassembly.xrefs.to(target) {
+            // the functions whose code contains the source, data has none
+            let functions = program
+                .functions_containing(xref.source)
+                .map(|graph| {
+                    CallGraph::node_name(&CallGraphNode::Function(graph.entry), &disassembly.labels)
+                })
+                .collect::<Vec<String>>();
+            let location = if functions.is_empty() {
+                "data".to_string()
+            } else {
+                functions.join(", ")
+            };
+            println!("0x{:x}\t{}\t{}", xref.source, xref.kind, location);
+        }
+    }
     Ok(())
 }
+
+/// Print the instructions of every executable region with their labels, then what the analyses
+/// found besides the instructions
+fn print_listing(disassembly: &Disassembly) {
+    for region in &disassembly.regions {
+        println!("Section {}", region.region.name);
+        for instruction in &region.instructions {
+            if let Some(label) = disassembly.labels.get(&instruction.address) {
+                println!("{}:", label);
+            }
+            // the comment holds the annotations found during disassembly
+            let comment = match &instruction.import {
+                Some(import) => format!("\t; {}", import),
+                None => String::new(),
+            };
+            match disassembly.instruction_probability(instruction.address) {
+                Some(probability) => println!(
+                    "{:x}, {:.2}, {}{}",
+                    instruction.address, probability, instruction, comment
+                ),
+                None => println!("{:x}, {}{}", instruction.address, instruction, comment),
+            }
+        }
+    }
+
+    for (address, confidence) in &disassembly.function_confidence {
+        println!(
+            "Function found from its prologue: 0x{:x}, confidence {:.2}",
+            address, confidence
+        );
+    }
+    for region in disassembly
+        .code_regions
+        .iter()
+        .filter(|r| r.confidence < 1.0)
+    {
+        println!(
+            "Code found in a gap: 0x{:x}-0x{:x}, confidence {:.2}",
+            region.start, region.end, region.confidence
+        );
+    }
+    for trick in &disassembly.anti_disassembly {
+        println!("Anti-disassembly at {}", trick);
+    }
+}
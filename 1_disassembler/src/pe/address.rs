@@ -1,2 +1,135 @@
-/// An address is the information necessary to encode a location
+use crate::pe::section::Section;
+use anyhow::{anyhow, bail, Result};
+use std::fmt::{Display, Formatter};
+
+/// An address is the information necessary to encode a location. Unless stated otherwise, an
+/// address is a virtual address (VA), that is the image base plus a relative virtual address.
 pub type Address = u64;
+
+/// A relative virtual address (RVA): the distance of a location from the image base once the
+/// binary is loaded in memory
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Rva(pub u32);
+
+/// A virtual address (VA): the image base plus a relative virtual address. This is the address
+/// other tools (debuggers, disassemblers) show for a given location.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VirtualAddress(pub Address);
+
+/// A file offset: the position of a byte inside the file stored on disk
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FileOffset(pub u64);
+
+impl Display for Rva {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "rva:0x{:x}", self.0)
+    }
+}
+
+impl Display for VirtualAddress {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "0x{:x}", self.0)
+    }
+}
+
+impl Display for FileOffset {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "offset:0x{:x}", self.0)
+    }
+}
+
+/// Translates addresses between the three address spaces of a PE file: relative virtual
+/// addresses, virtual addresses and file offsets. RVA and VA only differ by the image base, while
+/// moving from or to a file offset requires knowing which section contains the address.
+#[derive(Clone, Debug, Default)]
+pub struct AddressTranslator {
+    /// preferred address at which the image is loaded
+    pub image_base: Address,
+    /// size of the headers, which are mapped at RVA 0 and are identical in memory and on disk
+    pub size_of_headers: u32,
+    /// sections of the binary
+    pub sections: Vec<Section>,
+}
+
+impl AddressTranslator {
+    /// Creates a new translator
+    pub fn new(image_base: Address, size_of_headers: u32, sections: Vec<Section>) -> Self {
+        Self {
+            image_base,
+            size_of_headers,
+            sections,
+        }
+    }
+
+    /// Translate a relative virtual address into a virtual address
+    pub fn rva_to_va(&self, rva: Rva) -> VirtualAddress {
+        VirtualAddress(self.image_base + rva.0 as Address)
+    }
+
+    /// Translate a virtual address into a relative virtual address
+    pub fn va_to_rva(&self, va: VirtualAddress) -> Result<Rva> {
+        let rva =
+            va.0.checked_sub(self.image_base)
+                .ok_or_else(|| anyhow!("{} is below the image base 0x{:x}", va, self.image_base))?;
+        let rva = u32::try_from(rva).map_err(|_| anyhow!("{} is outside the image", va))?;
+        Ok(Rva(rva))
+    }
+
+    /// Returns the section that contains the relative virtual address
+    pub fn section_of_rva(&self, rva: Rva) -> Result<&Section> {
+        self.sections
+            .iter()
+            .find(|s| s.contains_rva(rva))
+            .ok_or_else(|| anyhow!("{} does not belong to any section", rva))
+    }
+
+    /// Returns the section that contains the virtual address
+    pub fn section_of_va(&self, va: VirtualAddress) -> Result<&Section> {
+        self.section_of_rva(self.va_to_rva(va)?)
+    }
+
+    /// Translate a relative virtual address into a file offset
+    pub fn rva_to_offset(&self, rva: Rva) -> Result<FileOffset> {
+        if rva.0 < self.size_of_headers {
+            return Ok(FileOffset(rva.0 as u64));
+        }
+
+        let section = self.section_of_rva(rva)?;
+        let delta = rva.0 - section.virtual_address.0;
+        if delta >= section.size_of_raw_data {
+            bail!(
+                "{} belongs to section {} but it is not backed by the file",
+                rva,
+                section.name
+            );
+        }
+
+        Ok(FileOffset(section.pointer_to_raw_data.0 + delta as u64))
+    }
+
+    /// Translate a file offset into a relative virtual address
+    pub fn offset_to_rva(&self, offset: FileOffset) -> Result<Rva> {
+        if offset.0 < self.size_of_headers as u64 {
+            return Ok(Rva(offset.0 as u32));
+        }
+
+        let section = self
+            .sections
+            .iter()
+            .find(|s| s.contains_offset(offset))
+            .ok_or_else(|| anyhow!("{} does not belong to any section", offset))?;
+        let delta = (offset.0 - section.pointer_to_raw_data.0) as u32;
+
+        Ok(Rva(section.virtual_address.0 + delta))
+    }
+
+    /// Translate a virtual address into a file offset
+    pub fn va_to_offset(&self, va: VirtualAddress) -> Result<FileOffset> {
+        self.rva_to_offset(self.va_to_rva(va)?)
+    }
+
+    /// Translate a file offset into a virtual address
+    pub fn offset_to_va(&self, offset: FileOffset) -> Result<VirtualAddress> {
+        Ok(self.rva_to_va(self.offset_to_rva(offset)?))
+    }
+}
@@ -1,6 +1,12 @@
-use crate::pe::address::Address;
+use crate::pe::address::{AddressTranslator, Rva};
+use crate::pe::exceptions::ExceptionEntry;
+use crate::pe::exports::Export;
 use crate::pe::file_read::FileRead;
-use anyhow::{anyhow, bail, Result};
+use crate::pe::imports::Import;
+use crate::pe::relocations::Relocations;
+use crate::pe::section::{ExecutableRegion, Section};
+use crate::pe::virtual_image::VirtualImage;
+use anyhow::{bail, Result};
 use goblin::Object;
 use std::fs;
 use std::path::Path;
@@ -19,28 +25,89 @@ impl BinaryParser {
             _ => bail!("We do not support any files beside PE"),
         };
 
-        let entrypoint = pe.entry;
-        let text_section_offset = pe
+        let (size_of_headers, size_of_image) = pe
+            .header
+            .optional_header
+            .map(|h| {
+                (
+                    h.windows_fields.size_of_headers,
+                    h.windows_fields.size_of_image,
+                )
+            })
+            .unwrap_or_default();
+        let relocation_directory = pe
+            .header
+            .optional_header
+            .and_then(|h| h.data_directories.get_base_relocation_table().copied());
+        let sections: Vec<Section> = pe.sections.iter().map(Section::from).collect();
+        let image = VirtualImage::new(
+            &buffer,
+            pe.image_base as u64,
+            size_of_image,
+            size_of_headers,
+            &sections,
+        )?;
+        let translator = AddressTranslator::new(pe.image_base as u64, size_of_headers, sections);
+
+        // every section that is both readable and executable may contain code: regions are
+        // reported as virtual addresses, which is how the virtual image is addressed
+        let executable_regions: Vec<ExecutableRegion> = translator
             .sections
             .iter()
-            .find(|s| {
-                entrypoint > s.virtual_address as usize
-                    && entrypoint < (s.virtual_address + s.virtual_size) as usize
-                    && s.characteristics & (IMAGE_SCN_MEM_READ | IMAGE_SCN_MEM_EXECUTE)
-                        == (IMAGE_SCN_MEM_READ | IMAGE_SCN_MEM_EXECUTE)
+            .filter(|s| {
+                s.characteristics & (IMAGE_SCN_MEM_READ | IMAGE_SCN_MEM_EXECUTE)
+                    == (IMAGE_SCN_MEM_READ | IMAGE_SCN_MEM_EXECUTE)
+            })
+            .map(|s| {
+                ExecutableRegion::new(
+                    s.name.clone(),
+                    translator.rva_to_va(s.virtual_address).0,
+                    s.mapped_size() as usize,
+                )
             })
-            .ok_or_else(|| anyhow!("No executable and readable section found"))?;
+            .collect();
+        if executable_regions.is_empty() {
+            bail!("No executable and readable section found");
+        }
 
-        let begin_text_section_addr = text_section_offset.pointer_to_raw_data as Address;
-        let size_text_section = text_section_offset.size_of_raw_data as usize;
+        // DLLs may have no entrypoint at all: in that case the entrypoint is the image base, which
+        // is not part of any executable region
+        let entrypoint = translator.rva_to_va(Rva(pe.entry as u32));
+
+        let imports = pe
+            .import_data
+            .as_ref()
+            .map(|import_data| Import::parse(import_data, pe.is_64))
+            .unwrap_or_default();
+        let exports = pe
+            .export_data
+            .as_ref()
+            .map(|export_data| Export::parse(export_data, &image))
+            .unwrap_or_default();
+        let exceptions = pe
+            .exception_data
+            .as_ref()
+            .map(|exception_data| ExceptionEntry::parse(exception_data, &pe.sections))
+            .unwrap_or_default();
+        let relocations = match relocation_directory {
+            Some(directory) => {
+                Relocations::parse(&image, Rva(directory.virtual_address), directory.size)
+            }
+            None => Relocations::default(),
+        };
 
-        let result = FileRead::new(
+        let mut result = FileRead::new(
             path_str.clone(),
             buffer,
-            begin_text_section_addr,
-            size_text_section,
-            entrypoint as u64,
+            image,
+            translator,
+            executable_regions,
+            entrypoint.0,
         );
+        result.imports = imports;
+        result.exports = exports;
+        result.exceptions = exceptions;
+        result.relocations = relocations;
 
         Ok(result)
     }
@@ -50,3 +117,5 @@ impl BinaryParser {
 pub const IMAGE_SCN_MEM_READ: u32 = 0x40000000;
 /// The section has executable permissions
 pub const IMAGE_SCN_MEM_EXECUTE: u32 = 0x20000000;
+/// The section has writable permissions
+pub const IMAGE_SCN_MEM_WRITE: u32 = 0x80000000;
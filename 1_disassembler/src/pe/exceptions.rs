@@ -0,0 +1,220 @@
+use crate::pe::address::Rva;
+use crate::x86::register::X86Register;
+use goblin::pe::exception::{self, ExceptionData, StackFrameOffset};
+use goblin::pe::section_table::SectionTable;
+
+/// An entry of the exception directory (RUNTIME_FUNCTION): on x64, every function that
+/// allocates stack space or calls other functions has one
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RuntimeFunction {
+    /// relative virtual address of the first byte of the function
+    pub begin: Rva,
+    /// relative virtual address of the first byte after the function
+    pub end: Rva,
+    /// relative virtual address of the unwind information
+    pub unwind_info_address: Rva,
+}
+
+/// Offset from the stack frame where a register is saved
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FrameOffset {
+    /// offset from rsp, after the fixed stack allocation
+    Rsp(u32),
+    /// offset from the frame register
+    FrameRegister(u32),
+}
+
+/// An operation performed by the prolog of a function
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UnwindOperation {
+    /// push of a nonvolatile register
+    PushNonVolatile(X86Register),
+    /// allocation of a fixed-size area on the stack
+    Alloc(u32),
+    /// the frame register is set to rsp plus the frame register offset
+    SetFrameRegister,
+    /// a nonvolatile register is saved on the stack with a mov
+    SaveNonVolatile(X86Register, FrameOffset),
+    /// the lower 64 bits of a nonvolatile xmm register are saved on the stack
+    SaveXmm(X86Register, FrameOffset),
+    /// a nonvolatile xmm register is saved on the stack
+    SaveXmm128(X86Register, FrameOffset),
+    /// describes the epilog of the function (version 2 only)
+    Epilog,
+    /// push of a machine frame, with or without an error code
+    PushMachineFrame(bool),
+    /// reserved operation, without effect
+    Noop,
+}
+
+/// An unwind code: what the instruction that ends at `code_offset` inside the prolog did
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnwindCode {
+    /// offset of the end of the instruction from the beginning of the prolog
+    pub code_offset: u8,
+    /// operation performed by the instruction
+    pub operation: UnwindOperation,
+}
+
+/// Handler invoked by the exception dispatcher for the function
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UnwindHandler {
+    /// relative virtual address of the exception handler
+    Exception(Rva),
+    /// relative virtual address of the termination handler
+    Termination(Rva),
+}
+
+/// The UNWIND_INFO structure associated with a runtime function
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct UnwindInfo {
+    /// version of the structure
+    pub version: u8,
+    /// size of the prolog of the function
+    pub size_of_prolog: u8,
+    /// register used as frame pointer, if the function uses one
+    pub frame_register: Option<X86Register>,
+    /// offset from rsp applied to the frame register when it is established
+    pub frame_register_offset: u32,
+    /// unwind codes, sorted by descending code offset
+    pub unwind_codes: Vec<UnwindCode>,
+    /// the runtime function this entry is chained to: the entry describes a portion of that
+    /// function (e.g. a cold block moved far away)
+    pub chained_info: Option<RuntimeFunction>,
+    /// exception or termination handler
+    pub handler: Option<UnwindHandler>,
+}
+
+/// A runtime function together with its unwind information
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ExceptionEntry {
+    /// boundaries of the function
+    pub function: RuntimeFunction,
+    /// unwind information, if it can be parsed
+    pub unwind_info: Option<UnwindInfo>,
+}
+
+impl ExceptionEntry {
+    /// Extract the runtime functions and their unwind information from the exception directory
+    /// parsed by goblin. Entries are sorted by begin address.
+    pub fn parse(exception_data: &ExceptionData, sections: &[SectionTable]) -> Vec<ExceptionEntry> {
+        let mut entries = vec![];
+
+        for function in exception_data.functions().flatten() {
+            let unwind_info = exception_data
+                .get_unwind_info(function, sections)
+                .ok()
+                .map(|unwind_info| UnwindInfo::from(&unwind_info));
+            entries.push(ExceptionEntry {
+                function: RuntimeFunction::from(&function),
+                unwind_info,
+            });
+        }
+
+        entries.sort_by_key(|entry| entry.function.begin);
+        entries
+    }
+
+    /// Returns if the entry describes a portion of another function
+    pub fn is_chained(&self) -> bool {
+        self.unwind_info
+            .as_ref()
+            .is_some_and(|unwind_info| unwind_info.chained_info.is_some())
+    }
+
+    /// Returns if the relative virtual address is inside the function
+    pub fn contains(&self, rva: Rva) -> bool {
+        rva >= self.function.begin && rva < self.function.end
+    }
+}
+
+impl From<&exception::RuntimeFunction> for RuntimeFunction {
+    fn from(function: &exception::RuntimeFunction) -> Self {
+        Self {
+            begin: Rva(function.begin_address),
+            end: Rva(function.end_address),
+            unwind_info_address: Rva(function.unwind_info_address),
+        }
+    }
+}
+
+impl From<&exception::UnwindInfo<'_>> for UnwindInfo {
+    fn from(unwind_info: &exception::UnwindInfo) -> Self {
+        let unwind_codes = unwind_info
+            .unwind_codes()
+            .flatten()
+            .map(|code| UnwindCode {
+                code_offset: code.code_offset,
+                operation: UnwindOperation::from(code.operation),
+            })
+            .collect();
+        let handler = unwind_info.handler.map(|handler| match handler {
+            exception::UnwindHandler::ExceptionHandler(rva, _) => {
+                UnwindHandler::Exception(Rva(rva))
+            }
+            exception::UnwindHandler::TerminationHandler(rva, _) => {
+                UnwindHandler::Termination(Rva(rva))
+            }
+        });
+
+        Self {
+            version: unwind_info.version,
+            size_of_prolog: unwind_info.size_of_prolog,
+            // register 0 (rax) is never used as frame pointer: it means no frame pointer
+            frame_register: match unwind_info.frame_register.0 {
+                0 => None,
+                _ => Some(register(unwind_info.frame_register)),
+            },
+            frame_register_offset: unwind_info.frame_register_offset,
+            unwind_codes,
+            chained_info: unwind_info.chained_info.as_ref().map(RuntimeFunction::from),
+            handler,
+        }
+    }
+}
+
+impl From<exception::UnwindOperation> for UnwindOperation {
+    fn from(operation: exception::UnwindOperation) -> Self {
+        use exception::UnwindOperation as Operation;
+        match operation {
+            Operation::PushNonVolatile(register) => {
+                UnwindOperation::PushNonVolatile(self::register(register))
+            }
+            Operation::Alloc(size) => UnwindOperation::Alloc(size),
+            Operation::SetFPRegister => UnwindOperation::SetFrameRegister,
+            Operation::SaveNonVolatile(register, offset) => {
+                UnwindOperation::SaveNonVolatile(self::register(register), offset.into())
+            }
+            Operation::SaveXMM(register, offset) => {
+                UnwindOperation::SaveXmm(self::register(register), offset.into())
+            }
+            Operation::SaveXMM128(register, offset) => {
+                UnwindOperation::SaveXmm128(self::register(register), offset.into())
+            }
+            Operation::Epilog => UnwindOperation::Epilog,
+            Operation::PushMachineFrame(error_code) => {
+                UnwindOperation::PushMachineFrame(error_code)
+            }
+            Operation::Noop => UnwindOperation::Noop,
+        }
+    }
+}
+
+impl From<StackFrameOffset> for FrameOffset {
+    fn from(offset: StackFrameOffset) -> Self {
+        match offset {
+            StackFrameOffset::RSP(offset) => FrameOffset::Rsp(offset),
+            StackFrameOffset::FP(offset) => FrameOffset::FrameRegister(offset),
+        }
+    }
+}
+
+/// Register used by the unwind codes: general purpose registers are numbered from 0 to 15, rip is
+/// 16 and xmm registers go from 17 to 32
+fn register(register: exception::Register) -> X86Register {
+    match register.0 {
+        16 => X86Register::Rip,
+        number @ 17.. => X86Register::xmm(number - 17),
+        number => X86Register::gpr64(number),
+    }
+}
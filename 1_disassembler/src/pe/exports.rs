@@ -0,0 +1,78 @@
+use crate::pe::address::Rva;
+use crate::pe::virtual_image::VirtualImage;
+use goblin::pe::export::{ExportAddressTableEntry, ExportData};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+/// A symbol exported by the binary, usually a DLL
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Export {
+    /// name of the symbol: symbols exported only by ordinal have no name
+    pub name: Option<String>,
+    /// other names exported for the same ordinal, sorted like the export name table
+    pub aliases: Vec<String>,
+    /// ordinal of the symbol, that is its index in the export address table plus the ordinal base
+    pub ordinal: u16,
+    /// relative virtual address of the symbol. For forwarders, it is the address of the forwarder
+    /// string.
+    pub rva: Rva,
+    /// the symbol is implemented by another DLL (e.g. `NTDLL.RtlAllocateHeap`)
+    pub forwarder: Option<String>,
+}
+
+impl Export {
+    /// Extract the exports from the export directory parsed by goblin. Strings are read from the
+    /// mapped image.
+    pub fn parse(export_data: &ExportData, image: &VirtualImage) -> Vec<Export> {
+        let ordinal_base = export_data.export_directory_table.ordinal_base;
+        let mut exports = vec![];
+
+        // the ordinal table maps every name to an index of the export address table
+        let mut names: BTreeMap<usize, Vec<String>> = BTreeMap::new();
+        for (ordinal, name_rva) in export_data
+            .export_ordinal_table
+            .iter()
+            .zip(&export_data.export_name_pointer_table)
+        {
+            if let Ok(name) = image.read_c_string(image.va(Rva(*name_rva))) {
+                names.entry(*ordinal as usize).or_default().push(name);
+            }
+        }
+
+        for (index, entry) in export_data.export_address_table.iter().enumerate() {
+            let (rva, forwarder) = match entry {
+                // unused ordinals have an empty slot
+                ExportAddressTableEntry::ExportRVA(0) => continue,
+                ExportAddressTableEntry::ExportRVA(rva) => (*rva, None),
+                ExportAddressTableEntry::ForwarderRVA(rva) => {
+                    (*rva, image.read_c_string(image.va(Rva(*rva))).ok())
+                }
+            };
+
+            let mut aliases = names.remove(&index).unwrap_or_default().into_iter();
+            exports.push(Export {
+                name: aliases.next(),
+                aliases: aliases.collect(),
+                ordinal: (ordinal_base as usize + index) as u16,
+                rva: Rva(rva),
+                forwarder,
+            });
+        }
+
+        exports
+    }
+
+    /// Returns if the symbol is implemented by another DLL
+    pub fn is_forwarder(&self) -> bool {
+        self.forwarder.is_some()
+    }
+}
+
+impl Display for Export {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.name {
+            Some(name) => write!(f, "{}", name),
+            None => write!(f, "#{}", self.ordinal),
+        }
+    }
+}
@@ -1,18 +1,35 @@
-use crate::pe::address::Address;
+use crate::pe::address::{Address, AddressTranslator, VirtualAddress};
+use crate::pe::exceptions::ExceptionEntry;
+use crate::pe::exports::Export;
+use crate::pe::imports::Import;
+use crate::pe::relocations::Relocations;
+use crate::pe::section::ExecutableRegion;
+use crate::pe::virtual_image::VirtualImage;
+use anyhow::Result;
 
-/// Contains basic information about the file read, such as the buffer, path, and address of the
-/// potential text section
+/// Contains basic information about the file read, such as the buffer, path, and the regions that
+/// contain code
 pub struct FileRead {
     /// path of the file read
     pub path: String,
     /// all the bytes contained in the file
     pub bytes: Vec<u8>,
-    /// first address of the executable section
-    pub instr_address: Address,
-    /// size of the executable section
-    pub size: usize,
-    /// entrypoint
+    /// the file mapped in memory, as the loader would do: code and data should be read from here
+    pub image: VirtualImage,
+    /// translates addresses between virtual addresses, relative virtual addresses and file offsets
+    pub translator: AddressTranslator,
+    /// every executable and readable section of the binary
+    pub executable_regions: Vec<ExecutableRegion>,
+    /// virtual address of the entrypoint
     pub entrypoint: Address,
+    /// functions imported from other DLLs
+    pub imports: Vec<Import>,
+    /// symbols exported by the binary
+    pub exports: Vec<Export>,
+    /// runtime functions from the exception directory, sorted by begin address
+    pub exceptions: Vec<ExceptionEntry>,
+    /// locations that contain absolute addresses, from the base relocation directory
+    pub relocations: Relocations,
 }
 
 impl FileRead {
@@ -20,16 +37,84 @@ impl FileRead {
     pub fn new(
         path: String,
         bytes: Vec<u8>,
-        instr_address: Address,
-        size: usize,
+        image: VirtualImage,
+        translator: AddressTranslator,
+        executable_regions: Vec<ExecutableRegion>,
         entrypoint: Address,
     ) -> Self {
         Self {
             path,
             bytes,
-            instr_address,
-            size,
+            image,
+            translator,
+            executable_regions,
             entrypoint,
+            imports: vec![],
+            exports: vec![],
+            exceptions: vec![],
+            relocations: Relocations::default(),
         }
     }
+
+    /// Returns the executable region that contains the virtual address
+    pub fn executable_region(&self, address: Address) -> Option<&ExecutableRegion> {
+        self.executable_regions
+            .iter()
+            .find(|region| region.contains(address))
+    }
+
+    /// Returns the virtual address of every exported function implemented by the binary, together
+    /// with its export
+    pub fn exported_functions(&self) -> impl Iterator<Item = (Address, &Export)> {
+        self.exports
+            .iter()
+            .filter(|export| !export.is_forwarder())
+            .map(|export| (self.translator.rva_to_va(export.rva).0, export))
+    }
+
+    /// Returns the runtime function that contains the virtual address
+    pub fn runtime_function(&self, address: Address) -> Option<&ExceptionEntry> {
+        let rva = self.translator.va_to_rva(VirtualAddress(address)).ok()?;
+        let index = self
+            .exceptions
+            .partition_point(|entry| entry.function.begin <= rva);
+        self.exceptions[..index]
+            .last()
+            .filter(|entry| entry.contains(rva))
+    }
+
+    /// Returns the function imported through the IAT slot at the virtual address
+    pub fn import_at(&self, address: Address) -> Option<&Import> {
+        self.imports
+            .iter()
+            .find(|import| self.translator.rva_to_va(import.iat_slot).0 == address)
+    }
+
+    /// Move the binary to another image base, as the loader would do when the preferred image base
+    /// is not available: the mapped image and the file bytes are patched with the base
+    /// relocations, and every virtual address is moved accordingly
+    pub fn rebase(&mut self, image_base: Address) -> Result<()> {
+        let delta = image_base.wrapping_sub(self.translator.image_base);
+
+        // the image is checked before it is patched: nothing moves if a relocation is broken
+        self.image.rebase(image_base, &self.relocations)?;
+        for (rva, kind) in &self.relocations.entries {
+            // locations that are not backed by the file only exist in memory
+            let Ok(offset) = self.translator.rva_to_offset(*rva) else {
+                continue;
+            };
+            let offset = offset.0 as usize;
+            if offset + kind.size() <= self.bytes.len() {
+                kind.apply(&mut self.bytes[offset..], delta)?;
+            }
+        }
+
+        self.translator.image_base = image_base;
+        self.entrypoint = self.entrypoint.wrapping_add(delta);
+        for region in self.executable_regions.iter_mut() {
+            region.start = region.start.wrapping_add(delta);
+        }
+
+        Ok(())
+    }
 }
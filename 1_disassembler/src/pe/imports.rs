@@ -0,0 +1,83 @@
+use crate::pe::address::Rva;
+use goblin::pe::import::{ImportData, SyntheticImportLookupTableEntry};
+use std::fmt::{Display, Formatter};
+
+/// How a function is imported from a DLL
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ImportName {
+    /// The function is imported by name
+    Name(String),
+    /// The function is imported by ordinal
+    Ordinal(u16),
+}
+
+/// A function imported from a DLL. The loader writes the address of the function inside the IAT
+/// slot, so every `call qword ptr [rip + ...]` that reads the slot calls the imported function.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Import {
+    /// name of the DLL that exports the function
+    pub dll: String,
+    /// name or ordinal of the function
+    pub name: ImportName,
+    /// relative virtual address of the slot inside the import address table (IAT)
+    pub iat_slot: Rva,
+    /// index inside the export name table of the DLL, used by the loader as a first guess
+    pub hint: u16,
+}
+
+impl Import {
+    /// Extract the imports from the import directory parsed by goblin
+    pub fn parse(import_data: &ImportData, is_64: bool) -> Vec<Import> {
+        let slot_size = if is_64 { 8 } else { 4 };
+        let mut imports = vec![];
+
+        for dll in &import_data.import_data {
+            let iat = dll.import_directory_entry.import_address_table_rva;
+            let Some(lookup_table) = &dll.import_lookup_table else {
+                continue;
+            };
+
+            for (i, entry) in lookup_table.iter().enumerate() {
+                let (name, hint) = match entry {
+                    SyntheticImportLookupTableEntry::HintNameTableRVA((_, entry)) => {
+                        (ImportName::Name(entry.name.to_string()), entry.hint)
+                    }
+                    SyntheticImportLookupTableEntry::OrdinalNumber(ordinal) => {
+                        (ImportName::Ordinal(*ordinal), 0)
+                    }
+                };
+                imports.push(Import {
+                    dll: dll.name.to_string(),
+                    name,
+                    iat_slot: Rva(iat + (i * slot_size) as u32),
+                    hint,
+                });
+            }
+        }
+
+        imports
+    }
+
+    /// Returns the name of the imported function, if it is imported by name
+    pub fn function_name(&self) -> Option<&str> {
+        match &self.name {
+            ImportName::Name(name) => Some(name),
+            ImportName::Ordinal(_) => None,
+        }
+    }
+}
+
+impl Display for ImportName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ImportName::Name(name) => write!(f, "{}", name),
+            ImportName::Ordinal(ordinal) => write!(f, "#{}", ordinal),
+        }
+    }
+}
+
+impl Display for Import {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}!{}", self.dll, self.name)
+    }
+}
@@ -2,5 +2,17 @@
 pub mod address;
 /// Exports the binary parser
 pub mod binary_parser;
+/// Exports the exception directory (function boundaries and unwind information)
+pub mod exceptions;
+/// Exports the symbols exported by the binary
+pub mod exports;
 /// Exports the struct being returned from binary parser
 pub mod file_read;
+/// Exports the functions imported by the binary
+pub mod imports;
+/// Exports the base relocations of the binary
+pub mod relocations;
+/// Exports the sections of the binary
+pub mod section;
+/// Exports the binary mapped in memory
+pub mod virtual_image;
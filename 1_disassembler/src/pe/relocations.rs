@@ -0,0 +1,198 @@
+use crate::pe::address::{Address, Rva};
+use crate::pe::virtual_image::VirtualImage;
+use anyhow::{bail, Result};
+use std::collections::BTreeMap;
+
+/// Type of a base relocation: how the loader patches the location when the image is not loaded at
+/// its preferred image base
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RelocationType {
+    /// padding entry, the location is not patched
+    Absolute,
+    /// the high 16 bits of the difference are added to a 16-bit word
+    High,
+    /// the low 16 bits of the difference are added to a 16-bit word
+    Low,
+    /// the difference is added to a 32-bit pointer
+    HighLow,
+    /// the high 16 bits of the difference are added to a 16-bit word, adjusted by the low 16 bits
+    /// stored in the next entry
+    HighAdj(u16),
+    /// the difference is added to a 64-bit pointer
+    Dir64,
+    /// architecture specific relocation we do not handle
+    Other(u8),
+}
+
+impl RelocationType {
+    /// Decode the type stored in the upper 4 bits of a relocation entry. The parameter of a
+    /// HIGHADJ is in the next entry: it is set to zero here.
+    pub fn from_raw(value: u8) -> Self {
+        match value {
+            IMAGE_REL_BASED_ABSOLUTE => RelocationType::Absolute,
+            IMAGE_REL_BASED_HIGH => RelocationType::High,
+            IMAGE_REL_BASED_LOW => RelocationType::Low,
+            IMAGE_REL_BASED_HIGHLOW => RelocationType::HighLow,
+            IMAGE_REL_BASED_HIGHADJ => RelocationType::HighAdj(0),
+            IMAGE_REL_BASED_DIR64 => RelocationType::Dir64,
+            other => RelocationType::Other(other),
+        }
+    }
+
+    /// Number of bytes patched by the relocation
+    pub fn size(&self) -> usize {
+        match self {
+            RelocationType::Dir64 => 8,
+            RelocationType::HighLow => 4,
+            RelocationType::High | RelocationType::Low | RelocationType::HighAdj(_) => 2,
+            RelocationType::Absolute | RelocationType::Other(_) => 0,
+        }
+    }
+
+    /// Patch the bytes at the location of the relocation, adding the difference between the new
+    /// and the old image base. The types we do not handle leave the bytes untouched.
+    pub fn apply(&self, bytes: &mut [u8], delta: u64) -> Result<()> {
+        let Some(location) = bytes.get_mut(..self.size()) else {
+            bail!("The relocation crosses the end of the buffer");
+        };
+
+        match self {
+            RelocationType::Dir64 => {
+                let value = u64::from_le_bytes(location.try_into()?).wrapping_add(delta);
+                location.copy_from_slice(&value.to_le_bytes());
+            }
+            RelocationType::HighLow => {
+                let value = u32::from_le_bytes(location.try_into()?).wrapping_add(delta as u32);
+                location.copy_from_slice(&value.to_le_bytes());
+            }
+            RelocationType::High => {
+                let value =
+                    u16::from_le_bytes(location.try_into()?).wrapping_add((delta >> 16) as u16);
+                location.copy_from_slice(&value.to_le_bytes());
+            }
+            RelocationType::Low => {
+                let value = u16::from_le_bytes(location.try_into()?).wrapping_add(delta as u16);
+                location.copy_from_slice(&value.to_le_bytes());
+            }
+            RelocationType::HighAdj(low) => {
+                // the loader rounds the 32-bit value to its high 16 bits
+                let value = ((u16::from_le_bytes(location.try_into()?) as u32) << 16)
+                    .wrapping_add(*low as i16 as u32)
+                    .wrapping_add(delta as u32)
+                    .wrapping_add(0x8000);
+                location.copy_from_slice(&((value >> 16) as u16).to_le_bytes());
+            }
+            RelocationType::Absolute | RelocationType::Other(_) => {}
+        }
+
+        Ok(())
+    }
+}
+
+/// The base relocation directory: every location patched by the loader when the image is not
+/// loaded at its preferred image base, that is every absolute address stored in the image
+#[derive(Clone, Debug, Default)]
+pub struct Relocations {
+    /// type of the relocation for every patched location. Padding entries are discarded.
+    pub entries: BTreeMap<Rva, RelocationType>,
+}
+
+impl Relocations {
+    /// Parse the base relocation directory from the mapped image. A malformed block stops the
+    /// parsing: the relocations of the blocks before it are kept, since a broken directory does
+    /// not make the rest of the binary unreadable.
+    pub fn parse(image: &VirtualImage, directory_rva: Rva, directory_size: u32) -> Self {
+        let mut entries = BTreeMap::new();
+        let mut block = image.va(directory_rva);
+        let end = block + directory_size as u64;
+
+        // the directory is a list of blocks, one for each page that contains relocations
+        while block + BLOCK_HEADER_SIZE <= end {
+            match Self::parse_block(image, block, &mut entries) {
+                Ok(size) => block += size,
+                Err(error) => {
+                    log::warn!("Base relocations ignored from 0x{:x}: {}", block, error);
+                    break;
+                }
+            }
+        }
+
+        Self { entries }
+    }
+
+    /// Parse the block of relocations that starts at the virtual address, and return its size
+    fn parse_block(
+        image: &VirtualImage,
+        block: Address,
+        entries: &mut BTreeMap<Rva, RelocationType>,
+    ) -> Result<u64> {
+        let page_rva = image.read_u32(block)?;
+        let block_size = image.read_u32(block + 4)? as u64;
+        if block_size < BLOCK_HEADER_SIZE {
+            bail!("Malformed base relocation block at 0x{:x}", block);
+        }
+
+        let count = (block_size - BLOCK_HEADER_SIZE) / 2;
+        let mut i = 0;
+        while i < count {
+            let entry = image.read_u16(block + BLOCK_HEADER_SIZE + i * 2)?;
+            let mut kind = RelocationType::from_raw((entry >> 12) as u8);
+            i += 1;
+            // the entry after a HIGHADJ is its parameter, not a relocation
+            if let RelocationType::HighAdj(_) = kind {
+                kind = RelocationType::HighAdj(image.read_u16(block + BLOCK_HEADER_SIZE + i * 2)?);
+                i += 1;
+            }
+            if kind == RelocationType::Absolute {
+                continue;
+            }
+            match page_rva.checked_add((entry & 0xfff) as u32) {
+                Some(rva) => {
+                    entries.insert(Rva(rva), kind);
+                }
+                None => log::warn!(
+                    "Base relocation at 0x{:x} is outside the address space",
+                    block
+                ),
+            }
+        }
+        Ok(block_size)
+    }
+
+    /// Returns the relocation at the relative virtual address
+    pub fn get(&self, rva: Rva) -> Option<RelocationType> {
+        self.entries.get(&rva).copied()
+    }
+
+    /// Iterates over the relocations whose location is inside `[start, start + size)`
+    pub fn in_range(
+        &self,
+        start: Rva,
+        size: u32,
+    ) -> impl Iterator<Item = (Rva, RelocationType)> + '_ {
+        self.entries
+            .range(start..Rva(start.0.saturating_add(size)))
+            .map(|(rva, kind)| (*rva, *kind))
+    }
+
+    /// Returns if the binary has no relocations
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Size of the header of a relocation block: page RVA and block size
+const BLOCK_HEADER_SIZE: u64 = 8;
+
+/// Padding entry
+const IMAGE_REL_BASED_ABSOLUTE: u8 = 0;
+/// High 16 bits of the difference
+const IMAGE_REL_BASED_HIGH: u8 = 1;
+/// Low 16 bits of the difference
+const IMAGE_REL_BASED_LOW: u8 = 2;
+/// 32 bits of the difference
+const IMAGE_REL_BASED_HIGHLOW: u8 = 3;
+/// High 16 bits of the difference, adjusted by the next entry
+const IMAGE_REL_BASED_HIGHADJ: u8 = 4;
+/// 64 bits of the difference
+const IMAGE_REL_BASED_DIR64: u8 = 10;
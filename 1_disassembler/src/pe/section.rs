@@ -0,0 +1,103 @@
+use crate::pe::address::{Address, FileOffset, Rva};
+use crate::pe::binary_parser::{IMAGE_SCN_MEM_EXECUTE, IMAGE_SCN_MEM_READ, IMAGE_SCN_MEM_WRITE};
+use goblin::pe::section_table::SectionTable;
+
+/// A section of the PE file, as described by the section table
+#[derive(Clone, Debug, Default)]
+pub struct Section {
+    /// name of the section (e.g. .text)
+    pub name: String,
+    /// relative virtual address of the first byte of the section once loaded in memory
+    pub virtual_address: Rva,
+    /// size of the section once loaded in memory
+    pub virtual_size: u32,
+    /// offset of the first byte of the section inside the file
+    pub pointer_to_raw_data: FileOffset,
+    /// size of the section inside the file
+    pub size_of_raw_data: u32,
+    /// flags that describe the section (permissions, content)
+    pub characteristics: u32,
+}
+
+impl Section {
+    /// Size of the section once loaded in memory. Some linkers leave the virtual size empty, in
+    /// that case the loader maps the raw data.
+    pub fn mapped_size(&self) -> u32 {
+        if self.virtual_size == 0 {
+            self.size_of_raw_data
+        } else {
+            self.virtual_size
+        }
+    }
+
+    /// Returns if the relative virtual address is inside the section once loaded in memory
+    pub fn contains_rva(&self, rva: Rva) -> bool {
+        rva >= self.virtual_address
+            && (rva.0 as u64) < self.virtual_address.0 as u64 + self.mapped_size() as u64
+    }
+
+    /// Returns if the file offset is inside the raw data of the section
+    pub fn contains_offset(&self, offset: FileOffset) -> bool {
+        self.size_of_raw_data > 0
+            && offset >= self.pointer_to_raw_data
+            && offset.0 < self.pointer_to_raw_data.0 + self.size_of_raw_data as u64
+    }
+
+    /// Returns if the section is readable
+    pub fn is_readable(&self) -> bool {
+        self.characteristics & IMAGE_SCN_MEM_READ != 0
+    }
+
+    /// Returns if the section is writable
+    pub fn is_writable(&self) -> bool {
+        self.characteristics & IMAGE_SCN_MEM_WRITE != 0
+    }
+
+    /// Returns if the section is executable
+    pub fn is_executable(&self) -> bool {
+        self.characteristics & IMAGE_SCN_MEM_EXECUTE != 0
+    }
+}
+
+impl From<&SectionTable> for Section {
+    fn from(section: &SectionTable) -> Self {
+        Self {
+            name: String::from_utf8_lossy(&section.name)
+                .trim_end_matches('\0')
+                .to_string(),
+            virtual_address: Rva(section.virtual_address),
+            virtual_size: section.virtual_size,
+            pointer_to_raw_data: FileOffset(section.pointer_to_raw_data as u64),
+            size_of_raw_data: section.size_of_raw_data,
+            characteristics: section.characteristics,
+        }
+    }
+}
+
+/// A range of virtual addresses that contains executable code
+#[derive(Clone, Debug, Default)]
+pub struct ExecutableRegion {
+    /// name of the section the region comes from
+    pub name: String,
+    /// virtual address of the first byte of the region
+    pub start: Address,
+    /// size of the region
+    pub size: usize,
+}
+
+impl ExecutableRegion {
+    /// Creates a new executable region
+    pub fn new(name: String, start: Address, size: usize) -> Self {
+        Self { name, start, size }
+    }
+
+    /// Virtual address of the first byte after the region
+    pub fn end(&self) -> Address {
+        self.start + self.size as Address
+    }
+
+    /// Returns if the virtual address is inside the region
+    pub fn contains(&self, address: Address) -> bool {
+        address >= self.start && address < self.end()
+    }
+}
@@ -0,0 +1,206 @@
+use crate::pe::address::{Address, Rva};
+use crate::pe::relocations::{RelocationType, Relocations};
+use crate::pe::section::Section;
+use anyhow::{anyhow, bail, Result};
+
+/// Size of a page of memory: permissions are tracked with this granularity
+pub const PAGE_SIZE: u64 = 0x1000;
+
+/// Permissions granted to a page of the virtual image
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Permissions {
+    /// the page can be read
+    pub read: bool,
+    /// the page can be written
+    pub write: bool,
+    /// the page can be executed
+    pub execute: bool,
+}
+
+impl Permissions {
+    /// Permissions of the pages that contain a section
+    pub fn from_section(section: &Section) -> Self {
+        Self {
+            read: section.is_readable(),
+            write: section.is_writable(),
+            execute: section.is_executable(),
+        }
+    }
+}
+
+/// The binary as the Windows loader maps it in memory: headers at the image base, every section
+/// at its virtual address with its virtual size. Bytes that are not backed by the file (e.g.
+/// uninitialised data, gaps due to section alignment) are filled with zeros.
+#[derive(Clone, Debug, Default)]
+pub struct VirtualImage {
+    /// virtual address of the first byte of the image
+    pub image_base: Address,
+    /// content of the image, indexed by relative virtual address
+    pub bytes: Vec<u8>,
+    /// permissions for every page of the image
+    pub permissions: Vec<Permissions>,
+}
+
+impl VirtualImage {
+    /// Map a file according to its section table
+    pub fn new(
+        file: &[u8],
+        image_base: Address,
+        size_of_image: u32,
+        size_of_headers: u32,
+        sections: &[Section],
+    ) -> Result<Self> {
+        // the declared size of image should contain every section, but we do not trust it
+        let size = sections
+            .iter()
+            .map(|s| s.virtual_address.0 as u64 + s.mapped_size() as u64)
+            .chain(std::iter::once(size_of_image as u64))
+            .max()
+            .unwrap_or_default();
+        let size = size.div_ceil(PAGE_SIZE) * PAGE_SIZE;
+        if size > MAXIMUM_IMAGE_SIZE {
+            bail!("The image size 0x{:x} is too big to be mapped", size);
+        }
+
+        let mut bytes = vec![0; size as usize];
+        let mut permissions = vec![Permissions::default(); (size / PAGE_SIZE) as usize];
+
+        // headers are mapped as read-only data
+        let headers_size = (size_of_headers as usize).min(file.len()).min(bytes.len());
+        bytes[..headers_size].copy_from_slice(&file[..headers_size]);
+        let headers_pages = (headers_size as u64).div_ceil(PAGE_SIZE) as usize;
+        for page in permissions.iter_mut().take(headers_pages) {
+            page.read = true;
+        }
+
+        for section in sections {
+            let start = section.virtual_address.0 as usize;
+            let mapped_size = section.mapped_size() as usize;
+
+            // only the bytes present in the file are copied, the rest of the section is zero
+            let raw_start = (section.pointer_to_raw_data.0 as usize).min(file.len());
+            let raw_size = (section.size_of_raw_data as usize)
+                .min(mapped_size)
+                .min(file.len() - raw_start);
+            bytes[start..start + raw_size].copy_from_slice(&file[raw_start..raw_start + raw_size]);
+
+            let first_page = start / PAGE_SIZE as usize;
+            let last_page = (start + mapped_size).div_ceil(PAGE_SIZE as usize);
+            for page in &mut permissions[first_page..last_page] {
+                *page = Permissions::from_section(section);
+            }
+        }
+
+        Ok(Self {
+            image_base,
+            bytes,
+            permissions,
+        })
+    }
+
+    /// Size of the image in memory
+    pub fn size(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Translate a virtual address into an index of the image
+    fn index(&self, address: Address) -> Result<usize> {
+        address
+            .checked_sub(self.image_base)
+            .filter(|rva| *rva < self.bytes.len() as u64)
+            .map(|rva| rva as usize)
+            .ok_or_else(|| anyhow!("Address 0x{:x} is outside the image", address))
+    }
+
+    /// Returns if the virtual address is inside the image
+    pub fn contains(&self, address: Address) -> bool {
+        self.index(address).is_ok()
+    }
+
+    /// Returns the virtual address of a relative virtual address
+    pub fn va(&self, rva: Rva) -> Address {
+        self.image_base + rva.0 as Address
+    }
+
+    /// Read exactly `length` bytes starting from a virtual address
+    pub fn read(&self, address: Address, length: usize) -> Result<&[u8]> {
+        let start = self.index(address)?;
+        self.bytes.get(start..start + length).ok_or_else(|| {
+            anyhow!(
+                "Reading 0x{:x} bytes from 0x{:x} crosses the end of the image",
+                length,
+                address
+            )
+        })
+    }
+
+    /// Read up to `length` bytes starting from a virtual address: the read stops at the end of the
+    /// image
+    pub fn read_bounded(&self, address: Address, length: usize) -> Result<&[u8]> {
+        let start = self.index(address)?;
+        let end = (start + length).min(self.bytes.len());
+        Ok(&self.bytes[start..end])
+    }
+
+    /// Read a little endian u16 from a virtual address
+    pub fn read_u16(&self, address: Address) -> Result<u16> {
+        let bytes = self.read(address, 2)?;
+        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    /// Read a little endian u32 from a virtual address
+    pub fn read_u32(&self, address: Address) -> Result<u32> {
+        let bytes = self.read(address, 4)?;
+        Ok(u32::from_le_bytes(bytes.try_into()?))
+    }
+
+    /// Read a little endian u64 from a virtual address
+    pub fn read_u64(&self, address: Address) -> Result<u64> {
+        let bytes = self.read(address, 8)?;
+        Ok(u64::from_le_bytes(bytes.try_into()?))
+    }
+
+    /// Read a NUL terminated string from a virtual address
+    pub fn read_c_string(&self, address: Address) -> Result<String> {
+        let start = self.index(address)?;
+        let length = self.bytes[start..]
+            .iter()
+            .position(|byte| *byte == 0)
+            .ok_or_else(|| anyhow!("The string at 0x{:x} is not terminated", address))?;
+        Ok(String::from_utf8_lossy(&self.bytes[start..start + length]).to_string())
+    }
+
+    /// Move the image to another image base: every location described by a base relocation is
+    /// patched as the loader would do. Every location is checked before the first one is
+    /// patched, so that the image is left untouched on error.
+    pub fn rebase(&mut self, image_base: Address, relocations: &Relocations) -> Result<()> {
+        let delta = image_base.wrapping_sub(self.image_base);
+        for (rva, kind) in &relocations.entries {
+            if rva.0 as usize + kind.size() > self.bytes.len() {
+                bail!("Relocation at {} is outside the image", rva);
+            }
+            if let RelocationType::Other(raw) = kind {
+                log::warn!(
+                    "Relocation type {} at {} is not supported, the location is not patched",
+                    raw,
+                    rva
+                );
+            }
+        }
+
+        for (rva, kind) in &relocations.entries {
+            kind.apply(&mut self.bytes[rva.0 as usize..], delta)?;
+        }
+        self.image_base = image_base;
+        Ok(())
+    }
+
+    /// Permissions of the page that contains the virtual address
+    pub fn permissions(&self, address: Address) -> Option<Permissions> {
+        let index = self.index(address).ok()?;
+        self.permissions.get(index / PAGE_SIZE as usize).copied()
+    }
+}
+
+/// Upper bound for the size of an image we accept to map in memory
+const MAXIMUM_IMAGE_SIZE: u64 = 0x4000_0000;
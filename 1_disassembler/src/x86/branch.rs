@@ -0,0 +1,48 @@
+/// How an instruction transfers control, for instructions that do not simply continue with the
+/// next one
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BranchKind {
+    /// jump to a target encoded in the instruction (e.g. `jmp 0x140001000`)
+    UnconditionalDirect,
+    /// jump to a target read from a register or from memory (e.g. `jmp rax`)
+    UnconditionalIndirect,
+    /// jump taken or not depending on flags or registers (jcc, jrcxz, loop, xbegin): both the
+    /// target and the next instruction can execute
+    Conditional,
+    /// call of a target encoded in the instruction
+    CallDirect,
+    /// call of a target read from a register or from memory (e.g. through the IAT)
+    CallIndirect,
+    /// return to the caller
+    Return,
+    /// software interrupt, system call or instruction that raises an exception (e.g. int3,
+    /// syscall, ud2)
+    Interrupt,
+    /// the processor stops until the next interrupt
+    Halt,
+}
+
+impl BranchKind {
+    /// Returns if the branch is a call, direct or indirect
+    pub fn is_call(&self) -> bool {
+        matches!(self, BranchKind::CallDirect | BranchKind::CallIndirect)
+    }
+
+    /// Returns if the branch is a jump, conditional or not
+    pub fn is_jump(&self) -> bool {
+        matches!(
+            self,
+            BranchKind::UnconditionalDirect
+                | BranchKind::UnconditionalIndirect
+                | BranchKind::Conditional
+        )
+    }
+
+    /// Returns if the target is encoded in the instruction
+    pub fn is_direct(&self) -> bool {
+        matches!(
+            self,
+            BranchKind::UnconditionalDirect | BranchKind::Conditional | BranchKind::CallDirect
+        )
+    }
+}
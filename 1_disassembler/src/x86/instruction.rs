@@ -1,19 +1,29 @@
 use crate::pe::address::Address;
-use crate::x86::opcode::X86Opcode;
+use crate::pe::imports::Import;
+use crate::x86::branch::BranchKind;
+use crate::x86::memory::MemoryOperand;
+use crate::x86::opcode::{X86Flow, X86Opcode};
 use crate::x86::operands::X86Operand;
+use crate::x86::prefix::X86Prefix;
+use crate::x86::register::RegisterClass;
 
 use anyhow::{anyhow, Result};
+use std::fmt::{Display, Formatter};
 /// Represents a concrete instruction for insPEctor.
 #[derive(Clone, Default, Debug)]
 pub struct Instruction {
     /// address for the instruction
     pub address: Address,
+    /// prefixes that change the semantics of the instruction (e.g. lock, rep)
+    pub prefixes: Vec<X86Prefix>,
     /// opcode for the instruction
     pub opcode: X86Opcode,
     /// list of operands
     pub operands: Vec<X86Operand>,
     /// instruction size
     pub instruction_size: usize,
+    /// function imported through the IAT slot referenced by a memory operand, if any
+    pub import: Option<Import>,
 }
 
 impl Instruction {
@@ -26,71 +36,173 @@ impl Instruction {
     ) -> Self {
         Self {
             address,
+            prefixes: vec![],
             opcode,
             operands,
             instruction_size,
+            import: None,
         }
     }
 
-    /// Returns if an instruction changes control flow
+    /// How the instruction transfers control, from the flow of its opcode and from its operands
+    /// (a relative displacement makes a jump or a call direct). Returns `None` for instructions
+    /// that continue with the next one.
+    pub fn branch_kind(&self) -> Option<BranchKind> {
+        let direct = self
+            .operands
+            .iter()
+            .any(|operand| matches!(operand, X86Operand::Relative(_)));
+        match self.opcode.flow() {
+            X86Flow::Sequential => None,
+            X86Flow::Jump if direct => Some(BranchKind::UnconditionalDirect),
+            X86Flow::Jump => Some(BranchKind::UnconditionalIndirect),
+            X86Flow::ConditionalJump => Some(BranchKind::Conditional),
+            X86Flow::Call if direct => Some(BranchKind::CallDirect),
+            X86Flow::Call => Some(BranchKind::CallIndirect),
+            X86Flow::Return => Some(BranchKind::Return),
+            X86Flow::Interrupt | X86Flow::Exception => Some(BranchKind::Interrupt),
+            X86Flow::Halt => Some(BranchKind::Halt),
+        }
+    }
+
+    /// Returns if an instruction changes control flow: jumps and calls
     pub fn change_cfg(&self) -> bool {
-        let opcodes = [
-            X86Opcode::Jmp,
-            X86Opcode::Ja,
-            X86Opcode::Jno,
-            X86Opcode::Jb,
-            X86Opcode::Jbe,
-            X86Opcode::Jl,
-            //X86Opcode::Call,
-            X86Opcode::Jne,
-            X86Opcode::Je,
-        ];
-        opcodes.contains(&self.opcode)
+        self.branch_kind()
+            .is_some_and(|kind| kind.is_jump() || kind.is_call())
+    }
+
+    /// Returns if an instruction is a call
+    pub fn is_call(&self) -> bool {
+        self.branch_kind().is_some_and(|kind| kind.is_call())
     }
 
     /// Returns if an instruction is an unconditional jump
     pub fn is_jump(&self) -> bool {
-        self.opcode == X86Opcode::Jmp
+        matches!(
+            self.branch_kind(),
+            Some(BranchKind::UnconditionalDirect | BranchKind::UnconditionalIndirect)
+        )
     }
 
     /// Returns if an instruction is a conditional jump
     pub fn is_conditional_jump(&self) -> bool {
-        let opcodes = [
-            X86Opcode::Ja,
-            X86Opcode::Jno,
-            X86Opcode::Jb,
-            X86Opcode::Jbe,
-            X86Opcode::Jl,
-            X86Opcode::Jne,
-            X86Opcode::Je,
-        ];
-        opcodes.contains(&self.opcode)
-    }
-
-    /// Get immediate target for instructions that change control flow
-    pub fn get_target(&self) -> Result<Option<Address>> {
-        if self.operands.is_empty() {
-            return Ok(None);
+        self.branch_kind() == Some(BranchKind::Conditional)
+    }
+
+    /// Returns if the next instruction can execute after this one: straight-line code,
+    /// conditional jumps, calls (the callee returns, unless proven otherwise) and interrupts that
+    /// resume execution (e.g. syscall)
+    pub fn falls_through(&self) -> bool {
+        match self.branch_kind() {
+            None | Some(BranchKind::Conditional) => true,
+            Some(BranchKind::CallDirect | BranchKind::CallIndirect) => true,
+            Some(BranchKind::Interrupt) => !self.is_no_return(),
+            Some(
+                BranchKind::UnconditionalDirect
+                | BranchKind::UnconditionalIndirect
+                | BranchKind::Return
+                | BranchKind::Halt,
+            ) => false,
         }
+    }
 
+    /// Get the absolute target of instructions that change control flow, resolved from the
+    /// relative displacement. Indirect branches (through registers or memory) have no target.
+    pub fn get_target(&self) -> Result<Option<Address>> {
         match self.change_cfg() {
-            true => {
-                let target_op = &self.operands[0];
-                match target_op {
-                    X86Operand::Immediate(value) => {
-                        Ok(Some(self.address.wrapping_add(*value as u64)))
-                    }
-                    _ => Ok(None),
+            true => Ok(self.operands.iter().find_map(|operand| match operand {
+                X86Operand::Relative(displacement) => {
+                    Some(self.next_address().wrapping_add(*displacement as u64))
                 }
-            }
+                _ => None,
+            })),
             false => Err(anyhow!(
                 "This instruction is not a control flow instruction."
             )),
         }
     }
 
+    /// Address of the instruction that follows this one: relative branches and RIP-relative
+    /// operands are computed from there
+    pub fn next_address(&self) -> Address {
+        self.address.wrapping_add(self.instruction_size as Address)
+    }
+
+    /// Returns the memory operand of the instruction, if any
+    pub fn memory_operand(&self) -> Option<&MemoryOperand> {
+        self.operands.iter().find_map(|operand| match operand {
+            X86Operand::Memory(memory) => Some(memory),
+            _ => None,
+        })
+    }
+
+    /// Returns the absolute address referenced by a memory operand that does not depend on
+    /// registers, such as a RIP-relative operand. RIP points to the next instruction, so the
+    /// displacement is added to the end of this instruction.
+    pub fn memory_target(&self) -> Option<Address> {
+        self.memory_operand()?.target(self.next_address())
+    }
+
     /// Returns if an instruction is a return
     pub fn is_ret(&self) -> bool {
-        self.opcode == X86Opcode::Ret
+        self.branch_kind() == Some(BranchKind::Return)
+    }
+
+    /// Returns if the next instruction is never executed after this one: `int 0x29` (used by
+    /// `__fastfail` to terminate the process) and instructions that always raise an exception
+    /// (e.g. ud2)
+    pub fn is_no_return(&self) -> bool {
+        let fast_fail = self.opcode == X86Opcode::Int
+            && matches!(
+                self.operands.as_slice(),
+                [X86Operand::Immediate(FAST_FAIL_VECTOR)]
+            );
+        fast_fail || self.opcode.flow() == X86Flow::Exception
+    }
+
+    /// Returns if the instruction only executes in the kernel: a privileged opcode, or an access
+    /// to a control or debug register (e.g. `mov cr3, rax`)
+    pub fn is_privileged(&self) -> bool {
+        self.opcode.is_privileged()
+            || self.operands.iter().any(|operand| {
+                matches!(
+                    operand,
+                    X86Operand::Register(register)
+                        if matches!(register.class(), RegisterClass::Control | RegisterClass::Debug)
+                )
+            })
+    }
+
+    /// Returns if user code can execute the instruction: the bytes decode to a valid opcode that
+    /// is not privileged. Code found without proof (e.g. from a prologue or a pointer) must be
+    /// made of such instructions.
+    pub fn is_user_mode(&self) -> bool {
+        self.opcode != X86Opcode::Invalid && !self.is_privileged()
     }
 }
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for prefix in &self.prefixes {
+            write!(f, "{} ", prefix)?;
+        }
+        write!(f, "{}", self.opcode)?;
+        for (index, operand) in self.operands.iter().enumerate() {
+            let separator = if index == 0 { " " } else { ", " };
+            match operand {
+                // relative branches are printed with their absolute target
+                X86Operand::Relative(displacement) => write!(
+                    f,
+                    "{}0x{:x}",
+                    separator,
+                    self.next_address().wrapping_add(*displacement as u64)
+                )?,
+                operand => write!(f, "{}{}", separator, operand)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Interrupt vector raised by `__fastfail`: the process is terminated immediately
+const FAST_FAIL_VECTOR: i128 = 0x29;
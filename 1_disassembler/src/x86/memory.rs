@@ -0,0 +1,130 @@
+use crate::pe::address::Address;
+use crate::x86::register::X86Register;
+use std::fmt::{Display, Formatter};
+
+/// A memory operand: the address accessed is `segment:[base + index * scale + displacement]`
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MemoryOperand {
+    /// segment register written in the instruction, if any. In 64-bit mode only fs and gs have a
+    /// base different from zero.
+    pub segment: Option<X86Register>,
+    /// base register
+    pub base: Option<X86Register>,
+    /// index register, multiplied by the scale
+    pub index: Option<X86Register>,
+    /// multiplier of the index register: 1, 2, 4 or 8
+    pub scale: u8,
+    /// signed displacement added to the address. For RIP-relative operands it is relative to the
+    /// end of the instruction.
+    pub displacement: i64,
+    /// size of the memory accessed, in bits (0 when the memory is not accessed, e.g. lea)
+    pub size: u64,
+    /// the base register is rip: the address only depends on where the instruction is
+    pub rip_relative: bool,
+}
+
+impl MemoryOperand {
+    /// Creates a memory operand that only uses a base register and a displacement
+    pub fn new(base: Option<X86Register>, displacement: i64, size: u64) -> Self {
+        Self {
+            segment: None,
+            base,
+            index: None,
+            scale: 1,
+            displacement,
+            size,
+            rip_relative: base == Some(X86Register::Rip),
+        }
+    }
+
+    /// Computes the address accessed by the operand. `next_address` is the address of the
+    /// instruction that follows the one with this operand (the value of rip while the instruction
+    /// executes), `register_value` gives the value of the other registers. fs and gs overrides
+    /// add the base of the segment, which `register_value` returns for the segment register.
+    /// Returns `None` if the value of a register is unknown.
+    pub fn effective_address(
+        &self,
+        next_address: Address,
+        register_value: impl Fn(X86Register) -> Option<u64>,
+    ) -> Option<Address> {
+        let value = |register: X86Register| match register.parent() {
+            X86Register::Rip => Some(next_address),
+            _ => register_value(register),
+        };
+
+        let mut address = self.displacement as u64;
+        if let Some(base) = self.base {
+            address = address.wrapping_add(value(base)?);
+        }
+        if let Some(index) = self.index {
+            address = address.wrapping_add(value(index)?.wrapping_mul(self.scale as u64));
+        }
+        // with the address size override, the address is computed with 32-bit registers
+        if self
+            .base
+            .or(self.index)
+            .is_some_and(|register| register.width() == 32)
+        {
+            address &= u32::MAX as u64;
+        }
+        if let Some(segment) = self
+            .segment
+            .filter(|s| matches!(s, X86Register::Fs | X86Register::Gs))
+        {
+            address = address.wrapping_add(register_value(segment)?);
+        }
+        Some(address)
+    }
+
+    /// Absolute address accessed by the operand when it does not depend on registers:
+    /// RIP-relative operands (resolved from the end of the instruction) and plain displacements
+    pub fn target(&self, next_address: Address) -> Option<Address> {
+        if !self.rip_relative && self.base.is_some() {
+            return None;
+        }
+        self.effective_address(next_address, |_| None)
+    }
+}
+
+impl Display for MemoryOperand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let size = match self.size {
+            8 => "byte ptr ",
+            16 => "word ptr ",
+            32 => "dword ptr ",
+            48 => "fword ptr ",
+            64 => "qword ptr ",
+            80 => "tbyte ptr ",
+            128 => "xmmword ptr ",
+            256 => "ymmword ptr ",
+            512 => "zmmword ptr ",
+            _ => "",
+        };
+        write!(f, "{}", size)?;
+        if let Some(segment) = self.segment {
+            write!(f, "{}:", segment)?;
+        }
+
+        let mut terms = vec![];
+        if let Some(base) = self.base {
+            terms.push(base.to_string());
+        }
+        if let Some(index) = self.index {
+            match self.scale {
+                1 => terms.push(index.to_string()),
+                scale => terms.push(format!("{}*{}", index, scale)),
+            }
+        }
+
+        write!(f, "[{}", terms.join(" + "))?;
+        match self.displacement {
+            0 if !terms.is_empty() => {}
+            displacement if terms.is_empty() => write!(f, "0x{:x}", displacement as u64)?,
+            displacement if displacement < 0 => {
+                write!(f, " - 0x{:x}", displacement.unsigned_abs())?
+            }
+            displacement => write!(f, " + 0x{:x}", displacement)?,
+        }
+        write!(f, "]")
+    }
+}
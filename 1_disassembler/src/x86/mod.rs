@@ -1,6 +1,14 @@
+/// Exports the kinds of branches
+pub mod branch;
 /// Export instructions
 pub mod instruction;
+/// Exports memory operands
+pub mod memory;
 /// Exports opcode
 pub mod opcode;
 /// Export operands
 pub mod operands;
+/// Exports prefixes
+pub mod prefix;
+/// Exports registers
+pub mod register;
@@ -1,102 +1,2252 @@
-/// Represents an opcode for Intel x86_64
-#[derive(Clone, Default, Debug, PartialEq, Eq)]
-pub enum X86Opcode {
-    #[default]
-    /// An invalid opcode because it is not in this list
-    Invalid,
-
-    /// x86 opcode that represents xor instruction
-    Xor,
-    /// x86 opcode that represents xchg instruction
-    Xchg,
-    /// x86 opcode that represents test instruction
-    Test,
-    /// x86 opcode that represents sub instruction
-    Sub,
-    /// x86 opcode that represents shr instruction
-    Shr,
-    /// x86 opcode that represents shl instruction
-    Shl,
-    /// x86 opcode that represents setne instruction
-    Setne,
-    /// x86 opcode that represents sete instruction
-    Sete,
-    /// x86 opcode that represents sar instruction
-    Sar,
-    /// x86 opcode that represents ror instruction
-    Ror,
-    /// x86 opcode that represents rol instruction
-    Rol,
-    /// x86 opcode that represents ret instruction
-    Ret,
-    /// x86 opcode that represents rcl instruction
-    Rcl,
-    /// x86 opcode that represents rut instruction
-    Rut,
-    /// x86 opcode that represents or instruction
-    Or,
-    /// x86 opcode that represents push instruction
-    Push,
-    /// x86 opcode that represents pop instruction
-    Pop,
-    /// x86 opcode that represents not instruction
-    Not,
-    /// x86 opcode that represents neg instruction
-    Neg,
-    /// x86 opcode that represents nop instruction
-    Nop,
-    /// x86 opcode that represents mov instruction
-    Mov,
-    /// x86 opcode that represents movabs instruction
-    Movabs,
-    /// x86 opcode that represents lock instruction
-    Lock,
-    /// x86 opcode that represents lea instruction
-    Lea,
-    /// x86 opcode that represents jrcxz instruction
-    Jrcxz,
-    /// x86 opcode that represents jno instruction
-    Jno,
-    /// x86 opcode that represents jne instruction
-    Jne,
-    /// x86 opcode that represents jmp instruction
-    Jmp,
-    /// x86 opcode that represents jl instruction
-    Jl,
-    /// x86 opcode that represents je instruction
-    Je,
-    /// x86 opcode that represents jbe instruction
-    Jbe,
-    /// x86 opcode that represents jb instruction
-    Jb,
-    /// x86 opcode that represents ja instruction
-    Ja,
-    /// x86 opcode that represents int3 instruction
-    Int3,
-    /// x86 opcode that represents int instruction
-    Int,
-    /// x86 opcode that represents inc instruction
-    Inc,
-    /// x86 opcode that represents imul instruction
-    Imul,
-    /// x86 opcode that represents fstp instruction
-    Fstp,
-    /// x86 opcode that represents ficomp instruction
-    Ficomp,
-    /// x86 opcode that represents ficom instruction
-    Ficom,
-    /// x86 opcode that represents fcom instruction
-    Fcom,
-    /// x86 opcode that represents cmp instruction
-    Cmp,
-    /// x86 opcode that represents cmovne instruction
-    Cmovne,
-    /// x86 opcode that represents cdq instruction
-    Cdq,
-    /// x86 opcode that represents call instruction
+use iced_x86::Mnemonic;
+use std::fmt::{Display, Formatter};
+
+/// Family of instructions an opcode belongs to
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum OpcodeCategory {
+    /// integer arithmetic, logic, data movement and bit manipulation
+    General,
+    /// instructions that change the control flow: jumps, calls, returns, interrupts
+    ControlFlow,
+    /// string instructions (e.g. movsb, stosq), usually repeated with a rep prefix
+    String,
+    /// floating point instructions of the x87 FPU
+    X87,
+    /// MMX and 3DNow! instructions working on the mm registers
+    Mmx,
+    /// SSE instructions working on the xmm registers with the legacy encoding
+    Sse,
+    /// AVX instructions (VEX or XOP encoded)
+    Avx,
+    /// AVX-512 instructions (EVEX encoded)
+    Avx512,
+    /// privileged and system instructions (e.g. syscall, cpuid, rdmsr)
+    System,
+}
+
+/// How an opcode affects the control flow
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum X86Flow {
+    /// execution continues with the next instruction
+    Sequential,
+    /// unconditional jump, either to a relative target or through a register/memory operand
+    Jump,
+    /// conditional jump: either the target or the next instruction is executed (e.g. jcc, loop,
+    /// jrcxz)
+    ConditionalJump,
+    /// call of a function, execution resumes after the call when the function returns
     Call,
-    /// x86 opcode that represents and instruction
-    And,
-    /// x86 opcode that represents add instruction
-    Add,
+    /// return from a function (or from an interrupt, or a system call)
+    Return,
+    /// software interrupt or system call (e.g. int3, int 0x29, syscall): control moves to a
+    /// handler of the operating system
+    Interrupt,
+    /// instruction that always raises an exception (e.g. ud2)
+    Exception,
+    /// the processor stops until the next interrupt
+    Halt,
+}
+
+/// A set of bits of the rflags register
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct X86Flags(pub u32);
+
+impl X86Flags {
+    /// carry flag
+    pub const CF: X86Flags = X86Flags(1 << 0);
+    /// parity flag
+    pub const PF: X86Flags = X86Flags(1 << 2);
+    /// auxiliary carry flag
+    pub const AF: X86Flags = X86Flags(1 << 4);
+    /// zero flag
+    pub const ZF: X86Flags = X86Flags(1 << 6);
+    /// sign flag
+    pub const SF: X86Flags = X86Flags(1 << 7);
+    /// interrupt enable flag
+    pub const IF: X86Flags = X86Flags(1 << 9);
+    /// direction flag
+    pub const DF: X86Flags = X86Flags(1 << 10);
+    /// overflow flag
+    pub const OF: X86Flags = X86Flags(1 << 11);
+    /// alignment check flag
+    pub const AC: X86Flags = X86Flags(1 << 18);
+
+    /// Returns if no flag is in the set
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns if every flag of `other` is in the set
+    pub fn contains(&self, other: X86Flags) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+/// Metadata of an opcode
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OpcodeInfo {
+    /// family of the instruction
+    pub category: OpcodeCategory,
+    /// how the instruction affects the control flow
+    pub flow: X86Flow,
+    /// flags read by the instruction
+    pub flags_read: X86Flags,
+    /// flags written (set, cleared or left undefined) by the instruction
+    pub flags_written: X86Flags,
+}
+
+/// Mnemonic of `iced_x86` that an opcode of the table stands for
+macro_rules! iced_mnemonic {
+    ($variant:ident) => {
+        Mnemonic::$variant
+    };
+    ($variant:ident, $iced:ident) => {
+        Mnemonic::$iced
+    };
+}
+
+/// Declares the opcodes with their metadata: the table at the end of this file lists one opcode
+/// per line as `Variant => "mnemonic", category, flow, [flags read], [flags written];`. Every
+/// opcode is the mnemonic of `iced_x86` with the same name, unless the line names it as
+/// `Variant [decoder mnemonic] => ...`.
+macro_rules! opcodes {
+    ($($variant:ident $([$iced:ident])? => $mnemonic:literal, $category:ident, $flow:ident, [$($read:ident)*], [$($written:ident)*];)*) => {
+        /// Represents an opcode for Intel x86_64
+        #[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub enum X86Opcode {
+            #[default]
+            /// An invalid opcode: the bytes do not encode an instruction, or the mnemonic is
+            /// unknown
+            Invalid,
+            /// x86 opcode that represents movabs instruction: a mov of a 64-bit immediate or of
+            /// an absolute 64-bit memory offset, which the decoder reports as mov
+            Movabs,
+            $(
+                #[doc = concat!("x86 opcode that represents ", $mnemonic, " instruction")]
+                $variant,
+            )*
+        }
+
+        impl X86Opcode {
+            /// Translate a mnemonic (e.g. `mov`) into a concrete opcode
+            pub fn from_mnemonic(mnemonic: &str) -> Self {
+                match mnemonic {
+                    "movabs" => X86Opcode::Movabs,
+                    $($mnemonic => X86Opcode::$variant,)*
+                    _ => X86Opcode::Invalid,
+                }
+            }
+
+            /// Translate a mnemonic of the `iced_x86` decoder into a concrete opcode
+            pub fn from_iced(mnemonic: Mnemonic) -> Self {
+                match mnemonic {
+                    $(iced_mnemonic!($variant $(, $iced)?) => X86Opcode::$variant,)*
+                    _ => X86Opcode::Invalid,
+                }
+            }
+
+            /// Mnemonic of the opcode, as written in Intel syntax
+            pub fn mnemonic(&self) -> &'static str {
+                match self {
+                    X86Opcode::Invalid => "(bad)",
+                    X86Opcode::Movabs => "movabs",
+                    $(X86Opcode::$variant => $mnemonic,)*
+                }
+            }
+
+            /// Metadata of the opcode
+            pub fn info(&self) -> OpcodeInfo {
+                match self {
+                    X86Opcode::Invalid => OpcodeInfo {
+                        category: OpcodeCategory::General,
+                        flow: X86Flow::Exception,
+                        flags_read: X86Flags::default(),
+                        flags_written: X86Flags::default(),
+                    },
+                    X86Opcode::Movabs => OpcodeInfo {
+                        category: OpcodeCategory::General,
+                        flow: X86Flow::Sequential,
+                        flags_read: X86Flags::default(),
+                        flags_written: X86Flags::default(),
+                    },
+                    $(X86Opcode::$variant => OpcodeInfo {
+                        category: OpcodeCategory::$category,
+                        flow: X86Flow::$flow,
+                        flags_read: X86Flags(0 $(| X86Flags::$read.0)*),
+                        flags_written: X86Flags(0 $(| X86Flags::$written.0)*),
+                    },)*
+                }
+            }
+        }
+    };
+}
+
+impl X86Opcode {
+    /// Family of instructions the opcode belongs to
+    pub fn category(&self) -> OpcodeCategory {
+        self.info().category
+    }
+
+    /// How the opcode affects the control flow
+    pub fn flow(&self) -> X86Flow {
+        self.info().flow
+    }
+
+    /// Returns if the opcode reads at least one flag
+    pub fn reads_flags(&self) -> bool {
+        !self.info().flags_read.is_empty()
+    }
+
+    /// Returns if the opcode writes at least one flag
+    pub fn writes_flags(&self) -> bool {
+        !self.info().flags_written.is_empty()
+    }
+
+    /// Returns if the opcode is a jump, conditional or not
+    pub fn is_branch(&self) -> bool {
+        matches!(self.flow(), X86Flow::Jump | X86Flow::ConditionalJump)
+    }
+
+    /// Returns if the opcode calls a function
+    pub fn is_call(&self) -> bool {
+        self.flow() == X86Flow::Call
+    }
+
+    /// Returns if the opcode returns from a function
+    pub fn is_return(&self) -> bool {
+        self.flow() == X86Flow::Return
+    }
+
+    /// Returns if the opcode transfers control to the operating system, through a software
+    /// interrupt, a system call or an exception
+    pub fn is_trap(&self) -> bool {
+        matches!(self.flow(), X86Flow::Interrupt | X86Flow::Exception)
+    }
+
+    /// Returns if the opcode only executes at the privilege level of the kernel (or of a
+    /// hypervisor): user code raises an exception instead. Many system opcodes are not privileged
+    /// (e.g. cpuid, rdtsc, xgetbv, syscall), and forms of unprivileged mnemonics can be (e.g. mov
+    /// to a control register), which only the operands tell.
+    pub fn is_privileged(&self) -> bool {
+        PRIVILEGED_OPCODES.contains(self)
+    }
+}
+
+impl Display for X86Opcode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.mnemonic())
+    }
+}
+
+/// Opcodes that raise an exception outside of ring 0 (or of VMX root operation), including the
+/// I/O instructions and cli/sti, which only user code granted I/O privileges executes
+const PRIVILEGED_OPCODES: &[X86Opcode] = &[
+    X86Opcode::Bb0Reset,
+    X86Opcode::Bb1Reset,
+    X86Opcode::Cl1invmb,
+    X86Opcode::Clac,
+    X86Opcode::Clgi,
+    X86Opcode::Cli,
+    X86Opcode::Clrssbsy,
+    X86Opcode::Clts,
+    X86Opcode::CpuRead,
+    X86Opcode::CpuWrite,
+    X86Opcode::Dmint,
+    X86Opcode::Encls,
+    X86Opcode::Enclv,
+    X86Opcode::Enqcmds,
+    X86Opcode::Erets,
+    X86Opcode::Eretu,
+    X86Opcode::Getsec,
+    X86Opcode::Getsecq,
+    X86Opcode::Hlt,
+    X86Opcode::Hreset,
+    X86Opcode::In,
+    X86Opcode::Insb,
+    X86Opcode::Insd,
+    X86Opcode::Insw,
+    X86Opcode::Invd,
+    X86Opcode::Invept,
+    X86Opcode::Invlpg,
+    X86Opcode::Invlpga,
+    X86Opcode::Invlpgb,
+    X86Opcode::Invpcid,
+    X86Opcode::Invvpid,
+    X86Opcode::Lgdt,
+    X86Opcode::Lidt,
+    X86Opcode::Lkgs,
+    X86Opcode::Lldt,
+    X86Opcode::Lmsw,
+    X86Opcode::Loadall,
+    X86Opcode::Loadiwkey,
+    X86Opcode::Ltr,
+    X86Opcode::Monitor,
+    X86Opcode::Mwait,
+    X86Opcode::Out,
+    X86Opcode::Outsb,
+    X86Opcode::Outsd,
+    X86Opcode::Outsw,
+    X86Opcode::Pbndkb,
+    X86Opcode::Pconfig,
+    X86Opcode::Psmash,
+    X86Opcode::Pvalidate,
+    X86Opcode::Rdm,
+    X86Opcode::Rdmsr,
+    X86Opcode::Rdmsrlist,
+    X86Opcode::Rdshr,
+    X86Opcode::Rdudbg,
+    X86Opcode::Rmpadjust,
+    X86Opcode::Rmpquery,
+    X86Opcode::Rmpupdate,
+    X86Opcode::Rsdc,
+    X86Opcode::Rsldt,
+    X86Opcode::Rsm,
+    X86Opcode::Rsts,
+    X86Opcode::Seamcall,
+    X86Opcode::Seamops,
+    X86Opcode::Seamret,
+    X86Opcode::Setssbsy,
+    X86Opcode::Skinit,
+    X86Opcode::Smint,
+    X86Opcode::Stac,
+    X86Opcode::Stgi,
+    X86Opcode::Sti,
+    X86Opcode::Storeall,
+    X86Opcode::Svdc,
+    X86Opcode::Svldt,
+    X86Opcode::Svts,
+    X86Opcode::Swapgs,
+    X86Opcode::Sysexit,
+    X86Opcode::Sysexitq,
+    X86Opcode::Sysret,
+    X86Opcode::Sysretq,
+    X86Opcode::Tdcall,
+    X86Opcode::Tlbsync,
+    X86Opcode::Vmcall,
+    X86Opcode::Vmclear,
+    X86Opcode::Vmlaunch,
+    X86Opcode::Vmload,
+    X86Opcode::Vmmcall,
+    X86Opcode::Vmptrld,
+    X86Opcode::Vmptrst,
+    X86Opcode::Vmread,
+    X86Opcode::Vmresume,
+    X86Opcode::Vmrun,
+    X86Opcode::Vmsave,
+    X86Opcode::Vmwrite,
+    X86Opcode::Vmxoff,
+    X86Opcode::Vmxon,
+    X86Opcode::Wbinvd,
+    X86Opcode::Wbnoinvd,
+    X86Opcode::Wrmsr,
+    X86Opcode::Wrmsrlist,
+    X86Opcode::Wrmsrns,
+    X86Opcode::Wrshr,
+    X86Opcode::Wrudbg,
+    X86Opcode::Wrussd,
+    X86Opcode::Wrussq,
+    X86Opcode::Xrstors,
+    X86Opcode::Xrstors64,
+    X86Opcode::Xsaves,
+    X86Opcode::Xsaves64,
+    X86Opcode::Xsetbv,
+];
+
+// The table is generated from the instruction tables of the iced-x86 decoder: one entry for every
+// mnemonic, plus movabs. When a mnemonic has both privileged and unprivileged forms (e.g. mov and
+// mov to a control register) the metadata describes the unprivileged ones.
+opcodes! {
+    Aaa => "aaa", General, Sequential, [AF], [OF SF ZF AF CF PF];
+    Aad => "aad", General, Sequential, [], [OF SF ZF AF CF PF];
+    Aadd => "aadd", General, Sequential, [], [];
+    Aam => "aam", General, Sequential, [], [OF SF ZF AF CF PF];
+    Aand => "aand", General, Sequential, [], [];
+    Aas => "aas", General, Sequential, [AF], [OF SF ZF AF CF PF];
+    Adc => "adc", General, Sequential, [CF], [OF SF ZF AF CF PF];
+    Adcx => "adcx", General, Sequential, [CF], [CF];
+    Add => "add", General, Sequential, [], [OF SF ZF AF CF PF];
+    Addpd => "addpd", Sse, Sequential, [], [];
+    Addps => "addps", Sse, Sequential, [], [];
+    Addsd => "addsd", Sse, Sequential, [], [];
+    Addss => "addss", Sse, Sequential, [], [];
+    Addsubpd => "addsubpd", Sse, Sequential, [], [];
+    Addsubps => "addsubps", Sse, Sequential, [], [];
+    Adox => "adox", General, Sequential, [OF], [OF];
+    Aesdec => "aesdec", Sse, Sequential, [], [];
+    Aesdec128kl => "aesdec128kl", Sse, Sequential, [], [OF SF ZF AF CF PF];
+    Aesdec256kl => "aesdec256kl", Sse, Sequential, [], [OF SF ZF AF CF PF];
+    Aesdeclast => "aesdeclast", Sse, Sequential, [], [];
+    Aesdecwide128kl => "aesdecwide128kl", General, Sequential, [], [OF SF ZF AF CF PF];
+    Aesdecwide256kl => "aesdecwide256kl", General, Sequential, [], [OF SF ZF AF CF PF];
+    Aesenc => "aesenc", Sse, Sequential, [], [];
+    Aesenc128kl => "aesenc128kl", Sse, Sequential, [], [OF SF ZF AF CF PF];
+    Aesenc256kl => "aesenc256kl", Sse, Sequential, [], [OF SF ZF AF CF PF];
+    Aesenclast => "aesenclast", Sse, Sequential, [], [];
+    Aesencwide128kl => "aesencwide128kl", General, Sequential, [], [OF SF ZF AF CF PF];
+    Aesencwide256kl => "aesencwide256kl", General, Sequential, [], [OF SF ZF AF CF PF];
+    Aesimc => "aesimc", Sse, Sequential, [], [];
+    Aeskeygenassist => "aeskeygenassist", Sse, Sequential, [], [];
+    Altinst => "altinst", ControlFlow, Jump, [], [];
+    And => "and", General, Sequential, [], [OF SF ZF AF CF PF];
+    Andn => "andn", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Andnpd => "andnpd", Sse, Sequential, [], [];
+    Andnps => "andnps", Sse, Sequential, [], [];
+    Andpd => "andpd", Sse, Sequential, [], [];
+    Andps => "andps", Sse, Sequential, [], [];
+    Aor => "aor", General, Sequential, [], [];
+    Arpl => "arpl", General, Sequential, [], [ZF];
+    Axor => "axor", General, Sequential, [], [];
+    Bb0Reset [Bb0_reset] => "bb0_reset", System, Sequential, [], [];
+    Bb1Reset [Bb1_reset] => "bb1_reset", System, Sequential, [], [];
+    Bextr => "bextr", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Blcfill => "blcfill", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Blci => "blci", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Blcic => "blcic", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Blcmsk => "blcmsk", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Blcs => "blcs", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Blendpd => "blendpd", Sse, Sequential, [], [];
+    Blendps => "blendps", Sse, Sequential, [], [];
+    Blendvpd => "blendvpd", Sse, Sequential, [], [];
+    Blendvps => "blendvps", Sse, Sequential, [], [];
+    Blsfill => "blsfill", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Blsi => "blsi", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Blsic => "blsic", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Blsmsk => "blsmsk", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Blsr => "blsr", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Bndcl => "bndcl", General, Sequential, [], [];
+    Bndcn => "bndcn", General, Sequential, [], [];
+    Bndcu => "bndcu", General, Sequential, [], [];
+    Bndldx => "bndldx", General, Sequential, [], [];
+    Bndmk => "bndmk", General, Sequential, [], [];
+    Bndmov => "bndmov", General, Sequential, [], [];
+    Bndstx => "bndstx", General, Sequential, [], [];
+    Bound => "bound", General, Sequential, [], [];
+    Bsf => "bsf", General, Sequential, [], [OF SF ZF AF CF PF];
+    Bsr => "bsr", General, Sequential, [], [OF SF ZF AF CF PF];
+    Bswap => "bswap", General, Sequential, [], [];
+    Bt => "bt", General, Sequential, [], [OF SF AF CF PF];
+    Btc => "btc", General, Sequential, [], [OF SF AF CF PF];
+    Btr => "btr", General, Sequential, [], [OF SF AF CF PF];
+    Bts => "bts", General, Sequential, [], [OF SF AF CF PF];
+    Bzhi => "bzhi", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Call => "call", ControlFlow, Call, [], [];
+    Cbw => "cbw", General, Sequential, [], [];
+    CcsEncrypt [Ccs_encrypt] => "ccs_encrypt", General, Sequential, [], [];
+    CcsHash [Ccs_hash] => "ccs_hash", General, Sequential, [], [];
+    Cdq => "cdq", General, Sequential, [], [];
+    Cdqe => "cdqe", General, Sequential, [], [];
+    Cl1invmb => "cl1invmb", System, Sequential, [], [];
+    Clac => "clac", System, Sequential, [], [AC];
+    Clc => "clc", General, Sequential, [], [CF];
+    Cld => "cld", General, Sequential, [], [DF];
+    Cldemote => "cldemote", General, Sequential, [], [];
+    Clevict0 => "clevict0", Avx, Sequential, [], [];
+    Clevict1 => "clevict1", Avx, Sequential, [], [];
+    Clflush => "clflush", General, Sequential, [], [];
+    Clflushopt => "clflushopt", General, Sequential, [], [];
+    Clgi => "clgi", System, Sequential, [], [];
+    Cli => "cli", System, Sequential, [], [IF];
+    Clrssbsy => "clrssbsy", System, Sequential, [], [OF SF ZF AF CF PF];
+    Clts => "clts", System, Sequential, [], [];
+    Clui => "clui", General, Sequential, [], [];
+    Clwb => "clwb", General, Sequential, [], [];
+    Clzero => "clzero", General, Sequential, [], [];
+    Cmc => "cmc", General, Sequential, [CF], [CF];
+    Cmova => "cmova", General, Sequential, [ZF CF], [];
+    Cmovae => "cmovae", General, Sequential, [CF], [];
+    Cmovb => "cmovb", General, Sequential, [CF], [];
+    Cmovbe => "cmovbe", General, Sequential, [ZF CF], [];
+    Cmove => "cmove", General, Sequential, [ZF], [];
+    Cmovg => "cmovg", General, Sequential, [OF SF ZF], [];
+    Cmovge => "cmovge", General, Sequential, [OF SF], [];
+    Cmovl => "cmovl", General, Sequential, [OF SF], [];
+    Cmovle => "cmovle", General, Sequential, [OF SF ZF], [];
+    Cmovne => "cmovne", General, Sequential, [ZF], [];
+    Cmovno => "cmovno", General, Sequential, [OF], [];
+    Cmovnp => "cmovnp", General, Sequential, [PF], [];
+    Cmovns => "cmovns", General, Sequential, [SF], [];
+    Cmovo => "cmovo", General, Sequential, [OF], [];
+    Cmovp => "cmovp", General, Sequential, [PF], [];
+    Cmovs => "cmovs", General, Sequential, [SF], [];
+    Cmp => "cmp", General, Sequential, [], [OF SF ZF AF CF PF];
+    Cmpbexadd => "cmpbexadd", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Cmpbxadd => "cmpbxadd", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Cmplexadd => "cmplexadd", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Cmplxadd => "cmplxadd", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Cmpnbexadd => "cmpnbexadd", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Cmpnbxadd => "cmpnbxadd", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Cmpnlexadd => "cmpnlexadd", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Cmpnlxadd => "cmpnlxadd", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Cmpnoxadd => "cmpnoxadd", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Cmpnpxadd => "cmpnpxadd", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Cmpnsxadd => "cmpnsxadd", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Cmpnzxadd => "cmpnzxadd", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Cmpoxadd => "cmpoxadd", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Cmppd => "cmppd", Sse, Sequential, [], [];
+    Cmpps => "cmpps", Sse, Sequential, [], [];
+    Cmppxadd => "cmppxadd", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Cmpsb => "cmpsb", String, Sequential, [DF], [OF SF ZF AF CF PF];
+    Cmpsd => "cmpsd", Sse, Sequential, [DF], [OF SF ZF AF CF PF];
+    Cmpsq => "cmpsq", String, Sequential, [DF], [OF SF ZF AF CF PF];
+    Cmpss => "cmpss", Sse, Sequential, [], [];
+    Cmpsw => "cmpsw", String, Sequential, [DF], [OF SF ZF AF CF PF];
+    Cmpsxadd => "cmpsxadd", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Cmpxchg => "cmpxchg", General, Sequential, [], [OF SF ZF AF CF PF];
+    Cmpxchg16b => "cmpxchg16b", General, Sequential, [], [ZF];
+    Cmpxchg8b => "cmpxchg8b", General, Sequential, [], [ZF];
+    Cmpzxadd => "cmpzxadd", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Comisd => "comisd", Sse, Sequential, [], [OF SF ZF AF CF PF];
+    Comiss => "comiss", Sse, Sequential, [], [OF SF ZF AF CF PF];
+    CpuRead [Cpu_read] => "cpu_read", System, Sequential, [], [];
+    CpuWrite [Cpu_write] => "cpu_write", System, Sequential, [], [];
+    Cpuid => "cpuid", System, Sequential, [], [];
+    Cqo => "cqo", General, Sequential, [], [];
+    Crc32 => "crc32", Sse, Sequential, [], [];
+    Cvtdq2pd => "cvtdq2pd", Sse, Sequential, [], [];
+    Cvtdq2ps => "cvtdq2ps", Sse, Sequential, [], [];
+    Cvtpd2dq => "cvtpd2dq", Sse, Sequential, [], [];
+    Cvtpd2pi => "cvtpd2pi", Sse, Sequential, [], [];
+    Cvtpd2ps => "cvtpd2ps", Sse, Sequential, [], [];
+    Cvtpi2pd => "cvtpi2pd", Sse, Sequential, [], [];
+    Cvtpi2ps => "cvtpi2ps", Sse, Sequential, [], [];
+    Cvtps2dq => "cvtps2dq", Sse, Sequential, [], [];
+    Cvtps2pd => "cvtps2pd", Sse, Sequential, [], [];
+    Cvtps2pi => "cvtps2pi", Sse, Sequential, [], [];
+    Cvtsd2si => "cvtsd2si", Sse, Sequential, [], [];
+    Cvtsd2ss => "cvtsd2ss", Sse, Sequential, [], [];
+    Cvtsi2sd => "cvtsi2sd", Sse, Sequential, [], [];
+    Cvtsi2ss => "cvtsi2ss", Sse, Sequential, [], [];
+    Cvtss2sd => "cvtss2sd", Sse, Sequential, [], [];
+    Cvtss2si => "cvtss2si", Sse, Sequential, [], [];
+    Cvttpd2dq => "cvttpd2dq", Sse, Sequential, [], [];
+    Cvttpd2pi => "cvttpd2pi", Sse, Sequential, [], [];
+    Cvttps2dq => "cvttps2dq", Sse, Sequential, [], [];
+    Cvttps2pi => "cvttps2pi", Sse, Sequential, [], [];
+    Cvttsd2si => "cvttsd2si", Sse, Sequential, [], [];
+    Cvttss2si => "cvttss2si", Sse, Sequential, [], [];
+    Cwd => "cwd", General, Sequential, [], [];
+    Cwde => "cwde", General, Sequential, [], [];
+    Daa => "daa", General, Sequential, [AF CF], [OF SF ZF AF CF PF];
+    Das => "das", General, Sequential, [AF CF], [OF SF ZF AF CF PF];
+    Db => "db", ControlFlow, Exception, [], [];
+    Dd => "dd", ControlFlow, Exception, [], [];
+    Dec => "dec", General, Sequential, [], [OF SF ZF AF PF];
+    Delay => "delay", Avx, Sequential, [], [];
+    Div => "div", General, Sequential, [], [OF SF ZF AF CF PF];
+    Divpd => "divpd", Sse, Sequential, [], [];
+    Divps => "divps", Sse, Sequential, [], [];
+    Divsd => "divsd", Sse, Sequential, [], [];
+    Divss => "divss", Sse, Sequential, [], [];
+    Dmint => "dmint", System, Interrupt, [], [];
+    Dppd => "dppd", Sse, Sequential, [], [];
+    Dpps => "dpps", Sse, Sequential, [], [];
+    Dq => "dq", ControlFlow, Exception, [], [];
+    Dw => "dw", ControlFlow, Exception, [], [];
+    Emms => "emms", General, Sequential, [], [];
+    Encls => "encls", System, Sequential, [], [OF SF ZF AF CF PF];
+    Enclu => "enclu", General, Sequential, [], [OF SF ZF AF CF PF];
+    Enclv => "enclv", System, Sequential, [], [OF SF ZF AF CF PF];
+    Encodekey128 => "encodekey128", General, Sequential, [], [OF SF ZF AF CF PF];
+    Encodekey256 => "encodekey256", General, Sequential, [], [OF SF ZF AF CF PF];
+    Endbr32 => "endbr32", General, Sequential, [], [];
+    Endbr64 => "endbr64", General, Sequential, [], [];
+    Enqcmd => "enqcmd", General, Sequential, [], [OF SF ZF AF CF PF];
+    Enqcmds => "enqcmds", System, Sequential, [], [OF SF ZF AF CF PF];
+    Enter => "enter", General, Sequential, [], [];
+    Erets => "erets", System, Return, [], [OF SF ZF AF CF PF DF IF AC];
+    Eretu => "eretu", System, Return, [], [OF SF ZF AF CF PF DF IF AC];
+    Extractps => "extractps", Sse, Sequential, [], [];
+    Extrq => "extrq", Sse, Sequential, [], [];
+    F2xm1 => "f2xm1", X87, Sequential, [], [];
+    Fabs => "fabs", X87, Sequential, [], [];
+    Fadd => "fadd", X87, Sequential, [], [];
+    Faddp => "faddp", X87, Sequential, [], [];
+    Fbld => "fbld", X87, Sequential, [], [];
+    Fbstp => "fbstp", X87, Sequential, [], [];
+    Fchs => "fchs", X87, Sequential, [], [];
+    Fclex => "fclex", X87, Sequential, [], [];
+    Fcmovb => "fcmovb", X87, Sequential, [CF], [];
+    Fcmovbe => "fcmovbe", X87, Sequential, [ZF CF], [];
+    Fcmove => "fcmove", X87, Sequential, [ZF], [];
+    Fcmovnb => "fcmovnb", X87, Sequential, [CF], [];
+    Fcmovnbe => "fcmovnbe", X87, Sequential, [ZF CF], [];
+    Fcmovne => "fcmovne", X87, Sequential, [ZF], [];
+    Fcmovnu => "fcmovnu", X87, Sequential, [PF], [];
+    Fcmovu => "fcmovu", X87, Sequential, [PF], [];
+    Fcom => "fcom", X87, Sequential, [], [];
+    Fcomi => "fcomi", X87, Sequential, [], [OF SF ZF AF CF PF];
+    Fcomip => "fcomip", X87, Sequential, [], [OF SF ZF AF CF PF];
+    Fcomp => "fcomp", X87, Sequential, [], [];
+    Fcompp => "fcompp", X87, Sequential, [], [];
+    Fcos => "fcos", X87, Sequential, [], [];
+    Fdecstp => "fdecstp", X87, Sequential, [], [];
+    Fdisi => "fdisi", X87, Sequential, [], [];
+    Fdiv => "fdiv", X87, Sequential, [], [];
+    Fdivp => "fdivp", X87, Sequential, [], [];
+    Fdivr => "fdivr", X87, Sequential, [], [];
+    Fdivrp => "fdivrp", X87, Sequential, [], [];
+    Femms => "femms", Mmx, Sequential, [], [];
+    Feni => "feni", X87, Sequential, [], [];
+    Ffree => "ffree", X87, Sequential, [], [];
+    Ffreep => "ffreep", X87, Sequential, [], [];
+    Fiadd => "fiadd", X87, Sequential, [], [];
+    Ficom => "ficom", X87, Sequential, [], [];
+    Ficomp => "ficomp", X87, Sequential, [], [];
+    Fidiv => "fidiv", X87, Sequential, [], [];
+    Fidivr => "fidivr", X87, Sequential, [], [];
+    Fild => "fild", X87, Sequential, [], [];
+    Fimul => "fimul", X87, Sequential, [], [];
+    Fincstp => "fincstp", X87, Sequential, [], [];
+    Finit => "finit", X87, Sequential, [], [];
+    Fist => "fist", X87, Sequential, [], [];
+    Fistp => "fistp", X87, Sequential, [], [];
+    Fisttp => "fisttp", X87, Sequential, [], [];
+    Fisub => "fisub", X87, Sequential, [], [];
+    Fisubr => "fisubr", X87, Sequential, [], [];
+    Fld => "fld", X87, Sequential, [], [];
+    Fld1 => "fld1", X87, Sequential, [], [];
+    Fldcw => "fldcw", X87, Sequential, [], [];
+    Fldenv => "fldenv", X87, Sequential, [], [];
+    Fldl2e => "fldl2e", X87, Sequential, [], [];
+    Fldl2t => "fldl2t", X87, Sequential, [], [];
+    Fldlg2 => "fldlg2", X87, Sequential, [], [];
+    Fldln2 => "fldln2", X87, Sequential, [], [];
+    Fldpi => "fldpi", X87, Sequential, [], [];
+    Fldz => "fldz", X87, Sequential, [], [];
+    Fmul => "fmul", X87, Sequential, [], [];
+    Fmulp => "fmulp", X87, Sequential, [], [];
+    Fnclex => "fnclex", X87, Sequential, [], [];
+    Fndisi => "fndisi", X87, Sequential, [], [];
+    Fneni => "fneni", X87, Sequential, [], [];
+    Fninit => "fninit", X87, Sequential, [], [];
+    Fnop => "fnop", X87, Sequential, [], [];
+    Fnsave => "fnsave", X87, Sequential, [], [];
+    Fnsetpm => "fnsetpm", X87, Sequential, [], [];
+    Fnstcw => "fnstcw", X87, Sequential, [], [];
+    Fnstdw => "fnstdw", X87, Sequential, [], [];
+    Fnstenv => "fnstenv", X87, Sequential, [], [];
+    Fnstsg => "fnstsg", X87, Sequential, [], [];
+    Fnstsw => "fnstsw", X87, Sequential, [], [];
+    Fpatan => "fpatan", X87, Sequential, [], [];
+    Fprem => "fprem", X87, Sequential, [], [];
+    Fprem1 => "fprem1", X87, Sequential, [], [];
+    Fptan => "fptan", X87, Sequential, [], [];
+    Frichop => "frichop", General, Sequential, [], [];
+    Frinear => "frinear", General, Sequential, [], [];
+    Frint2 => "frint2", General, Sequential, [], [];
+    Frndint => "frndint", X87, Sequential, [], [];
+    Frstor => "frstor", X87, Sequential, [], [];
+    Frstpm => "frstpm", X87, Sequential, [], [];
+    Fsave => "fsave", X87, Sequential, [], [];
+    Fscale => "fscale", X87, Sequential, [], [];
+    Fsetpm => "fsetpm", X87, Sequential, [], [];
+    Fsin => "fsin", X87, Sequential, [], [];
+    Fsincos => "fsincos", X87, Sequential, [], [];
+    Fsqrt => "fsqrt", X87, Sequential, [], [];
+    Fst => "fst", X87, Sequential, [], [];
+    Fstcw => "fstcw", X87, Sequential, [], [];
+    Fstdw => "fstdw", X87, Sequential, [], [];
+    Fstenv => "fstenv", X87, Sequential, [], [];
+    Fstp => "fstp", X87, Sequential, [], [];
+    Fstpnce => "fstpnce", X87, Sequential, [], [];
+    Fstsg => "fstsg", X87, Sequential, [], [];
+    Fstsw => "fstsw", X87, Sequential, [], [];
+    Fsub => "fsub", X87, Sequential, [], [];
+    Fsubp => "fsubp", X87, Sequential, [], [];
+    Fsubr => "fsubr", X87, Sequential, [], [];
+    Fsubrp => "fsubrp", X87, Sequential, [], [];
+    Ftst => "ftst", X87, Sequential, [], [];
+    Ftstp => "ftstp", General, Sequential, [], [];
+    Fucom => "fucom", X87, Sequential, [], [];
+    Fucomi => "fucomi", X87, Sequential, [], [OF SF ZF AF CF PF];
+    Fucomip => "fucomip", X87, Sequential, [], [OF SF ZF AF CF PF];
+    Fucomp => "fucomp", X87, Sequential, [], [];
+    Fucompp => "fucompp", X87, Sequential, [], [];
+    Fxam => "fxam", X87, Sequential, [], [];
+    Fxch => "fxch", X87, Sequential, [], [];
+    Fxrstor => "fxrstor", General, Sequential, [], [];
+    Fxrstor64 => "fxrstor64", General, Sequential, [], [];
+    Fxsave => "fxsave", General, Sequential, [], [];
+    Fxsave64 => "fxsave64", General, Sequential, [], [];
+    Fxtract => "fxtract", X87, Sequential, [], [];
+    Fyl2x => "fyl2x", X87, Sequential, [], [];
+    Fyl2xp1 => "fyl2xp1", X87, Sequential, [], [];
+    Getsec => "getsec", System, Sequential, [], [];
+    Getsecq => "getsecq", System, Sequential, [], [];
+    Gf2p8affineinvqb => "gf2p8affineinvqb", Sse, Sequential, [], [];
+    Gf2p8affineqb => "gf2p8affineqb", Sse, Sequential, [], [];
+    Gf2p8mulb => "gf2p8mulb", Sse, Sequential, [], [];
+    Haddpd => "haddpd", Sse, Sequential, [], [];
+    Haddps => "haddps", Sse, Sequential, [], [];
+    Hlt => "hlt", System, Halt, [], [];
+    Hreset => "hreset", System, Sequential, [], [];
+    Hsubpd => "hsubpd", Sse, Sequential, [], [];
+    Hsubps => "hsubps", Sse, Sequential, [], [];
+    Ibts => "ibts", General, Sequential, [], [];
+    Idiv => "idiv", General, Sequential, [], [OF SF ZF AF CF PF];
+    Imul => "imul", General, Sequential, [], [OF SF ZF AF CF PF];
+    In => "in", System, Sequential, [], [];
+    Inc => "inc", General, Sequential, [], [OF SF ZF AF PF];
+    Incsspd => "incsspd", General, Sequential, [], [];
+    Incsspq => "incsspq", General, Sequential, [], [];
+    Insb => "insb", System, Sequential, [DF], [];
+    Insd => "insd", System, Sequential, [DF], [];
+    Insertps => "insertps", Sse, Sequential, [], [];
+    Insertq => "insertq", Sse, Sequential, [], [];
+    Insw => "insw", System, Sequential, [DF], [];
+    Int => "int", ControlFlow, Interrupt, [], [];
+    Int1 => "int1", ControlFlow, Interrupt, [], [];
+    Int3 => "int3", ControlFlow, Interrupt, [], [];
+    Into => "into", ControlFlow, Interrupt, [OF], [];
+    Invd => "invd", System, Sequential, [], [];
+    Invept => "invept", System, Sequential, [], [OF SF ZF AF CF PF];
+    Invlpg => "invlpg", System, Sequential, [], [];
+    Invlpga => "invlpga", System, Sequential, [], [];
+    Invlpgb => "invlpgb", System, Sequential, [], [];
+    Invpcid => "invpcid", System, Sequential, [], [];
+    Invvpid => "invvpid", System, Sequential, [], [OF SF ZF AF CF PF];
+    Iret => "iret", ControlFlow, Return, [], [OF SF ZF AF CF PF DF IF];
+    Iretd => "iretd", ControlFlow, Return, [], [OF SF ZF AF CF PF DF IF AC];
+    Iretq => "iretq", ControlFlow, Return, [], [OF SF ZF AF CF PF DF IF AC];
+    Ja => "ja", ControlFlow, ConditionalJump, [ZF CF], [];
+    Jae => "jae", ControlFlow, ConditionalJump, [CF], [];
+    Jb => "jb", ControlFlow, ConditionalJump, [CF], [];
+    Jbe => "jbe", ControlFlow, ConditionalJump, [ZF CF], [];
+    Jcxz => "jcxz", ControlFlow, ConditionalJump, [], [];
+    Je => "je", ControlFlow, ConditionalJump, [ZF], [];
+    Jecxz => "jecxz", ControlFlow, ConditionalJump, [], [];
+    Jg => "jg", ControlFlow, ConditionalJump, [OF SF ZF], [];
+    Jge => "jge", ControlFlow, ConditionalJump, [OF SF], [];
+    Jknzd => "jknzd", ControlFlow, ConditionalJump, [], [];
+    Jkzd => "jkzd", ControlFlow, ConditionalJump, [], [];
+    Jl => "jl", ControlFlow, ConditionalJump, [OF SF], [];
+    Jle => "jle", ControlFlow, ConditionalJump, [OF SF ZF], [];
+    Jmp => "jmp", ControlFlow, Jump, [], [];
+    Jmpe => "jmpe", ControlFlow, Jump, [], [];
+    Jne => "jne", ControlFlow, ConditionalJump, [ZF], [];
+    Jno => "jno", ControlFlow, ConditionalJump, [OF], [];
+    Jnp => "jnp", ControlFlow, ConditionalJump, [PF], [];
+    Jns => "jns", ControlFlow, ConditionalJump, [SF], [];
+    Jo => "jo", ControlFlow, ConditionalJump, [OF], [];
+    Jp => "jp", ControlFlow, ConditionalJump, [PF], [];
+    Jrcxz => "jrcxz", ControlFlow, ConditionalJump, [], [];
+    Js => "js", ControlFlow, ConditionalJump, [SF], [];
+    Kaddb => "kaddb", Avx, Sequential, [], [];
+    Kaddd => "kaddd", Avx, Sequential, [], [];
+    Kaddq => "kaddq", Avx, Sequential, [], [];
+    Kaddw => "kaddw", Avx, Sequential, [], [];
+    Kand => "kand", Avx, Sequential, [], [];
+    Kandb => "kandb", Avx, Sequential, [], [];
+    Kandd => "kandd", Avx, Sequential, [], [];
+    Kandn => "kandn", Avx, Sequential, [], [];
+    Kandnb => "kandnb", Avx, Sequential, [], [];
+    Kandnd => "kandnd", Avx, Sequential, [], [];
+    Kandnq => "kandnq", Avx, Sequential, [], [];
+    Kandnr => "kandnr", Avx, Sequential, [], [];
+    Kandnw => "kandnw", Avx, Sequential, [], [];
+    Kandq => "kandq", Avx, Sequential, [], [];
+    Kandw => "kandw", Avx, Sequential, [], [];
+    Kconcath => "kconcath", Avx, Sequential, [], [];
+    Kconcatl => "kconcatl", Avx, Sequential, [], [];
+    Kextract => "kextract", Avx, Sequential, [], [];
+    Kmerge2l1h => "kmerge2l1h", Avx, Sequential, [], [];
+    Kmerge2l1l => "kmerge2l1l", Avx, Sequential, [], [];
+    Kmov => "kmov", Avx, Sequential, [], [];
+    Kmovb => "kmovb", Avx, Sequential, [], [];
+    Kmovd => "kmovd", Avx, Sequential, [], [];
+    Kmovq => "kmovq", Avx, Sequential, [], [];
+    Kmovw => "kmovw", Avx, Sequential, [], [];
+    Knot => "knot", Avx, Sequential, [], [];
+    Knotb => "knotb", Avx, Sequential, [], [];
+    Knotd => "knotd", Avx, Sequential, [], [];
+    Knotq => "knotq", Avx, Sequential, [], [];
+    Knotw => "knotw", Avx, Sequential, [], [];
+    Kor => "kor", Avx, Sequential, [], [];
+    Korb => "korb", Avx, Sequential, [], [];
+    Kord => "kord", Avx, Sequential, [], [];
+    Korq => "korq", Avx, Sequential, [], [];
+    Kortest => "kortest", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Kortestb => "kortestb", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Kortestd => "kortestd", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Kortestq => "kortestq", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Kortestw => "kortestw", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Korw => "korw", Avx, Sequential, [], [];
+    Kshiftlb => "kshiftlb", Avx, Sequential, [], [];
+    Kshiftld => "kshiftld", Avx, Sequential, [], [];
+    Kshiftlq => "kshiftlq", Avx, Sequential, [], [];
+    Kshiftlw => "kshiftlw", Avx, Sequential, [], [];
+    Kshiftrb => "kshiftrb", Avx, Sequential, [], [];
+    Kshiftrd => "kshiftrd", Avx, Sequential, [], [];
+    Kshiftrq => "kshiftrq", Avx, Sequential, [], [];
+    Kshiftrw => "kshiftrw", Avx, Sequential, [], [];
+    Ktestb => "ktestb", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Ktestd => "ktestd", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Ktestq => "ktestq", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Ktestw => "ktestw", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Kunpckbw => "kunpckbw", Avx, Sequential, [], [];
+    Kunpckdq => "kunpckdq", Avx, Sequential, [], [];
+    Kunpckwd => "kunpckwd", Avx, Sequential, [], [];
+    Kxnor => "kxnor", Avx, Sequential, [], [];
+    Kxnorb => "kxnorb", Avx, Sequential, [], [];
+    Kxnord => "kxnord", Avx, Sequential, [], [];
+    Kxnorq => "kxnorq", Avx, Sequential, [], [];
+    Kxnorw => "kxnorw", Avx, Sequential, [], [];
+    Kxor => "kxor", Avx, Sequential, [], [];
+    Kxorb => "kxorb", Avx, Sequential, [], [];
+    Kxord => "kxord", Avx, Sequential, [], [];
+    Kxorq => "kxorq", Avx, Sequential, [], [];
+    Kxorw => "kxorw", Avx, Sequential, [], [];
+    Lahf => "lahf", General, Sequential, [SF ZF AF CF PF], [];
+    Lar => "lar", General, Sequential, [], [ZF];
+    Lddqu => "lddqu", Sse, Sequential, [], [];
+    Ldmxcsr => "ldmxcsr", Sse, Sequential, [], [];
+    Lds => "lds", General, Sequential, [], [];
+    Ldtilecfg => "ldtilecfg", Avx, Sequential, [], [];
+    Lea => "lea", General, Sequential, [], [];
+    Leave => "leave", General, Sequential, [], [];
+    Les => "les", General, Sequential, [], [];
+    Lfence => "lfence", Sse, Sequential, [], [];
+    Lfs => "lfs", General, Sequential, [], [];
+    Lgdt => "lgdt", System, Sequential, [], [];
+    Lgs => "lgs", General, Sequential, [], [];
+    Lidt => "lidt", System, Sequential, [], [];
+    Lkgs => "lkgs", System, Sequential, [], [];
+    Lldt => "lldt", System, Sequential, [], [];
+    Llwpcb => "llwpcb", Avx, Sequential, [], [];
+    Lmsw => "lmsw", System, Sequential, [], [];
+    Loadall => "loadall", System, Sequential, [], [];
+    Loadiwkey => "loadiwkey", System, Sequential, [], [OF SF ZF AF CF PF];
+    Lodsb => "lodsb", String, Sequential, [DF], [];
+    Lodsd => "lodsd", String, Sequential, [DF], [];
+    Lodsq => "lodsq", String, Sequential, [DF], [];
+    Lodsw => "lodsw", String, Sequential, [DF], [];
+    Loop => "loop", ControlFlow, ConditionalJump, [], [];
+    Loope => "loope", ControlFlow, ConditionalJump, [ZF], [];
+    Loopne => "loopne", ControlFlow, ConditionalJump, [ZF], [];
+    Lsl => "lsl", General, Sequential, [], [ZF];
+    Lss => "lss", General, Sequential, [], [];
+    Ltr => "ltr", System, Sequential, [], [];
+    Lwpins => "lwpins", Avx, Sequential, [], [CF];
+    Lwpval => "lwpval", Avx, Sequential, [], [];
+    Lzcnt => "lzcnt", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Maskmovdqu => "maskmovdqu", Sse, Sequential, [], [];
+    Maskmovq => "maskmovq", Sse, Sequential, [], [];
+    Maxpd => "maxpd", Sse, Sequential, [], [];
+    Maxps => "maxps", Sse, Sequential, [], [];
+    Maxsd => "maxsd", Sse, Sequential, [], [];
+    Maxss => "maxss", Sse, Sequential, [], [];
+    Mcommit => "mcommit", General, Sequential, [], [OF SF ZF AF CF PF];
+    Mfence => "mfence", Sse, Sequential, [], [];
+    Minpd => "minpd", Sse, Sequential, [], [];
+    Minps => "minps", Sse, Sequential, [], [];
+    Minsd => "minsd", Sse, Sequential, [], [];
+    Minss => "minss", Sse, Sequential, [], [];
+    Monitor => "monitor", System, Sequential, [], [];
+    Monitorx => "monitorx", System, Sequential, [], [];
+    Montmul => "montmul", General, Sequential, [], [];
+    Mov => "mov", General, Sequential, [], [];
+    Movapd => "movapd", Sse, Sequential, [], [];
+    Movaps => "movaps", Sse, Sequential, [], [];
+    Movbe => "movbe", General, Sequential, [], [];
+    Movd => "movd", Sse, Sequential, [], [];
+    Movddup => "movddup", Sse, Sequential, [], [];
+    Movdir64b => "movdir64b", General, Sequential, [], [];
+    Movdiri => "movdiri", General, Sequential, [], [];
+    Movdq2q => "movdq2q", Sse, Sequential, [], [];
+    Movdqa => "movdqa", Sse, Sequential, [], [];
+    Movdqu => "movdqu", Sse, Sequential, [], [];
+    Movhlps => "movhlps", Sse, Sequential, [], [];
+    Movhpd => "movhpd", Sse, Sequential, [], [];
+    Movhps => "movhps", Sse, Sequential, [], [];
+    Movlhps => "movlhps", Sse, Sequential, [], [];
+    Movlpd => "movlpd", Sse, Sequential, [], [];
+    Movlps => "movlps", Sse, Sequential, [], [];
+    Movmskpd => "movmskpd", Sse, Sequential, [], [];
+    Movmskps => "movmskps", Sse, Sequential, [], [];
+    Movntdq => "movntdq", Sse, Sequential, [], [];
+    Movntdqa => "movntdqa", Sse, Sequential, [], [];
+    Movnti => "movnti", Sse, Sequential, [], [];
+    Movntpd => "movntpd", Sse, Sequential, [], [];
+    Movntps => "movntps", Sse, Sequential, [], [];
+    Movntq => "movntq", Sse, Sequential, [], [];
+    Movntsd => "movntsd", Sse, Sequential, [], [];
+    Movntss => "movntss", Sse, Sequential, [], [];
+    Movq => "movq", Sse, Sequential, [], [];
+    Movq2dq => "movq2dq", Sse, Sequential, [], [];
+    Movsb => "movsb", String, Sequential, [DF], [];
+    Movsd => "movsd", Sse, Sequential, [DF], [];
+    Movshdup => "movshdup", Sse, Sequential, [], [];
+    Movsldup => "movsldup", Sse, Sequential, [], [];
+    Movsq => "movsq", String, Sequential, [DF], [];
+    Movss => "movss", Sse, Sequential, [], [];
+    Movsw => "movsw", String, Sequential, [DF], [];
+    Movsx => "movsx", General, Sequential, [], [];
+    Movsxd => "movsxd", General, Sequential, [], [];
+    Movupd => "movupd", Sse, Sequential, [], [];
+    Movups => "movups", Sse, Sequential, [], [];
+    Movzx => "movzx", General, Sequential, [], [];
+    Mpsadbw => "mpsadbw", Sse, Sequential, [], [];
+    Mul => "mul", General, Sequential, [], [OF SF ZF AF CF PF];
+    Mulpd => "mulpd", Sse, Sequential, [], [];
+    Mulps => "mulps", Sse, Sequential, [], [];
+    Mulsd => "mulsd", Sse, Sequential, [], [];
+    Mulss => "mulss", Sse, Sequential, [], [];
+    Mulx => "mulx", Avx, Sequential, [], [];
+    Mwait => "mwait", System, Sequential, [], [];
+    Mwaitx => "mwaitx", System, Sequential, [], [];
+    Neg => "neg", General, Sequential, [], [OF SF ZF AF CF PF];
+    Nop => "nop", General, Sequential, [], [];
+    Not => "not", General, Sequential, [], [];
+    Or => "or", General, Sequential, [], [OF SF ZF AF CF PF];
+    Orpd => "orpd", Sse, Sequential, [], [];
+    Orps => "orps", Sse, Sequential, [], [];
+    Out => "out", System, Sequential, [], [];
+    Outsb => "outsb", System, Sequential, [DF], [];
+    Outsd => "outsd", System, Sequential, [DF], [];
+    Outsw => "outsw", System, Sequential, [DF], [];
+    Pabsb => "pabsb", Sse, Sequential, [], [];
+    Pabsd => "pabsd", Sse, Sequential, [], [];
+    Pabsw => "pabsw", Sse, Sequential, [], [];
+    Packssdw => "packssdw", Sse, Sequential, [], [];
+    Packsswb => "packsswb", Sse, Sequential, [], [];
+    Packusdw => "packusdw", Sse, Sequential, [], [];
+    Packuswb => "packuswb", Sse, Sequential, [], [];
+    Paddb => "paddb", Sse, Sequential, [], [];
+    Paddd => "paddd", Sse, Sequential, [], [];
+    Paddq => "paddq", Sse, Sequential, [], [];
+    Paddsb => "paddsb", Sse, Sequential, [], [];
+    Paddsiw => "paddsiw", Mmx, Sequential, [], [];
+    Paddsw => "paddsw", Sse, Sequential, [], [];
+    Paddusb => "paddusb", Sse, Sequential, [], [];
+    Paddusw => "paddusw", Sse, Sequential, [], [];
+    Paddw => "paddw", Sse, Sequential, [], [];
+    Palignr => "palignr", Sse, Sequential, [], [];
+    Pand => "pand", Sse, Sequential, [], [];
+    Pandn => "pandn", Sse, Sequential, [], [];
+    Pause => "pause", General, Sequential, [], [];
+    Paveb => "paveb", Mmx, Sequential, [], [];
+    Pavgb => "pavgb", Sse, Sequential, [], [];
+    Pavgusb => "pavgusb", Mmx, Sequential, [], [];
+    Pavgw => "pavgw", Sse, Sequential, [], [];
+    Pblendvb => "pblendvb", Sse, Sequential, [], [];
+    Pblendw => "pblendw", Sse, Sequential, [], [];
+    Pbndkb => "pbndkb", System, Sequential, [], [OF SF ZF AF CF PF];
+    Pclmulqdq => "pclmulqdq", Sse, Sequential, [], [];
+    Pcmpeqb => "pcmpeqb", Sse, Sequential, [], [];
+    Pcmpeqd => "pcmpeqd", Sse, Sequential, [], [];
+    Pcmpeqq => "pcmpeqq", Sse, Sequential, [], [];
+    Pcmpeqw => "pcmpeqw", Sse, Sequential, [], [];
+    Pcmpestri => "pcmpestri", Sse, Sequential, [], [OF SF ZF AF CF PF];
+    Pcmpestri64 => "pcmpestri64", Sse, Sequential, [], [OF SF ZF AF CF PF];
+    Pcmpestrm => "pcmpestrm", Sse, Sequential, [], [OF SF ZF AF CF PF];
+    Pcmpestrm64 => "pcmpestrm64", Sse, Sequential, [], [OF SF ZF AF CF PF];
+    Pcmpgtb => "pcmpgtb", Sse, Sequential, [], [];
+    Pcmpgtd => "pcmpgtd", Sse, Sequential, [], [];
+    Pcmpgtq => "pcmpgtq", Sse, Sequential, [], [];
+    Pcmpgtw => "pcmpgtw", Sse, Sequential, [], [];
+    Pcmpistri => "pcmpistri", Sse, Sequential, [], [OF SF ZF AF CF PF];
+    Pcmpistrm => "pcmpistrm", Sse, Sequential, [], [OF SF ZF AF CF PF];
+    Pcommit => "pcommit", General, Sequential, [], [];
+    Pconfig => "pconfig", System, Sequential, [], [OF SF ZF AF CF PF];
+    Pdep => "pdep", Avx, Sequential, [], [];
+    Pdistib => "pdistib", Mmx, Sequential, [], [];
+    Pext => "pext", Avx, Sequential, [], [];
+    Pextrb => "pextrb", Sse, Sequential, [], [];
+    Pextrd => "pextrd", Sse, Sequential, [], [];
+    Pextrq => "pextrq", Sse, Sequential, [], [];
+    Pextrw => "pextrw", Sse, Sequential, [], [];
+    Pf2id => "pf2id", Mmx, Sequential, [], [];
+    Pf2iw => "pf2iw", Mmx, Sequential, [], [];
+    Pfacc => "pfacc", Mmx, Sequential, [], [];
+    Pfadd => "pfadd", Mmx, Sequential, [], [];
+    Pfcmpeq => "pfcmpeq", Mmx, Sequential, [], [];
+    Pfcmpge => "pfcmpge", Mmx, Sequential, [], [];
+    Pfcmpgt => "pfcmpgt", Mmx, Sequential, [], [];
+    Pfmax => "pfmax", Mmx, Sequential, [], [];
+    Pfmin => "pfmin", Mmx, Sequential, [], [];
+    Pfmul => "pfmul", Mmx, Sequential, [], [];
+    Pfnacc => "pfnacc", Mmx, Sequential, [], [];
+    Pfpnacc => "pfpnacc", Mmx, Sequential, [], [];
+    Pfrcp => "pfrcp", Mmx, Sequential, [], [];
+    Pfrcpit1 => "pfrcpit1", Mmx, Sequential, [], [];
+    Pfrcpit2 => "pfrcpit2", Mmx, Sequential, [], [];
+    Pfrcpv => "pfrcpv", Mmx, Sequential, [], [];
+    Pfrsqit1 => "pfrsqit1", Mmx, Sequential, [], [];
+    Pfrsqrt => "pfrsqrt", Mmx, Sequential, [], [];
+    Pfrsqrtv => "pfrsqrtv", Mmx, Sequential, [], [];
+    Pfsub => "pfsub", Mmx, Sequential, [], [];
+    Pfsubr => "pfsubr", Mmx, Sequential, [], [];
+    Phaddd => "phaddd", Sse, Sequential, [], [];
+    Phaddsw => "phaddsw", Sse, Sequential, [], [];
+    Phaddw => "phaddw", Sse, Sequential, [], [];
+    Phminposuw => "phminposuw", Sse, Sequential, [], [];
+    Phsubd => "phsubd", Sse, Sequential, [], [];
+    Phsubsw => "phsubsw", Sse, Sequential, [], [];
+    Phsubw => "phsubw", Sse, Sequential, [], [];
+    Pi2fd => "pi2fd", Mmx, Sequential, [], [];
+    Pi2fw => "pi2fw", Mmx, Sequential, [], [];
+    Pinsrb => "pinsrb", Sse, Sequential, [], [];
+    Pinsrd => "pinsrd", Sse, Sequential, [], [];
+    Pinsrq => "pinsrq", Sse, Sequential, [], [];
+    Pinsrw => "pinsrw", Sse, Sequential, [], [];
+    Pmachriw => "pmachriw", Mmx, Sequential, [], [];
+    Pmaddubsw => "pmaddubsw", Sse, Sequential, [], [];
+    Pmaddwd => "pmaddwd", Sse, Sequential, [], [];
+    Pmagw => "pmagw", Mmx, Sequential, [], [];
+    Pmaxsb => "pmaxsb", Sse, Sequential, [], [];
+    Pmaxsd => "pmaxsd", Sse, Sequential, [], [];
+    Pmaxsw => "pmaxsw", Sse, Sequential, [], [];
+    Pmaxub => "pmaxub", Sse, Sequential, [], [];
+    Pmaxud => "pmaxud", Sse, Sequential, [], [];
+    Pmaxuw => "pmaxuw", Sse, Sequential, [], [];
+    Pminsb => "pminsb", Sse, Sequential, [], [];
+    Pminsd => "pminsd", Sse, Sequential, [], [];
+    Pminsw => "pminsw", Sse, Sequential, [], [];
+    Pminub => "pminub", Sse, Sequential, [], [];
+    Pminud => "pminud", Sse, Sequential, [], [];
+    Pminuw => "pminuw", Sse, Sequential, [], [];
+    Pmovmskb => "pmovmskb", Sse, Sequential, [], [];
+    Pmovsxbd => "pmovsxbd", Sse, Sequential, [], [];
+    Pmovsxbq => "pmovsxbq", Sse, Sequential, [], [];
+    Pmovsxbw => "pmovsxbw", Sse, Sequential, [], [];
+    Pmovsxdq => "pmovsxdq", Sse, Sequential, [], [];
+    Pmovsxwd => "pmovsxwd", Sse, Sequential, [], [];
+    Pmovsxwq => "pmovsxwq", Sse, Sequential, [], [];
+    Pmovzxbd => "pmovzxbd", Sse, Sequential, [], [];
+    Pmovzxbq => "pmovzxbq", Sse, Sequential, [], [];
+    Pmovzxbw => "pmovzxbw", Sse, Sequential, [], [];
+    Pmovzxdq => "pmovzxdq", Sse, Sequential, [], [];
+    Pmovzxwd => "pmovzxwd", Sse, Sequential, [], [];
+    Pmovzxwq => "pmovzxwq", Sse, Sequential, [], [];
+    Pmuldq => "pmuldq", Sse, Sequential, [], [];
+    Pmulhriw => "pmulhriw", Mmx, Sequential, [], [];
+    Pmulhrsw => "pmulhrsw", Sse, Sequential, [], [];
+    Pmulhrw => "pmulhrw", Mmx, Sequential, [], [];
+    Pmulhuw => "pmulhuw", Sse, Sequential, [], [];
+    Pmulhw => "pmulhw", Sse, Sequential, [], [];
+    Pmulld => "pmulld", Sse, Sequential, [], [];
+    Pmullw => "pmullw", Sse, Sequential, [], [];
+    Pmuludq => "pmuludq", Sse, Sequential, [], [];
+    Pmvgezb => "pmvgezb", Mmx, Sequential, [], [];
+    Pmvlzb => "pmvlzb", Mmx, Sequential, [], [];
+    Pmvnzb => "pmvnzb", Mmx, Sequential, [], [];
+    Pmvzb => "pmvzb", Mmx, Sequential, [], [];
+    Pop => "pop", General, Sequential, [], [];
+    Popa => "popa", General, Sequential, [], [];
+    Popad => "popad", General, Sequential, [], [];
+    Popcnt => "popcnt", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Popf => "popf", General, Sequential, [], [OF SF ZF AF CF PF DF IF];
+    Popfd => "popfd", General, Sequential, [], [OF SF ZF AF CF PF DF IF AC];
+    Popfq => "popfq", General, Sequential, [], [OF SF ZF AF CF PF DF IF AC];
+    Por => "por", Sse, Sequential, [], [];
+    Prefetch => "prefetch", General, Sequential, [], [];
+    Prefetchit0 => "prefetchit0", General, Sequential, [], [];
+    Prefetchit1 => "prefetchit1", General, Sequential, [], [];
+    Prefetchnta => "prefetchnta", Sse, Sequential, [], [];
+    Prefetcht0 => "prefetcht0", Sse, Sequential, [], [];
+    Prefetcht1 => "prefetcht1", Sse, Sequential, [], [];
+    Prefetcht2 => "prefetcht2", Sse, Sequential, [], [];
+    Prefetchw => "prefetchw", General, Sequential, [], [];
+    Prefetchwt1 => "prefetchwt1", General, Sequential, [], [];
+    Psadbw => "psadbw", Sse, Sequential, [], [];
+    Pshufb => "pshufb", Sse, Sequential, [], [];
+    Pshufd => "pshufd", Sse, Sequential, [], [];
+    Pshufhw => "pshufhw", Sse, Sequential, [], [];
+    Pshuflw => "pshuflw", Sse, Sequential, [], [];
+    Pshufw => "pshufw", Sse, Sequential, [], [];
+    Psignb => "psignb", Sse, Sequential, [], [];
+    Psignd => "psignd", Sse, Sequential, [], [];
+    Psignw => "psignw", Sse, Sequential, [], [];
+    Pslld => "pslld", Sse, Sequential, [], [];
+    Pslldq => "pslldq", Sse, Sequential, [], [];
+    Psllq => "psllq", Sse, Sequential, [], [];
+    Psllw => "psllw", Sse, Sequential, [], [];
+    Psmash => "psmash", System, Sequential, [], [OF SF ZF AF PF];
+    Psrad => "psrad", Sse, Sequential, [], [];
+    Psraw => "psraw", Sse, Sequential, [], [];
+    Psrld => "psrld", Sse, Sequential, [], [];
+    Psrldq => "psrldq", Sse, Sequential, [], [];
+    Psrlq => "psrlq", Sse, Sequential, [], [];
+    Psrlw => "psrlw", Sse, Sequential, [], [];
+    Psubb => "psubb", Sse, Sequential, [], [];
+    Psubd => "psubd", Sse, Sequential, [], [];
+    Psubq => "psubq", Sse, Sequential, [], [];
+    Psubsb => "psubsb", Sse, Sequential, [], [];
+    Psubsiw => "psubsiw", Mmx, Sequential, [], [];
+    Psubsw => "psubsw", Sse, Sequential, [], [];
+    Psubusb => "psubusb", Sse, Sequential, [], [];
+    Psubusw => "psubusw", Sse, Sequential, [], [];
+    Psubw => "psubw", Sse, Sequential, [], [];
+    Pswapd => "pswapd", Mmx, Sequential, [], [];
+    Ptest => "ptest", Sse, Sequential, [], [OF SF ZF AF CF PF];
+    Ptwrite => "ptwrite", General, Sequential, [], [];
+    Punpckhbw => "punpckhbw", Sse, Sequential, [], [];
+    Punpckhdq => "punpckhdq", Sse, Sequential, [], [];
+    Punpckhqdq => "punpckhqdq", Sse, Sequential, [], [];
+    Punpckhwd => "punpckhwd", Sse, Sequential, [], [];
+    Punpcklbw => "punpcklbw", Sse, Sequential, [], [];
+    Punpckldq => "punpckldq", Sse, Sequential, [], [];
+    Punpcklqdq => "punpcklqdq", Sse, Sequential, [], [];
+    Punpcklwd => "punpcklwd", Sse, Sequential, [], [];
+    Push => "push", General, Sequential, [], [];
+    Pusha => "pusha", General, Sequential, [], [];
+    Pushad => "pushad", General, Sequential, [], [];
+    Pushf => "pushf", General, Sequential, [OF SF ZF AF CF PF DF IF], [];
+    Pushfd => "pushfd", General, Sequential, [OF SF ZF AF CF PF DF IF AC], [];
+    Pushfq => "pushfq", General, Sequential, [OF SF ZF AF CF PF DF IF AC], [];
+    Pvalidate => "pvalidate", System, Sequential, [], [OF SF ZF AF CF PF];
+    Pxor => "pxor", Sse, Sequential, [], [];
+    Rcl => "rcl", General, Sequential, [CF], [OF CF];
+    Rcpps => "rcpps", Sse, Sequential, [], [];
+    Rcpss => "rcpss", Sse, Sequential, [], [];
+    Rcr => "rcr", General, Sequential, [CF], [OF CF];
+    Rdfsbase => "rdfsbase", System, Sequential, [], [];
+    Rdgsbase => "rdgsbase", System, Sequential, [], [];
+    Rdm => "rdm", System, Return, [], [OF SF ZF AF CF PF DF IF AC];
+    Rdmsr => "rdmsr", System, Sequential, [], [];
+    Rdmsrlist => "rdmsrlist", System, Sequential, [], [];
+    Rdpid => "rdpid", System, Sequential, [], [];
+    Rdpkru => "rdpkru", General, Sequential, [], [];
+    Rdpmc => "rdpmc", General, Sequential, [], [];
+    Rdpru => "rdpru", System, Sequential, [], [OF SF ZF AF CF PF];
+    Rdrand => "rdrand", General, Sequential, [], [OF SF ZF AF CF PF];
+    Rdseed => "rdseed", General, Sequential, [], [OF SF ZF AF CF PF];
+    Rdshr => "rdshr", System, Sequential, [], [];
+    Rdsspd => "rdsspd", General, Sequential, [], [];
+    Rdsspq => "rdsspq", General, Sequential, [], [];
+    Rdtsc => "rdtsc", System, Sequential, [], [];
+    Rdtscp => "rdtscp", System, Sequential, [], [];
+    Rdudbg => "rdudbg", System, Sequential, [], [];
+    Reservednop => "reservednop", General, Sequential, [], [];
+    Ret => "ret", ControlFlow, Return, [], [];
+    Retf => "retf", ControlFlow, Return, [], [];
+    Rmpadjust => "rmpadjust", System, Sequential, [], [OF SF ZF AF PF];
+    Rmpquery => "rmpquery", System, Sequential, [], [OF SF ZF AF PF];
+    Rmpupdate => "rmpupdate", System, Sequential, [], [OF SF ZF AF PF];
+    Rol => "rol", General, Sequential, [], [OF CF];
+    Ror => "ror", General, Sequential, [], [OF CF];
+    Rorx => "rorx", Avx, Sequential, [], [];
+    Roundpd => "roundpd", Sse, Sequential, [], [];
+    Roundps => "roundps", Sse, Sequential, [], [];
+    Roundsd => "roundsd", Sse, Sequential, [], [];
+    Roundss => "roundss", Sse, Sequential, [], [];
+    Rsdc => "rsdc", System, Sequential, [], [];
+    Rsldt => "rsldt", System, Sequential, [], [];
+    Rsm => "rsm", System, Return, [], [OF SF ZF AF CF PF DF IF AC];
+    Rsqrtps => "rsqrtps", Sse, Sequential, [], [];
+    Rsqrtss => "rsqrtss", Sse, Sequential, [], [];
+    Rstorssp => "rstorssp", General, Sequential, [], [OF SF ZF AF CF PF];
+    Rsts => "rsts", System, Sequential, [], [];
+    Sahf => "sahf", General, Sequential, [], [SF ZF AF CF PF];
+    Sal => "sal", General, Sequential, [], [OF SF ZF AF CF PF];
+    Salc => "salc", General, Sequential, [CF], [];
+    Sar => "sar", General, Sequential, [], [OF SF ZF AF CF PF];
+    Sarx => "sarx", Avx, Sequential, [], [];
+    Saveprevssp => "saveprevssp", General, Sequential, [CF], [];
+    Sbb => "sbb", General, Sequential, [CF], [OF SF ZF AF CF PF];
+    Scasb => "scasb", String, Sequential, [DF], [OF SF ZF AF CF PF];
+    Scasd => "scasd", String, Sequential, [DF], [OF SF ZF AF CF PF];
+    Scasq => "scasq", String, Sequential, [DF], [OF SF ZF AF CF PF];
+    Scasw => "scasw", String, Sequential, [DF], [OF SF ZF AF CF PF];
+    Seamcall => "seamcall", System, Interrupt, [], [OF SF ZF AF CF PF];
+    Seamops => "seamops", System, Sequential, [], [OF SF ZF AF CF PF];
+    Seamret => "seamret", System, Interrupt, [], [OF SF ZF AF CF PF];
+    Senduipi => "senduipi", General, Sequential, [], [];
+    Serialize => "serialize", General, Sequential, [], [];
+    Seta => "seta", General, Sequential, [ZF CF], [];
+    Setae => "setae", General, Sequential, [CF], [];
+    Setb => "setb", General, Sequential, [CF], [];
+    Setbe => "setbe", General, Sequential, [ZF CF], [];
+    Sete => "sete", General, Sequential, [ZF], [];
+    Setg => "setg", General, Sequential, [OF SF ZF], [];
+    Setge => "setge", General, Sequential, [OF SF], [];
+    Setl => "setl", General, Sequential, [OF SF], [];
+    Setle => "setle", General, Sequential, [OF SF ZF], [];
+    Setne => "setne", General, Sequential, [ZF], [];
+    Setno => "setno", General, Sequential, [OF], [];
+    Setnp => "setnp", General, Sequential, [PF], [];
+    Setns => "setns", General, Sequential, [SF], [];
+    Seto => "seto", General, Sequential, [OF], [];
+    Setp => "setp", General, Sequential, [PF], [];
+    Sets => "sets", General, Sequential, [SF], [];
+    Setssbsy => "setssbsy", System, Sequential, [], [];
+    Sfence => "sfence", Sse, Sequential, [], [];
+    Sgdt => "sgdt", General, Sequential, [], [];
+    Sha1msg1 => "sha1msg1", Sse, Sequential, [], [];
+    Sha1msg2 => "sha1msg2", Sse, Sequential, [], [];
+    Sha1nexte => "sha1nexte", Sse, Sequential, [], [];
+    Sha1rnds4 => "sha1rnds4", Sse, Sequential, [], [];
+    Sha256msg1 => "sha256msg1", Sse, Sequential, [], [];
+    Sha256msg2 => "sha256msg2", Sse, Sequential, [], [];
+    Sha256rnds2 => "sha256rnds2", Sse, Sequential, [], [];
+    Shl => "shl", General, Sequential, [], [OF SF ZF AF CF PF];
+    Shld => "shld", General, Sequential, [], [OF SF ZF AF CF PF];
+    Shlx => "shlx", Avx, Sequential, [], [];
+    Shr => "shr", General, Sequential, [], [OF SF ZF AF CF PF];
+    Shrd => "shrd", General, Sequential, [], [OF SF ZF AF CF PF];
+    Shrx => "shrx", Avx, Sequential, [], [];
+    Shufpd => "shufpd", Sse, Sequential, [], [];
+    Shufps => "shufps", Sse, Sequential, [], [];
+    Sidt => "sidt", General, Sequential, [], [];
+    Skinit => "skinit", System, Return, [], [OF SF ZF AF CF PF DF IF AC];
+    Sldt => "sldt", General, Sequential, [], [];
+    Slwpcb => "slwpcb", Avx, Sequential, [], [];
+    Smint => "smint", System, Interrupt, [], [];
+    Smsw => "smsw", General, Sequential, [], [];
+    Spflt => "spflt", Avx, Sequential, [], [];
+    Sqrtpd => "sqrtpd", Sse, Sequential, [], [];
+    Sqrtps => "sqrtps", Sse, Sequential, [], [];
+    Sqrtsd => "sqrtsd", Sse, Sequential, [], [];
+    Sqrtss => "sqrtss", Sse, Sequential, [], [];
+    Stac => "stac", System, Sequential, [], [AC];
+    Stc => "stc", General, Sequential, [], [CF];
+    Std => "std", General, Sequential, [], [DF];
+    Stgi => "stgi", System, Sequential, [], [];
+    Sti => "sti", System, Sequential, [], [IF];
+    Stmxcsr => "stmxcsr", Sse, Sequential, [], [];
+    Storeall => "storeall", System, Sequential, [], [];
+    Stosb => "stosb", String, Sequential, [DF], [];
+    Stosd => "stosd", String, Sequential, [DF], [];
+    Stosq => "stosq", String, Sequential, [DF], [];
+    Stosw => "stosw", String, Sequential, [DF], [];
+    Str => "str", General, Sequential, [], [];
+    Sttilecfg => "sttilecfg", Avx, Sequential, [], [];
+    Stui => "stui", General, Sequential, [], [];
+    Sub => "sub", General, Sequential, [], [OF SF ZF AF CF PF];
+    Subpd => "subpd", Sse, Sequential, [], [];
+    Subps => "subps", Sse, Sequential, [], [];
+    Subsd => "subsd", Sse, Sequential, [], [];
+    Subss => "subss", Sse, Sequential, [], [];
+    Svdc => "svdc", System, Sequential, [], [];
+    Svldt => "svldt", System, Sequential, [], [];
+    Svts => "svts", System, Sequential, [], [];
+    Swapgs => "swapgs", System, Sequential, [], [];
+    Syscall => "syscall", System, Interrupt, [OF SF ZF AF CF PF DF IF AC], [OF SF ZF AF CF PF DF IF AC];
+    Sysenter => "sysenter", System, Interrupt, [], [IF];
+    Sysexit => "sysexit", System, Return, [], [];
+    Sysexitq => "sysexitq", System, Return, [], [];
+    Sysret => "sysret", System, Return, [], [OF SF ZF AF CF PF DF IF AC];
+    Sysretq => "sysretq", System, Return, [], [OF SF ZF AF CF PF DF IF AC];
+    T1mskc => "t1mskc", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Tcmmimfp16ps => "tcmmimfp16ps", Avx, Sequential, [], [];
+    Tcmmrlfp16ps => "tcmmrlfp16ps", Avx, Sequential, [], [];
+    Tdcall => "tdcall", System, Interrupt, [], [];
+    Tdpbf16ps => "tdpbf16ps", Avx, Sequential, [], [];
+    Tdpbssd => "tdpbssd", Avx, Sequential, [], [];
+    Tdpbsud => "tdpbsud", Avx, Sequential, [], [];
+    Tdpbusd => "tdpbusd", Avx, Sequential, [], [];
+    Tdpbuud => "tdpbuud", Avx, Sequential, [], [];
+    Tdpfp16ps => "tdpfp16ps", Avx, Sequential, [], [];
+    Test => "test", General, Sequential, [], [OF SF ZF AF CF PF];
+    Testui => "testui", General, Sequential, [], [OF SF ZF AF CF PF];
+    Tileloadd => "tileloadd", Avx, Sequential, [], [];
+    Tileloaddt1 => "tileloaddt1", Avx, Sequential, [], [];
+    Tilerelease => "tilerelease", Avx, Sequential, [], [];
+    Tilestored => "tilestored", Avx, Sequential, [], [];
+    Tilezero => "tilezero", Avx, Sequential, [], [];
+    Tlbsync => "tlbsync", System, Sequential, [], [];
+    Tpause => "tpause", General, Sequential, [], [OF SF ZF AF CF PF];
+    Tzcnt => "tzcnt", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Tzcnti => "tzcnti", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Tzmsk => "tzmsk", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Ucomisd => "ucomisd", Sse, Sequential, [], [OF SF ZF AF CF PF];
+    Ucomiss => "ucomiss", Sse, Sequential, [], [OF SF ZF AF CF PF];
+    Ud0 => "ud0", ControlFlow, Exception, [], [];
+    Ud1 => "ud1", ControlFlow, Exception, [], [];
+    Ud2 => "ud2", ControlFlow, Exception, [], [];
+    Uiret => "uiret", ControlFlow, Return, [], [OF SF ZF AF CF PF DF AC];
+    Umonitor => "umonitor", General, Sequential, [], [];
+    Umov => "umov", General, Sequential, [], [];
+    Umwait => "umwait", General, Sequential, [], [OF SF ZF AF CF PF];
+    Undoc => "undoc", Avx, Sequential, [], [];
+    Unpckhpd => "unpckhpd", Sse, Sequential, [], [];
+    Unpckhps => "unpckhps", Sse, Sequential, [], [];
+    Unpcklpd => "unpcklpd", Sse, Sequential, [], [];
+    Unpcklps => "unpcklps", Sse, Sequential, [], [];
+    V4fmaddps => "v4fmaddps", Avx512, Sequential, [], [];
+    V4fmaddss => "v4fmaddss", Avx512, Sequential, [], [];
+    V4fnmaddps => "v4fnmaddps", Avx512, Sequential, [], [];
+    V4fnmaddss => "v4fnmaddss", Avx512, Sequential, [], [];
+    Vaddnpd => "vaddnpd", Avx512, Sequential, [], [];
+    Vaddnps => "vaddnps", Avx512, Sequential, [], [];
+    Vaddpd => "vaddpd", Avx, Sequential, [], [];
+    Vaddph => "vaddph", Avx512, Sequential, [], [];
+    Vaddps => "vaddps", Avx, Sequential, [], [];
+    Vaddsd => "vaddsd", Avx, Sequential, [], [];
+    Vaddsetsps => "vaddsetsps", Avx512, Sequential, [], [];
+    Vaddsh => "vaddsh", Avx512, Sequential, [], [];
+    Vaddss => "vaddss", Avx, Sequential, [], [];
+    Vaddsubpd => "vaddsubpd", Avx, Sequential, [], [];
+    Vaddsubps => "vaddsubps", Avx, Sequential, [], [];
+    Vaesdec => "vaesdec", Avx, Sequential, [], [];
+    Vaesdeclast => "vaesdeclast", Avx, Sequential, [], [];
+    Vaesenc => "vaesenc", Avx, Sequential, [], [];
+    Vaesenclast => "vaesenclast", Avx, Sequential, [], [];
+    Vaesimc => "vaesimc", Avx, Sequential, [], [];
+    Vaeskeygenassist => "vaeskeygenassist", Avx, Sequential, [], [];
+    Valignd => "valignd", Avx512, Sequential, [], [];
+    Valignq => "valignq", Avx512, Sequential, [], [];
+    Vandnpd => "vandnpd", Avx, Sequential, [], [];
+    Vandnps => "vandnps", Avx, Sequential, [], [];
+    Vandpd => "vandpd", Avx, Sequential, [], [];
+    Vandps => "vandps", Avx, Sequential, [], [];
+    Vbcstnebf162ps => "vbcstnebf162ps", Avx, Sequential, [], [];
+    Vbcstnesh2ps => "vbcstnesh2ps", Avx, Sequential, [], [];
+    Vblendmpd => "vblendmpd", Avx512, Sequential, [], [];
+    Vblendmps => "vblendmps", Avx512, Sequential, [], [];
+    Vblendpd => "vblendpd", Avx, Sequential, [], [];
+    Vblendps => "vblendps", Avx, Sequential, [], [];
+    Vblendvpd => "vblendvpd", Avx, Sequential, [], [];
+    Vblendvps => "vblendvps", Avx, Sequential, [], [];
+    Vbroadcastf128 => "vbroadcastf128", Avx, Sequential, [], [];
+    Vbroadcastf32x2 => "vbroadcastf32x2", Avx512, Sequential, [], [];
+    Vbroadcastf32x4 => "vbroadcastf32x4", Avx512, Sequential, [], [];
+    Vbroadcastf32x8 => "vbroadcastf32x8", Avx512, Sequential, [], [];
+    Vbroadcastf64x2 => "vbroadcastf64x2", Avx512, Sequential, [], [];
+    Vbroadcastf64x4 => "vbroadcastf64x4", Avx512, Sequential, [], [];
+    Vbroadcasti128 => "vbroadcasti128", Avx, Sequential, [], [];
+    Vbroadcasti32x2 => "vbroadcasti32x2", Avx512, Sequential, [], [];
+    Vbroadcasti32x4 => "vbroadcasti32x4", Avx512, Sequential, [], [];
+    Vbroadcasti32x8 => "vbroadcasti32x8", Avx512, Sequential, [], [];
+    Vbroadcasti64x2 => "vbroadcasti64x2", Avx512, Sequential, [], [];
+    Vbroadcasti64x4 => "vbroadcasti64x4", Avx512, Sequential, [], [];
+    Vbroadcastsd => "vbroadcastsd", Avx, Sequential, [], [];
+    Vbroadcastss => "vbroadcastss", Avx, Sequential, [], [];
+    Vcmppd => "vcmppd", Avx, Sequential, [], [];
+    Vcmpph => "vcmpph", Avx512, Sequential, [], [];
+    Vcmpps => "vcmpps", Avx, Sequential, [], [];
+    Vcmpsd => "vcmpsd", Avx, Sequential, [], [];
+    Vcmpsh => "vcmpsh", Avx512, Sequential, [], [];
+    Vcmpss => "vcmpss", Avx, Sequential, [], [];
+    Vcomisd => "vcomisd", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Vcomish => "vcomish", Avx512, Sequential, [], [OF SF ZF AF CF PF];
+    Vcomiss => "vcomiss", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Vcompresspd => "vcompresspd", Avx512, Sequential, [], [];
+    Vcompressps => "vcompressps", Avx512, Sequential, [], [];
+    Vcvtdq2pd => "vcvtdq2pd", Avx, Sequential, [], [];
+    Vcvtdq2ph => "vcvtdq2ph", Avx512, Sequential, [], [];
+    Vcvtdq2ps => "vcvtdq2ps", Avx, Sequential, [], [];
+    Vcvtfxpntdq2ps => "vcvtfxpntdq2ps", Avx512, Sequential, [], [];
+    Vcvtfxpntpd2dq => "vcvtfxpntpd2dq", Avx512, Sequential, [], [];
+    Vcvtfxpntpd2udq => "vcvtfxpntpd2udq", Avx512, Sequential, [], [];
+    Vcvtfxpntps2dq => "vcvtfxpntps2dq", Avx512, Sequential, [], [];
+    Vcvtfxpntps2udq => "vcvtfxpntps2udq", Avx512, Sequential, [], [];
+    Vcvtfxpntudq2ps => "vcvtfxpntudq2ps", Avx512, Sequential, [], [];
+    Vcvtne2ps2bf16 => "vcvtne2ps2bf16", Avx512, Sequential, [], [];
+    Vcvtneebf162ps => "vcvtneebf162ps", Avx, Sequential, [], [];
+    Vcvtneeph2ps => "vcvtneeph2ps", Avx, Sequential, [], [];
+    Vcvtneobf162ps => "vcvtneobf162ps", Avx, Sequential, [], [];
+    Vcvtneoph2ps => "vcvtneoph2ps", Avx, Sequential, [], [];
+    Vcvtneps2bf16 => "vcvtneps2bf16", Avx, Sequential, [], [];
+    Vcvtpd2dq => "vcvtpd2dq", Avx, Sequential, [], [];
+    Vcvtpd2ph => "vcvtpd2ph", Avx512, Sequential, [], [];
+    Vcvtpd2ps => "vcvtpd2ps", Avx, Sequential, [], [];
+    Vcvtpd2qq => "vcvtpd2qq", Avx512, Sequential, [], [];
+    Vcvtpd2udq => "vcvtpd2udq", Avx512, Sequential, [], [];
+    Vcvtpd2uqq => "vcvtpd2uqq", Avx512, Sequential, [], [];
+    Vcvtph2dq => "vcvtph2dq", Avx512, Sequential, [], [];
+    Vcvtph2pd => "vcvtph2pd", Avx512, Sequential, [], [];
+    Vcvtph2ps => "vcvtph2ps", Avx, Sequential, [], [];
+    Vcvtph2psx => "vcvtph2psx", Avx512, Sequential, [], [];
+    Vcvtph2qq => "vcvtph2qq", Avx512, Sequential, [], [];
+    Vcvtph2udq => "vcvtph2udq", Avx512, Sequential, [], [];
+    Vcvtph2uqq => "vcvtph2uqq", Avx512, Sequential, [], [];
+    Vcvtph2uw => "vcvtph2uw", Avx512, Sequential, [], [];
+    Vcvtph2w => "vcvtph2w", Avx512, Sequential, [], [];
+    Vcvtps2dq => "vcvtps2dq", Avx, Sequential, [], [];
+    Vcvtps2pd => "vcvtps2pd", Avx, Sequential, [], [];
+    Vcvtps2ph => "vcvtps2ph", Avx, Sequential, [], [];
+    Vcvtps2phx => "vcvtps2phx", Avx512, Sequential, [], [];
+    Vcvtps2qq => "vcvtps2qq", Avx512, Sequential, [], [];
+    Vcvtps2udq => "vcvtps2udq", Avx512, Sequential, [], [];
+    Vcvtps2uqq => "vcvtps2uqq", Avx512, Sequential, [], [];
+    Vcvtqq2pd => "vcvtqq2pd", Avx512, Sequential, [], [];
+    Vcvtqq2ph => "vcvtqq2ph", Avx512, Sequential, [], [];
+    Vcvtqq2ps => "vcvtqq2ps", Avx512, Sequential, [], [];
+    Vcvtsd2sh => "vcvtsd2sh", Avx512, Sequential, [], [];
+    Vcvtsd2si => "vcvtsd2si", Avx, Sequential, [], [];
+    Vcvtsd2ss => "vcvtsd2ss", Avx, Sequential, [], [];
+    Vcvtsd2usi => "vcvtsd2usi", Avx512, Sequential, [], [];
+    Vcvtsh2sd => "vcvtsh2sd", Avx512, Sequential, [], [];
+    Vcvtsh2si => "vcvtsh2si", Avx512, Sequential, [], [];
+    Vcvtsh2ss => "vcvtsh2ss", Avx512, Sequential, [], [];
+    Vcvtsh2usi => "vcvtsh2usi", Avx512, Sequential, [], [];
+    Vcvtsi2sd => "vcvtsi2sd", Avx, Sequential, [], [];
+    Vcvtsi2sh => "vcvtsi2sh", Avx512, Sequential, [], [];
+    Vcvtsi2ss => "vcvtsi2ss", Avx, Sequential, [], [];
+    Vcvtss2sd => "vcvtss2sd", Avx, Sequential, [], [];
+    Vcvtss2sh => "vcvtss2sh", Avx512, Sequential, [], [];
+    Vcvtss2si => "vcvtss2si", Avx, Sequential, [], [];
+    Vcvtss2usi => "vcvtss2usi", Avx512, Sequential, [], [];
+    Vcvttpd2dq => "vcvttpd2dq", Avx, Sequential, [], [];
+    Vcvttpd2qq => "vcvttpd2qq", Avx512, Sequential, [], [];
+    Vcvttpd2udq => "vcvttpd2udq", Avx512, Sequential, [], [];
+    Vcvttpd2uqq => "vcvttpd2uqq", Avx512, Sequential, [], [];
+    Vcvttph2dq => "vcvttph2dq", Avx512, Sequential, [], [];
+    Vcvttph2qq => "vcvttph2qq", Avx512, Sequential, [], [];
+    Vcvttph2udq => "vcvttph2udq", Avx512, Sequential, [], [];
+    Vcvttph2uqq => "vcvttph2uqq", Avx512, Sequential, [], [];
+    Vcvttph2uw => "vcvttph2uw", Avx512, Sequential, [], [];
+    Vcvttph2w => "vcvttph2w", Avx512, Sequential, [], [];
+    Vcvttps2dq => "vcvttps2dq", Avx, Sequential, [], [];
+    Vcvttps2qq => "vcvttps2qq", Avx512, Sequential, [], [];
+    Vcvttps2udq => "vcvttps2udq", Avx512, Sequential, [], [];
+    Vcvttps2uqq => "vcvttps2uqq", Avx512, Sequential, [], [];
+    Vcvttsd2si => "vcvttsd2si", Avx, Sequential, [], [];
+    Vcvttsd2usi => "vcvttsd2usi", Avx512, Sequential, [], [];
+    Vcvttsh2si => "vcvttsh2si", Avx512, Sequential, [], [];
+    Vcvttsh2usi => "vcvttsh2usi", Avx512, Sequential, [], [];
+    Vcvttss2si => "vcvttss2si", Avx, Sequential, [], [];
+    Vcvttss2usi => "vcvttss2usi", Avx512, Sequential, [], [];
+    Vcvtudq2pd => "vcvtudq2pd", Avx512, Sequential, [], [];
+    Vcvtudq2ph => "vcvtudq2ph", Avx512, Sequential, [], [];
+    Vcvtudq2ps => "vcvtudq2ps", Avx512, Sequential, [], [];
+    Vcvtuqq2pd => "vcvtuqq2pd", Avx512, Sequential, [], [];
+    Vcvtuqq2ph => "vcvtuqq2ph", Avx512, Sequential, [], [];
+    Vcvtuqq2ps => "vcvtuqq2ps", Avx512, Sequential, [], [];
+    Vcvtusi2sd => "vcvtusi2sd", Avx512, Sequential, [], [];
+    Vcvtusi2sh => "vcvtusi2sh", Avx512, Sequential, [], [];
+    Vcvtusi2ss => "vcvtusi2ss", Avx512, Sequential, [], [];
+    Vcvtuw2ph => "vcvtuw2ph", Avx512, Sequential, [], [];
+    Vcvtw2ph => "vcvtw2ph", Avx512, Sequential, [], [];
+    Vdbpsadbw => "vdbpsadbw", Avx512, Sequential, [], [];
+    Vdivpd => "vdivpd", Avx, Sequential, [], [];
+    Vdivph => "vdivph", Avx512, Sequential, [], [];
+    Vdivps => "vdivps", Avx, Sequential, [], [];
+    Vdivsd => "vdivsd", Avx, Sequential, [], [];
+    Vdivsh => "vdivsh", Avx512, Sequential, [], [];
+    Vdivss => "vdivss", Avx, Sequential, [], [];
+    Vdpbf16ps => "vdpbf16ps", Avx512, Sequential, [], [];
+    Vdppd => "vdppd", Avx, Sequential, [], [];
+    Vdpps => "vdpps", Avx, Sequential, [], [];
+    Verr => "verr", General, Sequential, [], [ZF];
+    Verw => "verw", General, Sequential, [], [ZF];
+    Vexp223ps => "vexp223ps", Avx512, Sequential, [], [];
+    Vexp2pd => "vexp2pd", Avx512, Sequential, [], [];
+    Vexp2ps => "vexp2ps", Avx512, Sequential, [], [];
+    Vexpandpd => "vexpandpd", Avx512, Sequential, [], [];
+    Vexpandps => "vexpandps", Avx512, Sequential, [], [];
+    Vextractf128 => "vextractf128", Avx, Sequential, [], [];
+    Vextractf32x4 => "vextractf32x4", Avx512, Sequential, [], [];
+    Vextractf32x8 => "vextractf32x8", Avx512, Sequential, [], [];
+    Vextractf64x2 => "vextractf64x2", Avx512, Sequential, [], [];
+    Vextractf64x4 => "vextractf64x4", Avx512, Sequential, [], [];
+    Vextracti128 => "vextracti128", Avx, Sequential, [], [];
+    Vextracti32x4 => "vextracti32x4", Avx512, Sequential, [], [];
+    Vextracti32x8 => "vextracti32x8", Avx512, Sequential, [], [];
+    Vextracti64x2 => "vextracti64x2", Avx512, Sequential, [], [];
+    Vextracti64x4 => "vextracti64x4", Avx512, Sequential, [], [];
+    Vextractps => "vextractps", Avx, Sequential, [], [];
+    Vfcmaddcph => "vfcmaddcph", Avx512, Sequential, [], [];
+    Vfcmaddcsh => "vfcmaddcsh", Avx512, Sequential, [], [];
+    Vfcmulcph => "vfcmulcph", Avx512, Sequential, [], [];
+    Vfcmulcsh => "vfcmulcsh", Avx512, Sequential, [], [];
+    Vfixupimmpd => "vfixupimmpd", Avx512, Sequential, [], [];
+    Vfixupimmps => "vfixupimmps", Avx512, Sequential, [], [];
+    Vfixupimmsd => "vfixupimmsd", Avx512, Sequential, [], [];
+    Vfixupimmss => "vfixupimmss", Avx512, Sequential, [], [];
+    Vfixupnanpd => "vfixupnanpd", Avx512, Sequential, [], [];
+    Vfixupnanps => "vfixupnanps", Avx512, Sequential, [], [];
+    Vfmadd132pd => "vfmadd132pd", Avx, Sequential, [], [];
+    Vfmadd132ph => "vfmadd132ph", Avx512, Sequential, [], [];
+    Vfmadd132ps => "vfmadd132ps", Avx, Sequential, [], [];
+    Vfmadd132sd => "vfmadd132sd", Avx, Sequential, [], [];
+    Vfmadd132sh => "vfmadd132sh", Avx512, Sequential, [], [];
+    Vfmadd132ss => "vfmadd132ss", Avx, Sequential, [], [];
+    Vfmadd213pd => "vfmadd213pd", Avx, Sequential, [], [];
+    Vfmadd213ph => "vfmadd213ph", Avx512, Sequential, [], [];
+    Vfmadd213ps => "vfmadd213ps", Avx, Sequential, [], [];
+    Vfmadd213sd => "vfmadd213sd", Avx, Sequential, [], [];
+    Vfmadd213sh => "vfmadd213sh", Avx512, Sequential, [], [];
+    Vfmadd213ss => "vfmadd213ss", Avx, Sequential, [], [];
+    Vfmadd231pd => "vfmadd231pd", Avx, Sequential, [], [];
+    Vfmadd231ph => "vfmadd231ph", Avx512, Sequential, [], [];
+    Vfmadd231ps => "vfmadd231ps", Avx, Sequential, [], [];
+    Vfmadd231sd => "vfmadd231sd", Avx, Sequential, [], [];
+    Vfmadd231sh => "vfmadd231sh", Avx512, Sequential, [], [];
+    Vfmadd231ss => "vfmadd231ss", Avx, Sequential, [], [];
+    Vfmadd233ps => "vfmadd233ps", Avx512, Sequential, [], [];
+    Vfmaddcph => "vfmaddcph", Avx512, Sequential, [], [];
+    Vfmaddcsh => "vfmaddcsh", Avx512, Sequential, [], [];
+    Vfmaddpd => "vfmaddpd", Avx, Sequential, [], [];
+    Vfmaddps => "vfmaddps", Avx, Sequential, [], [];
+    Vfmaddsd => "vfmaddsd", Avx, Sequential, [], [];
+    Vfmaddss => "vfmaddss", Avx, Sequential, [], [];
+    Vfmaddsub132pd => "vfmaddsub132pd", Avx, Sequential, [], [];
+    Vfmaddsub132ph => "vfmaddsub132ph", Avx512, Sequential, [], [];
+    Vfmaddsub132ps => "vfmaddsub132ps", Avx, Sequential, [], [];
+    Vfmaddsub213pd => "vfmaddsub213pd", Avx, Sequential, [], [];
+    Vfmaddsub213ph => "vfmaddsub213ph", Avx512, Sequential, [], [];
+    Vfmaddsub213ps => "vfmaddsub213ps", Avx, Sequential, [], [];
+    Vfmaddsub231pd => "vfmaddsub231pd", Avx, Sequential, [], [];
+    Vfmaddsub231ph => "vfmaddsub231ph", Avx512, Sequential, [], [];
+    Vfmaddsub231ps => "vfmaddsub231ps", Avx, Sequential, [], [];
+    Vfmaddsubpd => "vfmaddsubpd", Avx, Sequential, [], [];
+    Vfmaddsubps => "vfmaddsubps", Avx, Sequential, [], [];
+    Vfmsub132pd => "vfmsub132pd", Avx, Sequential, [], [];
+    Vfmsub132ph => "vfmsub132ph", Avx512, Sequential, [], [];
+    Vfmsub132ps => "vfmsub132ps", Avx, Sequential, [], [];
+    Vfmsub132sd => "vfmsub132sd", Avx, Sequential, [], [];
+    Vfmsub132sh => "vfmsub132sh", Avx512, Sequential, [], [];
+    Vfmsub132ss => "vfmsub132ss", Avx, Sequential, [], [];
+    Vfmsub213pd => "vfmsub213pd", Avx, Sequential, [], [];
+    Vfmsub213ph => "vfmsub213ph", Avx512, Sequential, [], [];
+    Vfmsub213ps => "vfmsub213ps", Avx, Sequential, [], [];
+    Vfmsub213sd => "vfmsub213sd", Avx, Sequential, [], [];
+    Vfmsub213sh => "vfmsub213sh", Avx512, Sequential, [], [];
+    Vfmsub213ss => "vfmsub213ss", Avx, Sequential, [], [];
+    Vfmsub231pd => "vfmsub231pd", Avx, Sequential, [], [];
+    Vfmsub231ph => "vfmsub231ph", Avx512, Sequential, [], [];
+    Vfmsub231ps => "vfmsub231ps", Avx, Sequential, [], [];
+    Vfmsub231sd => "vfmsub231sd", Avx, Sequential, [], [];
+    Vfmsub231sh => "vfmsub231sh", Avx512, Sequential, [], [];
+    Vfmsub231ss => "vfmsub231ss", Avx, Sequential, [], [];
+    Vfmsubadd132pd => "vfmsubadd132pd", Avx, Sequential, [], [];
+    Vfmsubadd132ph => "vfmsubadd132ph", Avx512, Sequential, [], [];
+    Vfmsubadd132ps => "vfmsubadd132ps", Avx, Sequential, [], [];
+    Vfmsubadd213pd => "vfmsubadd213pd", Avx, Sequential, [], [];
+    Vfmsubadd213ph => "vfmsubadd213ph", Avx512, Sequential, [], [];
+    Vfmsubadd213ps => "vfmsubadd213ps", Avx, Sequential, [], [];
+    Vfmsubadd231pd => "vfmsubadd231pd", Avx, Sequential, [], [];
+    Vfmsubadd231ph => "vfmsubadd231ph", Avx512, Sequential, [], [];
+    Vfmsubadd231ps => "vfmsubadd231ps", Avx, Sequential, [], [];
+    Vfmsubaddpd => "vfmsubaddpd", Avx, Sequential, [], [];
+    Vfmsubaddps => "vfmsubaddps", Avx, Sequential, [], [];
+    Vfmsubpd => "vfmsubpd", Avx, Sequential, [], [];
+    Vfmsubps => "vfmsubps", Avx, Sequential, [], [];
+    Vfmsubsd => "vfmsubsd", Avx, Sequential, [], [];
+    Vfmsubss => "vfmsubss", Avx, Sequential, [], [];
+    Vfmulcph => "vfmulcph", Avx512, Sequential, [], [];
+    Vfmulcsh => "vfmulcsh", Avx512, Sequential, [], [];
+    Vfnmadd132pd => "vfnmadd132pd", Avx, Sequential, [], [];
+    Vfnmadd132ph => "vfnmadd132ph", Avx512, Sequential, [], [];
+    Vfnmadd132ps => "vfnmadd132ps", Avx, Sequential, [], [];
+    Vfnmadd132sd => "vfnmadd132sd", Avx, Sequential, [], [];
+    Vfnmadd132sh => "vfnmadd132sh", Avx512, Sequential, [], [];
+    Vfnmadd132ss => "vfnmadd132ss", Avx, Sequential, [], [];
+    Vfnmadd213pd => "vfnmadd213pd", Avx, Sequential, [], [];
+    Vfnmadd213ph => "vfnmadd213ph", Avx512, Sequential, [], [];
+    Vfnmadd213ps => "vfnmadd213ps", Avx, Sequential, [], [];
+    Vfnmadd213sd => "vfnmadd213sd", Avx, Sequential, [], [];
+    Vfnmadd213sh => "vfnmadd213sh", Avx512, Sequential, [], [];
+    Vfnmadd213ss => "vfnmadd213ss", Avx, Sequential, [], [];
+    Vfnmadd231pd => "vfnmadd231pd", Avx, Sequential, [], [];
+    Vfnmadd231ph => "vfnmadd231ph", Avx512, Sequential, [], [];
+    Vfnmadd231ps => "vfnmadd231ps", Avx, Sequential, [], [];
+    Vfnmadd231sd => "vfnmadd231sd", Avx, Sequential, [], [];
+    Vfnmadd231sh => "vfnmadd231sh", Avx512, Sequential, [], [];
+    Vfnmadd231ss => "vfnmadd231ss", Avx, Sequential, [], [];
+    Vfnmaddpd => "vfnmaddpd", Avx, Sequential, [], [];
+    Vfnmaddps => "vfnmaddps", Avx, Sequential, [], [];
+    Vfnmaddsd => "vfnmaddsd", Avx, Sequential, [], [];
+    Vfnmaddss => "vfnmaddss", Avx, Sequential, [], [];
+    Vfnmsub132pd => "vfnmsub132pd", Avx, Sequential, [], [];
+    Vfnmsub132ph => "vfnmsub132ph", Avx512, Sequential, [], [];
+    Vfnmsub132ps => "vfnmsub132ps", Avx, Sequential, [], [];
+    Vfnmsub132sd => "vfnmsub132sd", Avx, Sequential, [], [];
+    Vfnmsub132sh => "vfnmsub132sh", Avx512, Sequential, [], [];
+    Vfnmsub132ss => "vfnmsub132ss", Avx, Sequential, [], [];
+    Vfnmsub213pd => "vfnmsub213pd", Avx, Sequential, [], [];
+    Vfnmsub213ph => "vfnmsub213ph", Avx512, Sequential, [], [];
+    Vfnmsub213ps => "vfnmsub213ps", Avx, Sequential, [], [];
+    Vfnmsub213sd => "vfnmsub213sd", Avx, Sequential, [], [];
+    Vfnmsub213sh => "vfnmsub213sh", Avx512, Sequential, [], [];
+    Vfnmsub213ss => "vfnmsub213ss", Avx, Sequential, [], [];
+    Vfnmsub231pd => "vfnmsub231pd", Avx, Sequential, [], [];
+    Vfnmsub231ph => "vfnmsub231ph", Avx512, Sequential, [], [];
+    Vfnmsub231ps => "vfnmsub231ps", Avx, Sequential, [], [];
+    Vfnmsub231sd => "vfnmsub231sd", Avx, Sequential, [], [];
+    Vfnmsub231sh => "vfnmsub231sh", Avx512, Sequential, [], [];
+    Vfnmsub231ss => "vfnmsub231ss", Avx, Sequential, [], [];
+    Vfnmsubpd => "vfnmsubpd", Avx, Sequential, [], [];
+    Vfnmsubps => "vfnmsubps", Avx, Sequential, [], [];
+    Vfnmsubsd => "vfnmsubsd", Avx, Sequential, [], [];
+    Vfnmsubss => "vfnmsubss", Avx, Sequential, [], [];
+    Vfpclasspd => "vfpclasspd", Avx512, Sequential, [], [];
+    Vfpclassph => "vfpclassph", Avx512, Sequential, [], [];
+    Vfpclassps => "vfpclassps", Avx512, Sequential, [], [];
+    Vfpclasssd => "vfpclasssd", Avx512, Sequential, [], [];
+    Vfpclasssh => "vfpclasssh", Avx512, Sequential, [], [];
+    Vfpclassss => "vfpclassss", Avx512, Sequential, [], [];
+    Vfrczpd => "vfrczpd", Avx, Sequential, [], [];
+    Vfrczps => "vfrczps", Avx, Sequential, [], [];
+    Vfrczsd => "vfrczsd", Avx, Sequential, [], [];
+    Vfrczss => "vfrczss", Avx, Sequential, [], [];
+    Vgatherdpd => "vgatherdpd", Avx, Sequential, [], [];
+    Vgatherdps => "vgatherdps", Avx, Sequential, [], [];
+    Vgatherpf0dpd => "vgatherpf0dpd", Avx512, Sequential, [], [];
+    Vgatherpf0dps => "vgatherpf0dps", Avx512, Sequential, [], [];
+    Vgatherpf0hintdpd => "vgatherpf0hintdpd", Avx512, Sequential, [], [];
+    Vgatherpf0hintdps => "vgatherpf0hintdps", Avx512, Sequential, [], [];
+    Vgatherpf0qpd => "vgatherpf0qpd", Avx512, Sequential, [], [];
+    Vgatherpf0qps => "vgatherpf0qps", Avx512, Sequential, [], [];
+    Vgatherpf1dpd => "vgatherpf1dpd", Avx512, Sequential, [], [];
+    Vgatherpf1dps => "vgatherpf1dps", Avx512, Sequential, [], [];
+    Vgatherpf1qpd => "vgatherpf1qpd", Avx512, Sequential, [], [];
+    Vgatherpf1qps => "vgatherpf1qps", Avx512, Sequential, [], [];
+    Vgatherqpd => "vgatherqpd", Avx, Sequential, [], [];
+    Vgatherqps => "vgatherqps", Avx, Sequential, [], [];
+    Vgetexppd => "vgetexppd", Avx512, Sequential, [], [];
+    Vgetexpph => "vgetexpph", Avx512, Sequential, [], [];
+    Vgetexpps => "vgetexpps", Avx512, Sequential, [], [];
+    Vgetexpsd => "vgetexpsd", Avx512, Sequential, [], [];
+    Vgetexpsh => "vgetexpsh", Avx512, Sequential, [], [];
+    Vgetexpss => "vgetexpss", Avx512, Sequential, [], [];
+    Vgetmantpd => "vgetmantpd", Avx512, Sequential, [], [];
+    Vgetmantph => "vgetmantph", Avx512, Sequential, [], [];
+    Vgetmantps => "vgetmantps", Avx512, Sequential, [], [];
+    Vgetmantsd => "vgetmantsd", Avx512, Sequential, [], [];
+    Vgetmantsh => "vgetmantsh", Avx512, Sequential, [], [];
+    Vgetmantss => "vgetmantss", Avx512, Sequential, [], [];
+    Vgf2p8affineinvqb => "vgf2p8affineinvqb", Avx, Sequential, [], [];
+    Vgf2p8affineqb => "vgf2p8affineqb", Avx, Sequential, [], [];
+    Vgf2p8mulb => "vgf2p8mulb", Avx, Sequential, [], [];
+    Vgmaxabsps => "vgmaxabsps", Avx512, Sequential, [], [];
+    Vgmaxpd => "vgmaxpd", Avx512, Sequential, [], [];
+    Vgmaxps => "vgmaxps", Avx512, Sequential, [], [];
+    Vgminpd => "vgminpd", Avx512, Sequential, [], [];
+    Vgminps => "vgminps", Avx512, Sequential, [], [];
+    Vhaddpd => "vhaddpd", Avx, Sequential, [], [];
+    Vhaddps => "vhaddps", Avx, Sequential, [], [];
+    Vhsubpd => "vhsubpd", Avx, Sequential, [], [];
+    Vhsubps => "vhsubps", Avx, Sequential, [], [];
+    Vinsertf128 => "vinsertf128", Avx, Sequential, [], [];
+    Vinsertf32x4 => "vinsertf32x4", Avx512, Sequential, [], [];
+    Vinsertf32x8 => "vinsertf32x8", Avx512, Sequential, [], [];
+    Vinsertf64x2 => "vinsertf64x2", Avx512, Sequential, [], [];
+    Vinsertf64x4 => "vinsertf64x4", Avx512, Sequential, [], [];
+    Vinserti128 => "vinserti128", Avx, Sequential, [], [];
+    Vinserti32x4 => "vinserti32x4", Avx512, Sequential, [], [];
+    Vinserti32x8 => "vinserti32x8", Avx512, Sequential, [], [];
+    Vinserti64x2 => "vinserti64x2", Avx512, Sequential, [], [];
+    Vinserti64x4 => "vinserti64x4", Avx512, Sequential, [], [];
+    Vinsertps => "vinsertps", Avx, Sequential, [], [];
+    Vlddqu => "vlddqu", Avx, Sequential, [], [];
+    Vldmxcsr => "vldmxcsr", Avx, Sequential, [], [];
+    Vloadunpackhd => "vloadunpackhd", Avx512, Sequential, [], [];
+    Vloadunpackhpd => "vloadunpackhpd", Avx512, Sequential, [], [];
+    Vloadunpackhps => "vloadunpackhps", Avx512, Sequential, [], [];
+    Vloadunpackhq => "vloadunpackhq", Avx512, Sequential, [], [];
+    Vloadunpackld => "vloadunpackld", Avx512, Sequential, [], [];
+    Vloadunpacklpd => "vloadunpacklpd", Avx512, Sequential, [], [];
+    Vloadunpacklps => "vloadunpacklps", Avx512, Sequential, [], [];
+    Vloadunpacklq => "vloadunpacklq", Avx512, Sequential, [], [];
+    Vlog2ps => "vlog2ps", Avx512, Sequential, [], [];
+    Vmaskmovdqu => "vmaskmovdqu", Avx, Sequential, [], [];
+    Vmaskmovpd => "vmaskmovpd", Avx, Sequential, [], [];
+    Vmaskmovps => "vmaskmovps", Avx, Sequential, [], [];
+    Vmaxpd => "vmaxpd", Avx, Sequential, [], [];
+    Vmaxph => "vmaxph", Avx512, Sequential, [], [];
+    Vmaxps => "vmaxps", Avx, Sequential, [], [];
+    Vmaxsd => "vmaxsd", Avx, Sequential, [], [];
+    Vmaxsh => "vmaxsh", Avx512, Sequential, [], [];
+    Vmaxss => "vmaxss", Avx, Sequential, [], [];
+    Vmcall => "vmcall", System, Interrupt, [], [OF SF ZF AF CF PF];
+    Vmclear => "vmclear", System, Sequential, [], [OF SF ZF AF CF PF];
+    Vmfunc => "vmfunc", System, Sequential, [], [OF SF ZF AF CF PF];
+    Vmgexit => "vmgexit", ControlFlow, Interrupt, [], [];
+    Vminpd => "vminpd", Avx, Sequential, [], [];
+    Vminph => "vminph", Avx512, Sequential, [], [];
+    Vminps => "vminps", Avx, Sequential, [], [];
+    Vminsd => "vminsd", Avx, Sequential, [], [];
+    Vminsh => "vminsh", Avx512, Sequential, [], [];
+    Vminss => "vminss", Avx, Sequential, [], [];
+    Vmlaunch => "vmlaunch", System, Interrupt, [], [OF SF ZF AF CF PF];
+    Vmload => "vmload", System, Sequential, [], [];
+    Vmmcall => "vmmcall", System, Interrupt, [], [];
+    Vmovapd => "vmovapd", Avx, Sequential, [], [];
+    Vmovaps => "vmovaps", Avx, Sequential, [], [];
+    Vmovd => "vmovd", Avx, Sequential, [], [];
+    Vmovddup => "vmovddup", Avx, Sequential, [], [];
+    Vmovdqa => "vmovdqa", Avx, Sequential, [], [];
+    Vmovdqa32 => "vmovdqa32", Avx512, Sequential, [], [];
+    Vmovdqa64 => "vmovdqa64", Avx512, Sequential, [], [];
+    Vmovdqu => "vmovdqu", Avx, Sequential, [], [];
+    Vmovdqu16 => "vmovdqu16", Avx512, Sequential, [], [];
+    Vmovdqu32 => "vmovdqu32", Avx512, Sequential, [], [];
+    Vmovdqu64 => "vmovdqu64", Avx512, Sequential, [], [];
+    Vmovdqu8 => "vmovdqu8", Avx512, Sequential, [], [];
+    Vmovhlps => "vmovhlps", Avx, Sequential, [], [];
+    Vmovhpd => "vmovhpd", Avx, Sequential, [], [];
+    Vmovhps => "vmovhps", Avx, Sequential, [], [];
+    Vmovlhps => "vmovlhps", Avx, Sequential, [], [];
+    Vmovlpd => "vmovlpd", Avx, Sequential, [], [];
+    Vmovlps => "vmovlps", Avx, Sequential, [], [];
+    Vmovmskpd => "vmovmskpd", Avx, Sequential, [], [];
+    Vmovmskps => "vmovmskps", Avx, Sequential, [], [];
+    Vmovnrapd => "vmovnrapd", Avx512, Sequential, [], [];
+    Vmovnraps => "vmovnraps", Avx512, Sequential, [], [];
+    Vmovnrngoapd => "vmovnrngoapd", Avx512, Sequential, [], [];
+    Vmovnrngoaps => "vmovnrngoaps", Avx512, Sequential, [], [];
+    Vmovntdq => "vmovntdq", Avx, Sequential, [], [];
+    Vmovntdqa => "vmovntdqa", Avx, Sequential, [], [];
+    Vmovntpd => "vmovntpd", Avx, Sequential, [], [];
+    Vmovntps => "vmovntps", Avx, Sequential, [], [];
+    Vmovq => "vmovq", Avx, Sequential, [], [];
+    Vmovsd => "vmovsd", Avx, Sequential, [], [];
+    Vmovsh => "vmovsh", Avx512, Sequential, [], [];
+    Vmovshdup => "vmovshdup", Avx, Sequential, [], [];
+    Vmovsldup => "vmovsldup", Avx, Sequential, [], [];
+    Vmovss => "vmovss", Avx, Sequential, [], [];
+    Vmovupd => "vmovupd", Avx, Sequential, [], [];
+    Vmovups => "vmovups", Avx, Sequential, [], [];
+    Vmovw => "vmovw", Avx512, Sequential, [], [];
+    Vmpsadbw => "vmpsadbw", Avx, Sequential, [], [];
+    Vmptrld => "vmptrld", System, Sequential, [], [OF SF ZF AF CF PF];
+    Vmptrst => "vmptrst", System, Sequential, [], [OF SF ZF AF CF PF];
+    Vmread => "vmread", System, Sequential, [], [OF SF ZF AF CF PF];
+    Vmresume => "vmresume", System, Interrupt, [], [OF SF ZF AF CF PF];
+    Vmrun => "vmrun", System, Interrupt, [OF SF ZF AF CF PF DF IF AC], [];
+    Vmsave => "vmsave", System, Sequential, [], [];
+    Vmulpd => "vmulpd", Avx, Sequential, [], [];
+    Vmulph => "vmulph", Avx512, Sequential, [], [];
+    Vmulps => "vmulps", Avx, Sequential, [], [];
+    Vmulsd => "vmulsd", Avx, Sequential, [], [];
+    Vmulsh => "vmulsh", Avx512, Sequential, [], [];
+    Vmulss => "vmulss", Avx, Sequential, [], [];
+    Vmwrite => "vmwrite", System, Sequential, [], [OF SF ZF AF CF PF];
+    Vmxoff => "vmxoff", System, Sequential, [], [OF SF ZF AF CF PF];
+    Vmxon => "vmxon", System, Sequential, [], [OF SF ZF AF CF PF];
+    Vorpd => "vorpd", Avx, Sequential, [], [];
+    Vorps => "vorps", Avx, Sequential, [], [];
+    Vp2intersectd => "vp2intersectd", Avx512, Sequential, [], [];
+    Vp2intersectq => "vp2intersectq", Avx512, Sequential, [], [];
+    Vp4dpwssd => "vp4dpwssd", Avx512, Sequential, [], [];
+    Vp4dpwssds => "vp4dpwssds", Avx512, Sequential, [], [];
+    Vpabsb => "vpabsb", Avx, Sequential, [], [];
+    Vpabsd => "vpabsd", Avx, Sequential, [], [];
+    Vpabsq => "vpabsq", Avx512, Sequential, [], [];
+    Vpabsw => "vpabsw", Avx, Sequential, [], [];
+    Vpackssdw => "vpackssdw", Avx, Sequential, [], [];
+    Vpacksswb => "vpacksswb", Avx, Sequential, [], [];
+    Vpackstorehd => "vpackstorehd", Avx512, Sequential, [], [];
+    Vpackstorehpd => "vpackstorehpd", Avx512, Sequential, [], [];
+    Vpackstorehps => "vpackstorehps", Avx512, Sequential, [], [];
+    Vpackstorehq => "vpackstorehq", Avx512, Sequential, [], [];
+    Vpackstoreld => "vpackstoreld", Avx512, Sequential, [], [];
+    Vpackstorelpd => "vpackstorelpd", Avx512, Sequential, [], [];
+    Vpackstorelps => "vpackstorelps", Avx512, Sequential, [], [];
+    Vpackstorelq => "vpackstorelq", Avx512, Sequential, [], [];
+    Vpackusdw => "vpackusdw", Avx, Sequential, [], [];
+    Vpackuswb => "vpackuswb", Avx, Sequential, [], [];
+    Vpadcd => "vpadcd", Avx512, Sequential, [], [];
+    Vpaddb => "vpaddb", Avx, Sequential, [], [];
+    Vpaddd => "vpaddd", Avx, Sequential, [], [];
+    Vpaddq => "vpaddq", Avx, Sequential, [], [];
+    Vpaddsb => "vpaddsb", Avx, Sequential, [], [];
+    Vpaddsetcd => "vpaddsetcd", Avx512, Sequential, [], [];
+    Vpaddsetsd => "vpaddsetsd", Avx512, Sequential, [], [];
+    Vpaddsw => "vpaddsw", Avx, Sequential, [], [];
+    Vpaddusb => "vpaddusb", Avx, Sequential, [], [];
+    Vpaddusw => "vpaddusw", Avx, Sequential, [], [];
+    Vpaddw => "vpaddw", Avx, Sequential, [], [];
+    Vpalignr => "vpalignr", Avx, Sequential, [], [];
+    Vpand => "vpand", Avx, Sequential, [], [];
+    Vpandd => "vpandd", Avx512, Sequential, [], [];
+    Vpandn => "vpandn", Avx, Sequential, [], [];
+    Vpandnd => "vpandnd", Avx512, Sequential, [], [];
+    Vpandnq => "vpandnq", Avx512, Sequential, [], [];
+    Vpandq => "vpandq", Avx512, Sequential, [], [];
+    Vpavgb => "vpavgb", Avx, Sequential, [], [];
+    Vpavgw => "vpavgw", Avx, Sequential, [], [];
+    Vpblendd => "vpblendd", Avx, Sequential, [], [];
+    Vpblendmb => "vpblendmb", Avx512, Sequential, [], [];
+    Vpblendmd => "vpblendmd", Avx512, Sequential, [], [];
+    Vpblendmq => "vpblendmq", Avx512, Sequential, [], [];
+    Vpblendmw => "vpblendmw", Avx512, Sequential, [], [];
+    Vpblendvb => "vpblendvb", Avx, Sequential, [], [];
+    Vpblendw => "vpblendw", Avx, Sequential, [], [];
+    Vpbroadcastb => "vpbroadcastb", Avx, Sequential, [], [];
+    Vpbroadcastd => "vpbroadcastd", Avx, Sequential, [], [];
+    Vpbroadcastmb2q => "vpbroadcastmb2q", Avx512, Sequential, [], [];
+    Vpbroadcastmw2d => "vpbroadcastmw2d", Avx512, Sequential, [], [];
+    Vpbroadcastq => "vpbroadcastq", Avx, Sequential, [], [];
+    Vpbroadcastw => "vpbroadcastw", Avx, Sequential, [], [];
+    Vpclmulqdq => "vpclmulqdq", Avx, Sequential, [], [];
+    Vpcmov => "vpcmov", Avx, Sequential, [], [];
+    Vpcmpb => "vpcmpb", Avx512, Sequential, [], [];
+    Vpcmpd => "vpcmpd", Avx512, Sequential, [], [];
+    Vpcmpeqb => "vpcmpeqb", Avx, Sequential, [], [];
+    Vpcmpeqd => "vpcmpeqd", Avx, Sequential, [], [];
+    Vpcmpeqq => "vpcmpeqq", Avx, Sequential, [], [];
+    Vpcmpeqw => "vpcmpeqw", Avx, Sequential, [], [];
+    Vpcmpestri => "vpcmpestri", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Vpcmpestri64 => "vpcmpestri64", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Vpcmpestrm => "vpcmpestrm", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Vpcmpestrm64 => "vpcmpestrm64", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Vpcmpgtb => "vpcmpgtb", Avx, Sequential, [], [];
+    Vpcmpgtd => "vpcmpgtd", Avx, Sequential, [], [];
+    Vpcmpgtq => "vpcmpgtq", Avx, Sequential, [], [];
+    Vpcmpgtw => "vpcmpgtw", Avx, Sequential, [], [];
+    Vpcmpistri => "vpcmpistri", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Vpcmpistrm => "vpcmpistrm", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Vpcmpltd => "vpcmpltd", Avx512, Sequential, [], [];
+    Vpcmpq => "vpcmpq", Avx512, Sequential, [], [];
+    Vpcmpub => "vpcmpub", Avx512, Sequential, [], [];
+    Vpcmpud => "vpcmpud", Avx512, Sequential, [], [];
+    Vpcmpuq => "vpcmpuq", Avx512, Sequential, [], [];
+    Vpcmpuw => "vpcmpuw", Avx512, Sequential, [], [];
+    Vpcmpw => "vpcmpw", Avx512, Sequential, [], [];
+    Vpcomb => "vpcomb", Avx, Sequential, [], [];
+    Vpcomd => "vpcomd", Avx, Sequential, [], [];
+    Vpcompressb => "vpcompressb", Avx512, Sequential, [], [];
+    Vpcompressd => "vpcompressd", Avx512, Sequential, [], [];
+    Vpcompressq => "vpcompressq", Avx512, Sequential, [], [];
+    Vpcompressw => "vpcompressw", Avx512, Sequential, [], [];
+    Vpcomq => "vpcomq", Avx, Sequential, [], [];
+    Vpcomub => "vpcomub", Avx, Sequential, [], [];
+    Vpcomud => "vpcomud", Avx, Sequential, [], [];
+    Vpcomuq => "vpcomuq", Avx, Sequential, [], [];
+    Vpcomuw => "vpcomuw", Avx, Sequential, [], [];
+    Vpcomw => "vpcomw", Avx, Sequential, [], [];
+    Vpconflictd => "vpconflictd", Avx512, Sequential, [], [];
+    Vpconflictq => "vpconflictq", Avx512, Sequential, [], [];
+    Vpdpbssd => "vpdpbssd", Avx, Sequential, [], [];
+    Vpdpbssds => "vpdpbssds", Avx, Sequential, [], [];
+    Vpdpbsud => "vpdpbsud", Avx, Sequential, [], [];
+    Vpdpbsuds => "vpdpbsuds", Avx, Sequential, [], [];
+    Vpdpbusd => "vpdpbusd", Avx, Sequential, [], [];
+    Vpdpbusds => "vpdpbusds", Avx, Sequential, [], [];
+    Vpdpbuud => "vpdpbuud", Avx, Sequential, [], [];
+    Vpdpbuuds => "vpdpbuuds", Avx, Sequential, [], [];
+    Vpdpwssd => "vpdpwssd", Avx, Sequential, [], [];
+    Vpdpwssds => "vpdpwssds", Avx, Sequential, [], [];
+    Vpdpwsud => "vpdpwsud", Avx, Sequential, [], [];
+    Vpdpwsuds => "vpdpwsuds", Avx, Sequential, [], [];
+    Vpdpwusd => "vpdpwusd", Avx, Sequential, [], [];
+    Vpdpwusds => "vpdpwusds", Avx, Sequential, [], [];
+    Vpdpwuud => "vpdpwuud", Avx, Sequential, [], [];
+    Vpdpwuuds => "vpdpwuuds", Avx, Sequential, [], [];
+    Vperm2f128 => "vperm2f128", Avx, Sequential, [], [];
+    Vperm2i128 => "vperm2i128", Avx, Sequential, [], [];
+    Vpermb => "vpermb", Avx512, Sequential, [], [];
+    Vpermd => "vpermd", Avx, Sequential, [], [];
+    Vpermf32x4 => "vpermf32x4", Avx512, Sequential, [], [];
+    Vpermi2b => "vpermi2b", Avx512, Sequential, [], [];
+    Vpermi2d => "vpermi2d", Avx512, Sequential, [], [];
+    Vpermi2pd => "vpermi2pd", Avx512, Sequential, [], [];
+    Vpermi2ps => "vpermi2ps", Avx512, Sequential, [], [];
+    Vpermi2q => "vpermi2q", Avx512, Sequential, [], [];
+    Vpermi2w => "vpermi2w", Avx512, Sequential, [], [];
+    Vpermil2pd => "vpermil2pd", Avx, Sequential, [], [];
+    Vpermil2ps => "vpermil2ps", Avx, Sequential, [], [];
+    Vpermilpd => "vpermilpd", Avx, Sequential, [], [];
+    Vpermilps => "vpermilps", Avx, Sequential, [], [];
+    Vpermpd => "vpermpd", Avx, Sequential, [], [];
+    Vpermps => "vpermps", Avx, Sequential, [], [];
+    Vpermq => "vpermq", Avx, Sequential, [], [];
+    Vpermt2b => "vpermt2b", Avx512, Sequential, [], [];
+    Vpermt2d => "vpermt2d", Avx512, Sequential, [], [];
+    Vpermt2pd => "vpermt2pd", Avx512, Sequential, [], [];
+    Vpermt2ps => "vpermt2ps", Avx512, Sequential, [], [];
+    Vpermt2q => "vpermt2q", Avx512, Sequential, [], [];
+    Vpermt2w => "vpermt2w", Avx512, Sequential, [], [];
+    Vpermw => "vpermw", Avx512, Sequential, [], [];
+    Vpexpandb => "vpexpandb", Avx512, Sequential, [], [];
+    Vpexpandd => "vpexpandd", Avx512, Sequential, [], [];
+    Vpexpandq => "vpexpandq", Avx512, Sequential, [], [];
+    Vpexpandw => "vpexpandw", Avx512, Sequential, [], [];
+    Vpextrb => "vpextrb", Avx, Sequential, [], [];
+    Vpextrd => "vpextrd", Avx, Sequential, [], [];
+    Vpextrq => "vpextrq", Avx, Sequential, [], [];
+    Vpextrw => "vpextrw", Avx, Sequential, [], [];
+    Vpgatherdd => "vpgatherdd", Avx, Sequential, [], [];
+    Vpgatherdq => "vpgatherdq", Avx, Sequential, [], [];
+    Vpgatherqd => "vpgatherqd", Avx, Sequential, [], [];
+    Vpgatherqq => "vpgatherqq", Avx, Sequential, [], [];
+    Vphaddbd => "vphaddbd", Avx, Sequential, [], [];
+    Vphaddbq => "vphaddbq", Avx, Sequential, [], [];
+    Vphaddbw => "vphaddbw", Avx, Sequential, [], [];
+    Vphaddd => "vphaddd", Avx, Sequential, [], [];
+    Vphadddq => "vphadddq", Avx, Sequential, [], [];
+    Vphaddsw => "vphaddsw", Avx, Sequential, [], [];
+    Vphaddubd => "vphaddubd", Avx, Sequential, [], [];
+    Vphaddubq => "vphaddubq", Avx, Sequential, [], [];
+    Vphaddubw => "vphaddubw", Avx, Sequential, [], [];
+    Vphaddudq => "vphaddudq", Avx, Sequential, [], [];
+    Vphadduwd => "vphadduwd", Avx, Sequential, [], [];
+    Vphadduwq => "vphadduwq", Avx, Sequential, [], [];
+    Vphaddw => "vphaddw", Avx, Sequential, [], [];
+    Vphaddwd => "vphaddwd", Avx, Sequential, [], [];
+    Vphaddwq => "vphaddwq", Avx, Sequential, [], [];
+    Vphminposuw => "vphminposuw", Avx, Sequential, [], [];
+    Vphsubbw => "vphsubbw", Avx, Sequential, [], [];
+    Vphsubd => "vphsubd", Avx, Sequential, [], [];
+    Vphsubdq => "vphsubdq", Avx, Sequential, [], [];
+    Vphsubsw => "vphsubsw", Avx, Sequential, [], [];
+    Vphsubw => "vphsubw", Avx, Sequential, [], [];
+    Vphsubwd => "vphsubwd", Avx, Sequential, [], [];
+    Vpinsrb => "vpinsrb", Avx, Sequential, [], [];
+    Vpinsrd => "vpinsrd", Avx, Sequential, [], [];
+    Vpinsrq => "vpinsrq", Avx, Sequential, [], [];
+    Vpinsrw => "vpinsrw", Avx, Sequential, [], [];
+    Vplzcntd => "vplzcntd", Avx512, Sequential, [], [];
+    Vplzcntq => "vplzcntq", Avx512, Sequential, [], [];
+    Vpmacsdd => "vpmacsdd", Avx, Sequential, [], [];
+    Vpmacsdqh => "vpmacsdqh", Avx, Sequential, [], [];
+    Vpmacsdql => "vpmacsdql", Avx, Sequential, [], [];
+    Vpmacssdd => "vpmacssdd", Avx, Sequential, [], [];
+    Vpmacssdqh => "vpmacssdqh", Avx, Sequential, [], [];
+    Vpmacssdql => "vpmacssdql", Avx, Sequential, [], [];
+    Vpmacsswd => "vpmacsswd", Avx, Sequential, [], [];
+    Vpmacssww => "vpmacssww", Avx, Sequential, [], [];
+    Vpmacswd => "vpmacswd", Avx, Sequential, [], [];
+    Vpmacsww => "vpmacsww", Avx, Sequential, [], [];
+    Vpmadcsswd => "vpmadcsswd", Avx, Sequential, [], [];
+    Vpmadcswd => "vpmadcswd", Avx, Sequential, [], [];
+    Vpmadd231d => "vpmadd231d", Avx512, Sequential, [], [];
+    Vpmadd233d => "vpmadd233d", Avx512, Sequential, [], [];
+    Vpmadd52huq => "vpmadd52huq", Avx, Sequential, [], [];
+    Vpmadd52luq => "vpmadd52luq", Avx, Sequential, [], [];
+    Vpmaddubsw => "vpmaddubsw", Avx, Sequential, [], [];
+    Vpmaddwd => "vpmaddwd", Avx, Sequential, [], [];
+    Vpmaskmovd => "vpmaskmovd", Avx, Sequential, [], [];
+    Vpmaskmovq => "vpmaskmovq", Avx, Sequential, [], [];
+    Vpmaxsb => "vpmaxsb", Avx, Sequential, [], [];
+    Vpmaxsd => "vpmaxsd", Avx, Sequential, [], [];
+    Vpmaxsq => "vpmaxsq", Avx512, Sequential, [], [];
+    Vpmaxsw => "vpmaxsw", Avx, Sequential, [], [];
+    Vpmaxub => "vpmaxub", Avx, Sequential, [], [];
+    Vpmaxud => "vpmaxud", Avx, Sequential, [], [];
+    Vpmaxuq => "vpmaxuq", Avx512, Sequential, [], [];
+    Vpmaxuw => "vpmaxuw", Avx, Sequential, [], [];
+    Vpminsb => "vpminsb", Avx, Sequential, [], [];
+    Vpminsd => "vpminsd", Avx, Sequential, [], [];
+    Vpminsq => "vpminsq", Avx512, Sequential, [], [];
+    Vpminsw => "vpminsw", Avx, Sequential, [], [];
+    Vpminub => "vpminub", Avx, Sequential, [], [];
+    Vpminud => "vpminud", Avx, Sequential, [], [];
+    Vpminuq => "vpminuq", Avx512, Sequential, [], [];
+    Vpminuw => "vpminuw", Avx, Sequential, [], [];
+    Vpmovb2m => "vpmovb2m", Avx512, Sequential, [], [];
+    Vpmovd2m => "vpmovd2m", Avx512, Sequential, [], [];
+    Vpmovdb => "vpmovdb", Avx512, Sequential, [], [];
+    Vpmovdw => "vpmovdw", Avx512, Sequential, [], [];
+    Vpmovm2b => "vpmovm2b", Avx512, Sequential, [], [];
+    Vpmovm2d => "vpmovm2d", Avx512, Sequential, [], [];
+    Vpmovm2q => "vpmovm2q", Avx512, Sequential, [], [];
+    Vpmovm2w => "vpmovm2w", Avx512, Sequential, [], [];
+    Vpmovmskb => "vpmovmskb", Avx, Sequential, [], [];
+    Vpmovq2m => "vpmovq2m", Avx512, Sequential, [], [];
+    Vpmovqb => "vpmovqb", Avx512, Sequential, [], [];
+    Vpmovqd => "vpmovqd", Avx512, Sequential, [], [];
+    Vpmovqw => "vpmovqw", Avx512, Sequential, [], [];
+    Vpmovsdb => "vpmovsdb", Avx512, Sequential, [], [];
+    Vpmovsdw => "vpmovsdw", Avx512, Sequential, [], [];
+    Vpmovsqb => "vpmovsqb", Avx512, Sequential, [], [];
+    Vpmovsqd => "vpmovsqd", Avx512, Sequential, [], [];
+    Vpmovsqw => "vpmovsqw", Avx512, Sequential, [], [];
+    Vpmovswb => "vpmovswb", Avx512, Sequential, [], [];
+    Vpmovsxbd => "vpmovsxbd", Avx, Sequential, [], [];
+    Vpmovsxbq => "vpmovsxbq", Avx, Sequential, [], [];
+    Vpmovsxbw => "vpmovsxbw", Avx, Sequential, [], [];
+    Vpmovsxdq => "vpmovsxdq", Avx, Sequential, [], [];
+    Vpmovsxwd => "vpmovsxwd", Avx, Sequential, [], [];
+    Vpmovsxwq => "vpmovsxwq", Avx, Sequential, [], [];
+    Vpmovusdb => "vpmovusdb", Avx512, Sequential, [], [];
+    Vpmovusdw => "vpmovusdw", Avx512, Sequential, [], [];
+    Vpmovusqb => "vpmovusqb", Avx512, Sequential, [], [];
+    Vpmovusqd => "vpmovusqd", Avx512, Sequential, [], [];
+    Vpmovusqw => "vpmovusqw", Avx512, Sequential, [], [];
+    Vpmovuswb => "vpmovuswb", Avx512, Sequential, [], [];
+    Vpmovw2m => "vpmovw2m", Avx512, Sequential, [], [];
+    Vpmovwb => "vpmovwb", Avx512, Sequential, [], [];
+    Vpmovzxbd => "vpmovzxbd", Avx, Sequential, [], [];
+    Vpmovzxbq => "vpmovzxbq", Avx, Sequential, [], [];
+    Vpmovzxbw => "vpmovzxbw", Avx, Sequential, [], [];
+    Vpmovzxdq => "vpmovzxdq", Avx, Sequential, [], [];
+    Vpmovzxwd => "vpmovzxwd", Avx, Sequential, [], [];
+    Vpmovzxwq => "vpmovzxwq", Avx, Sequential, [], [];
+    Vpmuldq => "vpmuldq", Avx, Sequential, [], [];
+    Vpmulhd => "vpmulhd", Avx512, Sequential, [], [];
+    Vpmulhrsw => "vpmulhrsw", Avx, Sequential, [], [];
+    Vpmulhud => "vpmulhud", Avx512, Sequential, [], [];
+    Vpmulhuw => "vpmulhuw", Avx, Sequential, [], [];
+    Vpmulhw => "vpmulhw", Avx, Sequential, [], [];
+    Vpmulld => "vpmulld", Avx, Sequential, [], [];
+    Vpmullq => "vpmullq", Avx512, Sequential, [], [];
+    Vpmullw => "vpmullw", Avx, Sequential, [], [];
+    Vpmultishiftqb => "vpmultishiftqb", Avx512, Sequential, [], [];
+    Vpmuludq => "vpmuludq", Avx, Sequential, [], [];
+    Vpopcntb => "vpopcntb", Avx512, Sequential, [], [];
+    Vpopcntd => "vpopcntd", Avx512, Sequential, [], [];
+    Vpopcntq => "vpopcntq", Avx512, Sequential, [], [];
+    Vpopcntw => "vpopcntw", Avx512, Sequential, [], [];
+    Vpor => "vpor", Avx, Sequential, [], [];
+    Vpord => "vpord", Avx512, Sequential, [], [];
+    Vporq => "vporq", Avx512, Sequential, [], [];
+    Vpperm => "vpperm", Avx, Sequential, [], [];
+    Vprefetch0 => "vprefetch0", Avx, Sequential, [], [];
+    Vprefetch1 => "vprefetch1", Avx, Sequential, [], [];
+    Vprefetch2 => "vprefetch2", Avx, Sequential, [], [];
+    Vprefetche0 => "vprefetche0", Avx, Sequential, [], [];
+    Vprefetche1 => "vprefetche1", Avx, Sequential, [], [];
+    Vprefetche2 => "vprefetche2", Avx, Sequential, [], [];
+    Vprefetchenta => "vprefetchenta", Avx, Sequential, [], [];
+    Vprefetchnta => "vprefetchnta", Avx, Sequential, [], [];
+    Vprold => "vprold", Avx512, Sequential, [], [];
+    Vprolq => "vprolq", Avx512, Sequential, [], [];
+    Vprolvd => "vprolvd", Avx512, Sequential, [], [];
+    Vprolvq => "vprolvq", Avx512, Sequential, [], [];
+    Vprord => "vprord", Avx512, Sequential, [], [];
+    Vprorq => "vprorq", Avx512, Sequential, [], [];
+    Vprorvd => "vprorvd", Avx512, Sequential, [], [];
+    Vprorvq => "vprorvq", Avx512, Sequential, [], [];
+    Vprotb => "vprotb", Avx, Sequential, [], [];
+    Vprotd => "vprotd", Avx, Sequential, [], [];
+    Vprotq => "vprotq", Avx, Sequential, [], [];
+    Vprotw => "vprotw", Avx, Sequential, [], [];
+    Vpsadbw => "vpsadbw", Avx, Sequential, [], [];
+    Vpsbbd => "vpsbbd", Avx512, Sequential, [], [];
+    Vpsbbrd => "vpsbbrd", Avx512, Sequential, [], [];
+    Vpscatterdd => "vpscatterdd", Avx512, Sequential, [], [];
+    Vpscatterdq => "vpscatterdq", Avx512, Sequential, [], [];
+    Vpscatterqd => "vpscatterqd", Avx512, Sequential, [], [];
+    Vpscatterqq => "vpscatterqq", Avx512, Sequential, [], [];
+    Vpshab => "vpshab", Avx, Sequential, [], [];
+    Vpshad => "vpshad", Avx, Sequential, [], [];
+    Vpshaq => "vpshaq", Avx, Sequential, [], [];
+    Vpshaw => "vpshaw", Avx, Sequential, [], [];
+    Vpshlb => "vpshlb", Avx, Sequential, [], [];
+    Vpshld => "vpshld", Avx, Sequential, [], [];
+    Vpshldd => "vpshldd", Avx512, Sequential, [], [];
+    Vpshldq => "vpshldq", Avx512, Sequential, [], [];
+    Vpshldvd => "vpshldvd", Avx512, Sequential, [], [];
+    Vpshldvq => "vpshldvq", Avx512, Sequential, [], [];
+    Vpshldvw => "vpshldvw", Avx512, Sequential, [], [];
+    Vpshldw => "vpshldw", Avx512, Sequential, [], [];
+    Vpshlq => "vpshlq", Avx, Sequential, [], [];
+    Vpshlw => "vpshlw", Avx, Sequential, [], [];
+    Vpshrdd => "vpshrdd", Avx512, Sequential, [], [];
+    Vpshrdq => "vpshrdq", Avx512, Sequential, [], [];
+    Vpshrdvd => "vpshrdvd", Avx512, Sequential, [], [];
+    Vpshrdvq => "vpshrdvq", Avx512, Sequential, [], [];
+    Vpshrdvw => "vpshrdvw", Avx512, Sequential, [], [];
+    Vpshrdw => "vpshrdw", Avx512, Sequential, [], [];
+    Vpshufb => "vpshufb", Avx, Sequential, [], [];
+    Vpshufbitqmb => "vpshufbitqmb", Avx512, Sequential, [], [];
+    Vpshufd => "vpshufd", Avx, Sequential, [], [];
+    Vpshufhw => "vpshufhw", Avx, Sequential, [], [];
+    Vpshuflw => "vpshuflw", Avx, Sequential, [], [];
+    Vpsignb => "vpsignb", Avx, Sequential, [], [];
+    Vpsignd => "vpsignd", Avx, Sequential, [], [];
+    Vpsignw => "vpsignw", Avx, Sequential, [], [];
+    Vpslld => "vpslld", Avx, Sequential, [], [];
+    Vpslldq => "vpslldq", Avx, Sequential, [], [];
+    Vpsllq => "vpsllq", Avx, Sequential, [], [];
+    Vpsllvd => "vpsllvd", Avx, Sequential, [], [];
+    Vpsllvq => "vpsllvq", Avx, Sequential, [], [];
+    Vpsllvw => "vpsllvw", Avx512, Sequential, [], [];
+    Vpsllw => "vpsllw", Avx, Sequential, [], [];
+    Vpsrad => "vpsrad", Avx, Sequential, [], [];
+    Vpsraq => "vpsraq", Avx512, Sequential, [], [];
+    Vpsravd => "vpsravd", Avx, Sequential, [], [];
+    Vpsravq => "vpsravq", Avx512, Sequential, [], [];
+    Vpsravw => "vpsravw", Avx512, Sequential, [], [];
+    Vpsraw => "vpsraw", Avx, Sequential, [], [];
+    Vpsrld => "vpsrld", Avx, Sequential, [], [];
+    Vpsrldq => "vpsrldq", Avx, Sequential, [], [];
+    Vpsrlq => "vpsrlq", Avx, Sequential, [], [];
+    Vpsrlvd => "vpsrlvd", Avx, Sequential, [], [];
+    Vpsrlvq => "vpsrlvq", Avx, Sequential, [], [];
+    Vpsrlvw => "vpsrlvw", Avx512, Sequential, [], [];
+    Vpsrlw => "vpsrlw", Avx, Sequential, [], [];
+    Vpsubb => "vpsubb", Avx, Sequential, [], [];
+    Vpsubd => "vpsubd", Avx, Sequential, [], [];
+    Vpsubq => "vpsubq", Avx, Sequential, [], [];
+    Vpsubrd => "vpsubrd", Avx512, Sequential, [], [];
+    Vpsubrsetbd => "vpsubrsetbd", Avx512, Sequential, [], [];
+    Vpsubsb => "vpsubsb", Avx, Sequential, [], [];
+    Vpsubsetbd => "vpsubsetbd", Avx512, Sequential, [], [];
+    Vpsubsw => "vpsubsw", Avx, Sequential, [], [];
+    Vpsubusb => "vpsubusb", Avx, Sequential, [], [];
+    Vpsubusw => "vpsubusw", Avx, Sequential, [], [];
+    Vpsubw => "vpsubw", Avx, Sequential, [], [];
+    Vpternlogd => "vpternlogd", Avx512, Sequential, [], [];
+    Vpternlogq => "vpternlogq", Avx512, Sequential, [], [];
+    Vptest => "vptest", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Vptestmb => "vptestmb", Avx512, Sequential, [], [];
+    Vptestmd => "vptestmd", Avx512, Sequential, [], [];
+    Vptestmq => "vptestmq", Avx512, Sequential, [], [];
+    Vptestmw => "vptestmw", Avx512, Sequential, [], [];
+    Vptestnmb => "vptestnmb", Avx512, Sequential, [], [];
+    Vptestnmd => "vptestnmd", Avx512, Sequential, [], [];
+    Vptestnmq => "vptestnmq", Avx512, Sequential, [], [];
+    Vptestnmw => "vptestnmw", Avx512, Sequential, [], [];
+    Vpunpckhbw => "vpunpckhbw", Avx, Sequential, [], [];
+    Vpunpckhdq => "vpunpckhdq", Avx, Sequential, [], [];
+    Vpunpckhqdq => "vpunpckhqdq", Avx, Sequential, [], [];
+    Vpunpckhwd => "vpunpckhwd", Avx, Sequential, [], [];
+    Vpunpcklbw => "vpunpcklbw", Avx, Sequential, [], [];
+    Vpunpckldq => "vpunpckldq", Avx, Sequential, [], [];
+    Vpunpcklqdq => "vpunpcklqdq", Avx, Sequential, [], [];
+    Vpunpcklwd => "vpunpcklwd", Avx, Sequential, [], [];
+    Vpxor => "vpxor", Avx, Sequential, [], [];
+    Vpxord => "vpxord", Avx512, Sequential, [], [];
+    Vpxorq => "vpxorq", Avx512, Sequential, [], [];
+    Vrangepd => "vrangepd", Avx512, Sequential, [], [];
+    Vrangeps => "vrangeps", Avx512, Sequential, [], [];
+    Vrangesd => "vrangesd", Avx512, Sequential, [], [];
+    Vrangess => "vrangess", Avx512, Sequential, [], [];
+    Vrcp14pd => "vrcp14pd", Avx512, Sequential, [], [];
+    Vrcp14ps => "vrcp14ps", Avx512, Sequential, [], [];
+    Vrcp14sd => "vrcp14sd", Avx512, Sequential, [], [];
+    Vrcp14ss => "vrcp14ss", Avx512, Sequential, [], [];
+    Vrcp23ps => "vrcp23ps", Avx512, Sequential, [], [];
+    Vrcp28pd => "vrcp28pd", Avx512, Sequential, [], [];
+    Vrcp28ps => "vrcp28ps", Avx512, Sequential, [], [];
+    Vrcp28sd => "vrcp28sd", Avx512, Sequential, [], [];
+    Vrcp28ss => "vrcp28ss", Avx512, Sequential, [], [];
+    Vrcpph => "vrcpph", Avx512, Sequential, [], [];
+    Vrcpps => "vrcpps", Avx, Sequential, [], [];
+    Vrcpsh => "vrcpsh", Avx512, Sequential, [], [];
+    Vrcpss => "vrcpss", Avx, Sequential, [], [];
+    Vreducepd => "vreducepd", Avx512, Sequential, [], [];
+    Vreduceph => "vreduceph", Avx512, Sequential, [], [];
+    Vreduceps => "vreduceps", Avx512, Sequential, [], [];
+    Vreducesd => "vreducesd", Avx512, Sequential, [], [];
+    Vreducesh => "vreducesh", Avx512, Sequential, [], [];
+    Vreducess => "vreducess", Avx512, Sequential, [], [];
+    Vrndfxpntpd => "vrndfxpntpd", Avx512, Sequential, [], [];
+    Vrndfxpntps => "vrndfxpntps", Avx512, Sequential, [], [];
+    Vrndscalepd => "vrndscalepd", Avx512, Sequential, [], [];
+    Vrndscaleph => "vrndscaleph", Avx512, Sequential, [], [];
+    Vrndscaleps => "vrndscaleps", Avx512, Sequential, [], [];
+    Vrndscalesd => "vrndscalesd", Avx512, Sequential, [], [];
+    Vrndscalesh => "vrndscalesh", Avx512, Sequential, [], [];
+    Vrndscaless => "vrndscaless", Avx512, Sequential, [], [];
+    Vroundpd => "vroundpd", Avx, Sequential, [], [];
+    Vroundps => "vroundps", Avx, Sequential, [], [];
+    Vroundsd => "vroundsd", Avx, Sequential, [], [];
+    Vroundss => "vroundss", Avx, Sequential, [], [];
+    Vrsqrt14pd => "vrsqrt14pd", Avx512, Sequential, [], [];
+    Vrsqrt14ps => "vrsqrt14ps", Avx512, Sequential, [], [];
+    Vrsqrt14sd => "vrsqrt14sd", Avx512, Sequential, [], [];
+    Vrsqrt14ss => "vrsqrt14ss", Avx512, Sequential, [], [];
+    Vrsqrt23ps => "vrsqrt23ps", Avx512, Sequential, [], [];
+    Vrsqrt28pd => "vrsqrt28pd", Avx512, Sequential, [], [];
+    Vrsqrt28ps => "vrsqrt28ps", Avx512, Sequential, [], [];
+    Vrsqrt28sd => "vrsqrt28sd", Avx512, Sequential, [], [];
+    Vrsqrt28ss => "vrsqrt28ss", Avx512, Sequential, [], [];
+    Vrsqrtph => "vrsqrtph", Avx512, Sequential, [], [];
+    Vrsqrtps => "vrsqrtps", Avx, Sequential, [], [];
+    Vrsqrtsh => "vrsqrtsh", Avx512, Sequential, [], [];
+    Vrsqrtss => "vrsqrtss", Avx, Sequential, [], [];
+    Vscalefpd => "vscalefpd", Avx512, Sequential, [], [];
+    Vscalefph => "vscalefph", Avx512, Sequential, [], [];
+    Vscalefps => "vscalefps", Avx512, Sequential, [], [];
+    Vscalefsd => "vscalefsd", Avx512, Sequential, [], [];
+    Vscalefsh => "vscalefsh", Avx512, Sequential, [], [];
+    Vscalefss => "vscalefss", Avx512, Sequential, [], [];
+    Vscaleps => "vscaleps", Avx512, Sequential, [], [];
+    Vscatterdpd => "vscatterdpd", Avx512, Sequential, [], [];
+    Vscatterdps => "vscatterdps", Avx512, Sequential, [], [];
+    Vscatterpf0dpd => "vscatterpf0dpd", Avx512, Sequential, [], [];
+    Vscatterpf0dps => "vscatterpf0dps", Avx512, Sequential, [], [];
+    Vscatterpf0hintdpd => "vscatterpf0hintdpd", Avx512, Sequential, [], [];
+    Vscatterpf0hintdps => "vscatterpf0hintdps", Avx512, Sequential, [], [];
+    Vscatterpf0qpd => "vscatterpf0qpd", Avx512, Sequential, [], [];
+    Vscatterpf0qps => "vscatterpf0qps", Avx512, Sequential, [], [];
+    Vscatterpf1dpd => "vscatterpf1dpd", Avx512, Sequential, [], [];
+    Vscatterpf1dps => "vscatterpf1dps", Avx512, Sequential, [], [];
+    Vscatterpf1qpd => "vscatterpf1qpd", Avx512, Sequential, [], [];
+    Vscatterpf1qps => "vscatterpf1qps", Avx512, Sequential, [], [];
+    Vscatterqpd => "vscatterqpd", Avx512, Sequential, [], [];
+    Vscatterqps => "vscatterqps", Avx512, Sequential, [], [];
+    Vsha512msg1 => "vsha512msg1", Avx, Sequential, [], [];
+    Vsha512msg2 => "vsha512msg2", Avx, Sequential, [], [];
+    Vsha512rnds2 => "vsha512rnds2", Avx, Sequential, [], [];
+    Vshuff32x4 => "vshuff32x4", Avx512, Sequential, [], [];
+    Vshuff64x2 => "vshuff64x2", Avx512, Sequential, [], [];
+    Vshufi32x4 => "vshufi32x4", Avx512, Sequential, [], [];
+    Vshufi64x2 => "vshufi64x2", Avx512, Sequential, [], [];
+    Vshufpd => "vshufpd", Avx, Sequential, [], [];
+    Vshufps => "vshufps", Avx, Sequential, [], [];
+    Vsm3msg1 => "vsm3msg1", Avx, Sequential, [], [];
+    Vsm3msg2 => "vsm3msg2", Avx, Sequential, [], [];
+    Vsm3rnds2 => "vsm3rnds2", Avx, Sequential, [], [];
+    Vsm4key4 => "vsm4key4", Avx, Sequential, [], [];
+    Vsm4rnds4 => "vsm4rnds4", Avx, Sequential, [], [];
+    Vsqrtpd => "vsqrtpd", Avx, Sequential, [], [];
+    Vsqrtph => "vsqrtph", Avx512, Sequential, [], [];
+    Vsqrtps => "vsqrtps", Avx, Sequential, [], [];
+    Vsqrtsd => "vsqrtsd", Avx, Sequential, [], [];
+    Vsqrtsh => "vsqrtsh", Avx512, Sequential, [], [];
+    Vsqrtss => "vsqrtss", Avx, Sequential, [], [];
+    Vstmxcsr => "vstmxcsr", Avx, Sequential, [], [];
+    Vsubpd => "vsubpd", Avx, Sequential, [], [];
+    Vsubph => "vsubph", Avx512, Sequential, [], [];
+    Vsubps => "vsubps", Avx, Sequential, [], [];
+    Vsubrpd => "vsubrpd", Avx512, Sequential, [], [];
+    Vsubrps => "vsubrps", Avx512, Sequential, [], [];
+    Vsubsd => "vsubsd", Avx, Sequential, [], [];
+    Vsubsh => "vsubsh", Avx512, Sequential, [], [];
+    Vsubss => "vsubss", Avx, Sequential, [], [];
+    Vtestpd => "vtestpd", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Vtestps => "vtestps", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Vucomisd => "vucomisd", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Vucomish => "vucomish", Avx512, Sequential, [], [OF SF ZF AF CF PF];
+    Vucomiss => "vucomiss", Avx, Sequential, [], [OF SF ZF AF CF PF];
+    Vunpckhpd => "vunpckhpd", Avx, Sequential, [], [];
+    Vunpckhps => "vunpckhps", Avx, Sequential, [], [];
+    Vunpcklpd => "vunpcklpd", Avx, Sequential, [], [];
+    Vunpcklps => "vunpcklps", Avx, Sequential, [], [];
+    Vxorpd => "vxorpd", Avx, Sequential, [], [];
+    Vxorps => "vxorps", Avx, Sequential, [], [];
+    Vzeroall => "vzeroall", Avx, Sequential, [], [];
+    Vzeroupper => "vzeroupper", Avx, Sequential, [], [];
+    Wait => "wait", General, Sequential, [], [];
+    Wbinvd => "wbinvd", System, Sequential, [], [];
+    Wbnoinvd => "wbnoinvd", System, Sequential, [], [];
+    Wrfsbase => "wrfsbase", System, Sequential, [], [];
+    Wrgsbase => "wrgsbase", System, Sequential, [], [];
+    Wrmsr => "wrmsr", System, Sequential, [], [];
+    Wrmsrlist => "wrmsrlist", System, Sequential, [], [];
+    Wrmsrns => "wrmsrns", System, Sequential, [], [];
+    Wrpkru => "wrpkru", General, Sequential, [], [];
+    Wrshr => "wrshr", System, Sequential, [], [];
+    Wrssd => "wrssd", General, Sequential, [], [];
+    Wrssq => "wrssq", General, Sequential, [], [];
+    Wrudbg => "wrudbg", System, Sequential, [], [];
+    Wrussd => "wrussd", System, Sequential, [], [];
+    Wrussq => "wrussq", System, Sequential, [], [];
+    Xabort => "xabort", General, Sequential, [], [];
+    Xadd => "xadd", General, Sequential, [], [OF SF ZF AF CF PF];
+    Xbegin => "xbegin", ControlFlow, ConditionalJump, [], [];
+    Xbts => "xbts", General, Sequential, [], [];
+    Xchg => "xchg", General, Sequential, [], [];
+    Xcryptcbc => "xcryptcbc", General, Sequential, [], [];
+    Xcryptcfb => "xcryptcfb", General, Sequential, [], [];
+    Xcryptctr => "xcryptctr", General, Sequential, [], [];
+    Xcryptecb => "xcryptecb", General, Sequential, [], [];
+    Xcryptofb => "xcryptofb", General, Sequential, [], [];
+    Xend => "xend", General, Sequential, [], [];
+    Xgetbv => "xgetbv", System, Sequential, [], [];
+    Xlatb => "xlatb", General, Sequential, [], [];
+    Xor => "xor", General, Sequential, [], [OF SF ZF AF CF PF];
+    Xorpd => "xorpd", Sse, Sequential, [], [];
+    Xorps => "xorps", Sse, Sequential, [], [];
+    Xresldtrk => "xresldtrk", General, Sequential, [], [];
+    Xrstor => "xrstor", System, Sequential, [], [];
+    Xrstor64 => "xrstor64", System, Sequential, [], [];
+    Xrstors => "xrstors", System, Sequential, [], [];
+    Xrstors64 => "xrstors64", System, Sequential, [], [];
+    Xsave => "xsave", System, Sequential, [], [];
+    Xsave64 => "xsave64", System, Sequential, [], [];
+    Xsavec => "xsavec", System, Sequential, [], [];
+    Xsavec64 => "xsavec64", System, Sequential, [], [];
+    Xsaveopt => "xsaveopt", System, Sequential, [], [];
+    Xsaveopt64 => "xsaveopt64", System, Sequential, [], [];
+    Xsaves => "xsaves", System, Sequential, [], [];
+    Xsaves64 => "xsaves64", System, Sequential, [], [];
+    Xsetbv => "xsetbv", System, Sequential, [], [];
+    Xsha1 => "xsha1", General, Sequential, [], [];
+    Xsha256 => "xsha256", General, Sequential, [], [];
+    Xsha512 => "xsha512", General, Sequential, [], [];
+    Xsha512Alt [Xsha512_alt] => "xsha512_alt", General, Sequential, [], [];
+    Xstore => "xstore", General, Sequential, [], [];
+    XstoreAlt [Xstore_alt] => "xstore_alt", General, Sequential, [], [];
+    Xsusldtrk => "xsusldtrk", General, Sequential, [], [];
+    Xtest => "xtest", General, Sequential, [], [OF SF ZF AF CF PF];
+    ZeroBytes [Zero_bytes] => "zero_bytes", General, Sequential, [], [];
 }
@@ -1,19 +1,23 @@
+use crate::pe::address::Address;
+use crate::x86::memory::MemoryOperand;
+use crate::x86::register::X86Register;
 use std::fmt::{Display, Formatter};
 
 /// Represent an operand based on ISA x86
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum X86Operand {
     /// A x86 register
-    Register(String),
+    Register(X86Register),
     /// An immediate value
     Immediate(i128),
-    /// A memory offset defined with other params and a length
-    Memory {
-        /// the list of parameters used to identify memory (according to ISA)
-        params: Vec<X86Operand>,
-        /// the length of memory read or written
-        length: u64,
-    },
+    /// The displacement of a relative branch (jmp, jcc, call, loop, jrcxz): the target is the
+    /// end of the instruction plus the displacement
+    Relative(i64),
+    /// An immediate value that is an absolute address: a base relocation patches it when the
+    /// image is rebased
+    Pointer(Address),
+    /// A memory location, addressed through registers and a displacement
+    Memory(MemoryOperand),
 }
 
 impl Display for X86Operand {
@@ -22,11 +26,23 @@ impl Display for X86Operand {
             X86Operand::Register(reg) => {
                 write!(f, "{}", reg)
             }
+            X86Operand::Immediate(imm) if *imm < 0 => {
+                write!(f, "-0x{:x}", imm.unsigned_abs())
+            }
             X86Operand::Immediate(imm) => {
                 write!(f, "0x{:x}", imm)
             }
-            X86Operand::Memory { params, length } => {
-                write!(f, "[{:?}]_{}", params, length)
+            X86Operand::Relative(displacement) if *displacement < 0 => {
+                write!(f, "$-0x{:x}", displacement.unsigned_abs())
+            }
+            X86Operand::Relative(displacement) => {
+                write!(f, "$+0x{:x}", displacement)
+            }
+            X86Operand::Pointer(address) => {
+                write!(f, "0x{:x}", address)
+            }
+            X86Operand::Memory(memory) => {
+                write!(f, "{}", memory)
             }
         }
     }
@@ -0,0 +1,41 @@
+use std::fmt::{Display, Formatter};
+
+/// A legacy prefix that changes the semantics of an instruction. Prefixes that only change the
+/// size of operands or addresses, or the segment of a memory operand, are already reflected in
+/// the operands and are not listed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum X86Prefix {
+    /// the instruction accesses its memory operand atomically
+    Lock,
+    /// the string instruction is repeated rcx times
+    Rep,
+    /// the string instruction is repeated rcx times or while the operands are equal
+    Repe,
+    /// the string instruction is repeated rcx times or while the operands are not equal
+    Repne,
+}
+
+impl X86Prefix {
+    /// Translate a fixed string into a concrete prefix
+    pub fn from_name(prefix: &str) -> Option<Self> {
+        match prefix {
+            "lock" => Some(X86Prefix::Lock),
+            "rep" => Some(X86Prefix::Rep),
+            "repe" | "repz" => Some(X86Prefix::Repe),
+            "repne" | "repnz" => Some(X86Prefix::Repne),
+            _ => None,
+        }
+    }
+}
+
+impl Display for X86Prefix {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            X86Prefix::Lock => "lock",
+            X86Prefix::Rep => "rep",
+            X86Prefix::Repe => "repe",
+            X86Prefix::Repne => "repne",
+        };
+        write!(f, "{}", name)
+    }
+}
@@ -0,0 +1,430 @@
+use iced_x86::Register;
+use std::fmt::{Display, Formatter};
+
+/// Kind of a register
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RegisterClass {
+    /// general purpose register (e.g. rax, r8d, al)
+    Gpr,
+    /// instruction pointer
+    InstructionPointer,
+    /// the flags register
+    Flags,
+    /// segment register
+    Segment,
+    /// SSE/AVX vector register (xmm, ymm, zmm)
+    Vector,
+    /// AVX-512 opmask register
+    Mask,
+    /// MMX register
+    Mmx,
+    /// x87 FPU stack register
+    X87,
+    /// control register
+    Control,
+    /// debug register
+    Debug,
+    /// MPX bound register
+    Bound,
+    /// AMX tile register
+    Tile,
+}
+
+/// Declares the registers: the table at the end of this file lists one register per line as
+/// `Variant [decoder register] => "name", width in bits, class, canonical parent;`. The registers
+/// that `iced_x86` does not decode (the instruction pointer and the flags) have no decoder
+/// register.
+macro_rules! registers {
+    ($($variant:ident $([$iced:ident])? => $name:literal, $width:literal, $class:ident, $parent:ident;)*) => {
+        /// Represents a register for Intel x86_64
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub enum X86Register {
+            $(
+                #[doc = concat!("x86 register ", $name)]
+                $variant,
+            )*
+        }
+
+        impl X86Register {
+            /// Every register, in the order of the table
+            pub const ALL: &'static [X86Register] = &[$(X86Register::$variant,)*];
+
+            /// Name of the register, as written in Intel syntax
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(X86Register::$variant => $name,)*
+                }
+            }
+
+            /// Size of the register in bits
+            pub fn width(&self) -> u32 {
+                match self {
+                    $(X86Register::$variant => $width,)*
+                }
+            }
+
+            /// Kind of the register
+            pub fn class(&self) -> RegisterClass {
+                match self {
+                    $(X86Register::$variant => RegisterClass::$class,)*
+                }
+            }
+
+            /// The full register this one is a part of (e.g. al, ax and eax are parts of rax, xmm0
+            /// is part of zmm0). Registers that are not part of a larger one are their own parent.
+            pub fn parent(&self) -> X86Register {
+                match self {
+                    $(X86Register::$variant => X86Register::$parent,)*
+                }
+            }
+
+            /// Translate a register of the `iced_x86` decoder. Registers that do not exist in 64-bit
+            /// mode (e.g. test registers) have no translation.
+            pub fn from_iced(register: Register) -> Option<Self> {
+                match register {
+                    $($(Register::$iced => Some(X86Register::$variant),)?)*
+                    _ => None,
+                }
+            }
+
+            /// Register with the exact name
+            fn by_name(name: &str) -> Option<Self> {
+                match name {
+                    $($name => Some(X86Register::$variant),)*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+impl X86Register {
+    /// Translate a register name (e.g. `eax`) into a concrete register. Besides the Intel names,
+    /// the spellings used by other disassemblers are accepted: `r8l` for `r8b`, `st(0)` for
+    /// `st0`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim().to_lowercase();
+        if let Some(register) = Self::by_name(&name) {
+            return Some(register);
+        }
+
+        let alias = match name.as_str() {
+            "st" => "st0".to_string(),
+            _ if name.starts_with("st(") => name.replace(['(', ')'], ""),
+            _ if name.starts_with('r') && name.ends_with('l') => {
+                format!("{}b", name.trim_end_matches('l'))
+            }
+            _ => return None,
+        };
+        Self::by_name(&alias)
+    }
+
+    /// 64-bit general purpose register with the number used by the encoding (0 is rax, 15 is
+    /// r15). Only the low 4 bits of the number are used.
+    pub fn gpr64(number: u8) -> X86Register {
+        const GPR64: [X86Register; 16] = [
+            X86Register::Rax,
+            X86Register::Rcx,
+            X86Register::Rdx,
+            X86Register::Rbx,
+            X86Register::Rsp,
+            X86Register::Rbp,
+            X86Register::Rsi,
+            X86Register::Rdi,
+            X86Register::R8,
+            X86Register::R9,
+            X86Register::R10,
+            X86Register::R11,
+            X86Register::R12,
+            X86Register::R13,
+            X86Register::R14,
+            X86Register::R15,
+        ];
+        GPR64[(number & 0xf) as usize]
+    }
+
+    /// xmm register with the number used by the encoding. Only the low 5 bits of the number are
+    /// used.
+    pub fn xmm(number: u8) -> X86Register {
+        let first = Self::ALL
+            .iter()
+            .position(|register| *register == X86Register::Xmm0)
+            .unwrap_or_default();
+        Self::ALL[first + (number & 0x1f) as usize]
+    }
+
+    /// Returns if the register is a general purpose register
+    pub fn is_gpr(&self) -> bool {
+        self.class() == RegisterClass::Gpr
+    }
+
+    /// Position in bits of the register inside its parent: the high byte registers (ah, ch, dh,
+    /// bh) start at bit 8, every other register at bit 0
+    pub fn offset(&self) -> u32 {
+        match self {
+            X86Register::Ah | X86Register::Ch | X86Register::Dh | X86Register::Bh => 8,
+            _ => 0,
+        }
+    }
+
+    /// Returns if both registers share at least one bit of the same full register (e.g. eax and
+    /// ax, or ah and ax, but not ah and al)
+    pub fn overlaps(&self, other: X86Register) -> bool {
+        self.parent() == other.parent()
+            && self.offset() < other.offset() + other.width()
+            && other.offset() < self.offset() + self.width()
+    }
+}
+
+impl Display for X86Register {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+registers! {
+    Al [AL] => "al", 8, Gpr, Rax;
+    Cl [CL] => "cl", 8, Gpr, Rcx;
+    Dl [DL] => "dl", 8, Gpr, Rdx;
+    Bl [BL] => "bl", 8, Gpr, Rbx;
+    Spl [SPL] => "spl", 8, Gpr, Rsp;
+    Bpl [BPL] => "bpl", 8, Gpr, Rbp;
+    Sil [SIL] => "sil", 8, Gpr, Rsi;
+    Dil [DIL] => "dil", 8, Gpr, Rdi;
+    R8b [R8L] => "r8b", 8, Gpr, R8;
+    R9b [R9L] => "r9b", 8, Gpr, R9;
+    R10b [R10L] => "r10b", 8, Gpr, R10;
+    R11b [R11L] => "r11b", 8, Gpr, R11;
+    R12b [R12L] => "r12b", 8, Gpr, R12;
+    R13b [R13L] => "r13b", 8, Gpr, R13;
+    R14b [R14L] => "r14b", 8, Gpr, R14;
+    R15b [R15L] => "r15b", 8, Gpr, R15;
+    Ah [AH] => "ah", 8, Gpr, Rax;
+    Ch [CH] => "ch", 8, Gpr, Rcx;
+    Dh [DH] => "dh", 8, Gpr, Rdx;
+    Bh [BH] => "bh", 8, Gpr, Rbx;
+    Ax [AX] => "ax", 16, Gpr, Rax;
+    Cx [CX] => "cx", 16, Gpr, Rcx;
+    Dx [DX] => "dx", 16, Gpr, Rdx;
+    Bx [BX] => "bx", 16, Gpr, Rbx;
+    Sp [SP] => "sp", 16, Gpr, Rsp;
+    Bp [BP] => "bp", 16, Gpr, Rbp;
+    Si [SI] => "si", 16, Gpr, Rsi;
+    Di [DI] => "di", 16, Gpr, Rdi;
+    R8w [R8W] => "r8w", 16, Gpr, R8;
+    R9w [R9W] => "r9w", 16, Gpr, R9;
+    R10w [R10W] => "r10w", 16, Gpr, R10;
+    R11w [R11W] => "r11w", 16, Gpr, R11;
+    R12w [R12W] => "r12w", 16, Gpr, R12;
+    R13w [R13W] => "r13w", 16, Gpr, R13;
+    R14w [R14W] => "r14w", 16, Gpr, R14;
+    R15w [R15W] => "r15w", 16, Gpr, R15;
+    Eax [EAX] => "eax", 32, Gpr, Rax;
+    Ecx [ECX] => "ecx", 32, Gpr, Rcx;
+    Edx [EDX] => "edx", 32, Gpr, Rdx;
+    Ebx [EBX] => "ebx", 32, Gpr, Rbx;
+    Esp [ESP] => "esp", 32, Gpr, Rsp;
+    Ebp [EBP] => "ebp", 32, Gpr, Rbp;
+    Esi [ESI] => "esi", 32, Gpr, Rsi;
+    Edi [EDI] => "edi", 32, Gpr, Rdi;
+    R8d [R8D] => "r8d", 32, Gpr, R8;
+    R9d [R9D] => "r9d", 32, Gpr, R9;
+    R10d [R10D] => "r10d", 32, Gpr, R10;
+    R11d [R11D] => "r11d", 32, Gpr, R11;
+    R12d [R12D] => "r12d", 32, Gpr, R12;
+    R13d [R13D] => "r13d", 32, Gpr, R13;
+    R14d [R14D] => "r14d", 32, Gpr, R14;
+    R15d [R15D] => "r15d", 32, Gpr, R15;
+    Rax [RAX] => "rax", 64, Gpr, Rax;
+    Rcx [RCX] => "rcx", 64, Gpr, Rcx;
+    Rdx [RDX] => "rdx", 64, Gpr, Rdx;
+    Rbx [RBX] => "rbx", 64, Gpr, Rbx;
+    Rsp [RSP] => "rsp", 64, Gpr, Rsp;
+    Rbp [RBP] => "rbp", 64, Gpr, Rbp;
+    Rsi [RSI] => "rsi", 64, Gpr, Rsi;
+    Rdi [RDI] => "rdi", 64, Gpr, Rdi;
+    R8 [R8] => "r8", 64, Gpr, R8;
+    R9 [R9] => "r9", 64, Gpr, R9;
+    R10 [R10] => "r10", 64, Gpr, R10;
+    R11 [R11] => "r11", 64, Gpr, R11;
+    R12 [R12] => "r12", 64, Gpr, R12;
+    R13 [R13] => "r13", 64, Gpr, R13;
+    R14 [R14] => "r14", 64, Gpr, R14;
+    R15 [R15] => "r15", 64, Gpr, R15;
+    Ip => "ip", 16, InstructionPointer, Rip;
+    Eip [EIP] => "eip", 32, InstructionPointer, Rip;
+    Rip [RIP] => "rip", 64, InstructionPointer, Rip;
+    Flags => "flags", 16, Flags, Rflags;
+    Eflags => "eflags", 32, Flags, Rflags;
+    Rflags => "rflags", 64, Flags, Rflags;
+    Es [ES] => "es", 16, Segment, Es;
+    Cs [CS] => "cs", 16, Segment, Cs;
+    Ss [SS] => "ss", 16, Segment, Ss;
+    Ds [DS] => "ds", 16, Segment, Ds;
+    Fs [FS] => "fs", 16, Segment, Fs;
+    Gs [GS] => "gs", 16, Segment, Gs;
+    Xmm0 [XMM0] => "xmm0", 128, Vector, Zmm0;
+    Xmm1 [XMM1] => "xmm1", 128, Vector, Zmm1;
+    Xmm2 [XMM2] => "xmm2", 128, Vector, Zmm2;
+    Xmm3 [XMM3] => "xmm3", 128, Vector, Zmm3;
+    Xmm4 [XMM4] => "xmm4", 128, Vector, Zmm4;
+    Xmm5 [XMM5] => "xmm5", 128, Vector, Zmm5;
+    Xmm6 [XMM6] => "xmm6", 128, Vector, Zmm6;
+    Xmm7 [XMM7] => "xmm7", 128, Vector, Zmm7;
+    Xmm8 [XMM8] => "xmm8", 128, Vector, Zmm8;
+    Xmm9 [XMM9] => "xmm9", 128, Vector, Zmm9;
+    Xmm10 [XMM10] => "xmm10", 128, Vector, Zmm10;
+    Xmm11 [XMM11] => "xmm11", 128, Vector, Zmm11;
+    Xmm12 [XMM12] => "xmm12", 128, Vector, Zmm12;
+    Xmm13 [XMM13] => "xmm13", 128, Vector, Zmm13;
+    Xmm14 [XMM14] => "xmm14", 128, Vector, Zmm14;
+    Xmm15 [XMM15] => "xmm15", 128, Vector, Zmm15;
+    Xmm16 [XMM16] => "xmm16", 128, Vector, Zmm16;
+    Xmm17 [XMM17] => "xmm17", 128, Vector, Zmm17;
+    Xmm18 [XMM18] => "xmm18", 128, Vector, Zmm18;
+    Xmm19 [XMM19] => "xmm19", 128, Vector, Zmm19;
+    Xmm20 [XMM20] => "xmm20", 128, Vector, Zmm20;
+    Xmm21 [XMM21] => "xmm21", 128, Vector, Zmm21;
+    Xmm22 [XMM22] => "xmm22", 128, Vector, Zmm22;
+    Xmm23 [XMM23] => "xmm23", 128, Vector, Zmm23;
+    Xmm24 [XMM24] => "xmm24", 128, Vector, Zmm24;
+    Xmm25 [XMM25] => "xmm25", 128, Vector, Zmm25;
+    Xmm26 [XMM26] => "xmm26", 128, Vector, Zmm26;
+    Xmm27 [XMM27] => "xmm27", 128, Vector, Zmm27;
+    Xmm28 [XMM28] => "xmm28", 128, Vector, Zmm28;
+    Xmm29 [XMM29] => "xmm29", 128, Vector, Zmm29;
+    Xmm30 [XMM30] => "xmm30", 128, Vector, Zmm30;
+    Xmm31 [XMM31] => "xmm31", 128, Vector, Zmm31;
+    Ymm0 [YMM0] => "ymm0", 256, Vector, Zmm0;
+    Ymm1 [YMM1] => "ymm1", 256, Vector, Zmm1;
+    Ymm2 [YMM2] => "ymm2", 256, Vector, Zmm2;
+    Ymm3 [YMM3] => "ymm3", 256, Vector, Zmm3;
+    Ymm4 [YMM4] => "ymm4", 256, Vector, Zmm4;
+    Ymm5 [YMM5] => "ymm5", 256, Vector, Zmm5;
+    Ymm6 [YMM6] => "ymm6", 256, Vector, Zmm6;
+    Ymm7 [YMM7] => "ymm7", 256, Vector, Zmm7;
+    Ymm8 [YMM8] => "ymm8", 256, Vector, Zmm8;
+    Ymm9 [YMM9] => "ymm9", 256, Vector, Zmm9;
+    Ymm10 [YMM10] => "ymm10", 256, Vector, Zmm10;
+    Ymm11 [YMM11] => "ymm11", 256, Vector, Zmm11;
+    Ymm12 [YMM12] => "ymm12", 256, Vector, Zmm12;
+    Ymm13 [YMM13] => "ymm13", 256, Vector, Zmm13;
+    Ymm14 [YMM14] => "ymm14", 256, Vector, Zmm14;
+    Ymm15 [YMM15] => "ymm15", 256, Vector, Zmm15;
+    Ymm16 [YMM16] => "ymm16", 256, Vector, Zmm16;
+    Ymm17 [YMM17] => "ymm17", 256, Vector, Zmm17;
+    Ymm18 [YMM18] => "ymm18", 256, Vector, Zmm18;
+    Ymm19 [YMM19] => "ymm19", 256, Vector, Zmm19;
+    Ymm20 [YMM20] => "ymm20", 256, Vector, Zmm20;
+    Ymm21 [YMM21] => "ymm21", 256, Vector, Zmm21;
+    Ymm22 [YMM22] => "ymm22", 256, Vector, Zmm22;
+    Ymm23 [YMM23] => "ymm23", 256, Vector, Zmm23;
+    Ymm24 [YMM24] => "ymm24", 256, Vector, Zmm24;
+    Ymm25 [YMM25] => "ymm25", 256, Vector, Zmm25;
+    Ymm26 [YMM26] => "ymm26", 256, Vector, Zmm26;
+    Ymm27 [YMM27] => "ymm27", 256, Vector, Zmm27;
+    Ymm28 [YMM28] => "ymm28", 256, Vector, Zmm28;
+    Ymm29 [YMM29] => "ymm29", 256, Vector, Zmm29;
+    Ymm30 [YMM30] => "ymm30", 256, Vector, Zmm30;
+    Ymm31 [YMM31] => "ymm31", 256, Vector, Zmm31;
+    Zmm0 [ZMM0] => "zmm0", 512, Vector, Zmm0;
+    Zmm1 [ZMM1] => "zmm1", 512, Vector, Zmm1;
+    Zmm2 [ZMM2] => "zmm2", 512, Vector, Zmm2;
+    Zmm3 [ZMM3] => "zmm3", 512, Vector, Zmm3;
+    Zmm4 [ZMM4] => "zmm4", 512, Vector, Zmm4;
+    Zmm5 [ZMM5] => "zmm5", 512, Vector, Zmm5;
+    Zmm6 [ZMM6] => "zmm6", 512, Vector, Zmm6;
+    Zmm7 [ZMM7] => "zmm7", 512, Vector, Zmm7;
+    Zmm8 [ZMM8] => "zmm8", 512, Vector, Zmm8;
+    Zmm9 [ZMM9] => "zmm9", 512, Vector, Zmm9;
+    Zmm10 [ZMM10] => "zmm10", 512, Vector, Zmm10;
+    Zmm11 [ZMM11] => "zmm11", 512, Vector, Zmm11;
+    Zmm12 [ZMM12] => "zmm12", 512, Vector, Zmm12;
+    Zmm13 [ZMM13] => "zmm13", 512, Vector, Zmm13;
+    Zmm14 [ZMM14] => "zmm14", 512, Vector, Zmm14;
+    Zmm15 [ZMM15] => "zmm15", 512, Vector, Zmm15;
+    Zmm16 [ZMM16] => "zmm16", 512, Vector, Zmm16;
+    Zmm17 [ZMM17] => "zmm17", 512, Vector, Zmm17;
+    Zmm18 [ZMM18] => "zmm18", 512, Vector, Zmm18;
+    Zmm19 [ZMM19] => "zmm19", 512, Vector, Zmm19;
+    Zmm20 [ZMM20] => "zmm20", 512, Vector, Zmm20;
+    Zmm21 [ZMM21] => "zmm21", 512, Vector, Zmm21;
+    Zmm22 [ZMM22] => "zmm22", 512, Vector, Zmm22;
+    Zmm23 [ZMM23] => "zmm23", 512, Vector, Zmm23;
+    Zmm24 [ZMM24] => "zmm24", 512, Vector, Zmm24;
+    Zmm25 [ZMM25] => "zmm25", 512, Vector, Zmm25;
+    Zmm26 [ZMM26] => "zmm26", 512, Vector, Zmm26;
+    Zmm27 [ZMM27] => "zmm27", 512, Vector, Zmm27;
+    Zmm28 [ZMM28] => "zmm28", 512, Vector, Zmm28;
+    Zmm29 [ZMM29] => "zmm29", 512, Vector, Zmm29;
+    Zmm30 [ZMM30] => "zmm30", 512, Vector, Zmm30;
+    Zmm31 [ZMM31] => "zmm31", 512, Vector, Zmm31;
+    K0 [K0] => "k0", 64, Mask, K0;
+    K1 [K1] => "k1", 64, Mask, K1;
+    K2 [K2] => "k2", 64, Mask, K2;
+    K3 [K3] => "k3", 64, Mask, K3;
+    K4 [K4] => "k4", 64, Mask, K4;
+    K5 [K5] => "k5", 64, Mask, K5;
+    K6 [K6] => "k6", 64, Mask, K6;
+    K7 [K7] => "k7", 64, Mask, K7;
+    Mm0 [MM0] => "mm0", 64, Mmx, Mm0;
+    Mm1 [MM1] => "mm1", 64, Mmx, Mm1;
+    Mm2 [MM2] => "mm2", 64, Mmx, Mm2;
+    Mm3 [MM3] => "mm3", 64, Mmx, Mm3;
+    Mm4 [MM4] => "mm4", 64, Mmx, Mm4;
+    Mm5 [MM5] => "mm5", 64, Mmx, Mm5;
+    Mm6 [MM6] => "mm6", 64, Mmx, Mm6;
+    Mm7 [MM7] => "mm7", 64, Mmx, Mm7;
+    St0 [ST0] => "st0", 80, X87, St0;
+    St1 [ST1] => "st1", 80, X87, St1;
+    St2 [ST2] => "st2", 80, X87, St2;
+    St3 [ST3] => "st3", 80, X87, St3;
+    St4 [ST4] => "st4", 80, X87, St4;
+    St5 [ST5] => "st5", 80, X87, St5;
+    St6 [ST6] => "st6", 80, X87, St6;
+    St7 [ST7] => "st7", 80, X87, St7;
+    Cr0 [CR0] => "cr0", 64, Control, Cr0;
+    Cr1 [CR1] => "cr1", 64, Control, Cr1;
+    Cr2 [CR2] => "cr2", 64, Control, Cr2;
+    Cr3 [CR3] => "cr3", 64, Control, Cr3;
+    Cr4 [CR4] => "cr4", 64, Control, Cr4;
+    Cr5 [CR5] => "cr5", 64, Control, Cr5;
+    Cr6 [CR6] => "cr6", 64, Control, Cr6;
+    Cr7 [CR7] => "cr7", 64, Control, Cr7;
+    Cr8 [CR8] => "cr8", 64, Control, Cr8;
+    Cr9 [CR9] => "cr9", 64, Control, Cr9;
+    Cr10 [CR10] => "cr10", 64, Control, Cr10;
+    Cr11 [CR11] => "cr11", 64, Control, Cr11;
+    Cr12 [CR12] => "cr12", 64, Control, Cr12;
+    Cr13 [CR13] => "cr13", 64, Control, Cr13;
+    Cr14 [CR14] => "cr14", 64, Control, Cr14;
+    Cr15 [CR15] => "cr15", 64, Control, Cr15;
+    Dr0 [DR0] => "dr0", 64, Debug, Dr0;
+    Dr1 [DR1] => "dr1", 64, Debug, Dr1;
+    Dr2 [DR2] => "dr2", 64, Debug, Dr2;
+    Dr3 [DR3] => "dr3", 64, Debug, Dr3;
+    Dr4 [DR4] => "dr4", 64, Debug, Dr4;
+    Dr5 [DR5] => "dr5", 64, Debug, Dr5;
+    Dr6 [DR6] => "dr6", 64, Debug, Dr6;
+    Dr7 [DR7] => "dr7", 64, Debug, Dr7;
+    Dr8 [DR8] => "dr8", 64, Debug, Dr8;
+    Dr9 [DR9] => "dr9", 64, Debug, Dr9;
+    Dr10 [DR10] => "dr10", 64, Debug, Dr10;
+    Dr11 [DR11] => "dr11", 64, Debug, Dr11;
+    Dr12 [DR12] => "dr12", 64, Debug, Dr12;
+    Dr13 [DR13] => "dr13", 64, Debug, Dr13;
+    Dr14 [DR14] => "dr14", 64, Debug, Dr14;
+    Dr15 [DR15] => "dr15", 64, Debug, Dr15;
+    Bnd0 [BND0] => "bnd0", 128, Bound, Bnd0;
+    Bnd1 [BND1] => "bnd1", 128, Bound, Bnd1;
+    Bnd2 [BND2] => "bnd2", 128, Bound, Bnd2;
+    Bnd3 [BND3] => "bnd3", 128, Bound, Bnd3;
+    Tmm0 [TMM0] => "tmm0", 8192, Tile, Tmm0;
+    Tmm1 [TMM1] => "tmm1", 8192, Tile, Tmm1;
+    Tmm2 [TMM2] => "tmm2", 8192, Tile, Tmm2;
+    Tmm3 [TMM3] => "tmm3", 8192, Tile, Tmm3;
+    Tmm4 [TMM4] => "tmm4", 8192, Tile, Tmm4;
+    Tmm5 [TMM5] => "tmm5", 8192, Tile, Tmm5;
+    Tmm6 [TMM6] => "tmm6", 8192, Tile, Tmm6;
+    Tmm7 [TMM7] => "tmm7", 8192, Tile, Tmm7;
+}
@@ -0,0 +1,90 @@
+//! Translation between relative virtual addresses, virtual addresses and file offsets
+
+use InsPEctor::pe::address::{Address, AddressTranslator, FileOffset, Rva, VirtualAddress};
+use InsPEctor::pe::section::Section;
+
+const IMAGE_BASE: Address = 0x140000000;
+const SIZE_OF_HEADERS: u32 = 0x400;
+
+/// .text is 0x1800 bytes in memory but only 0x1000 bytes in the file, .data follows it in both
+fn translator() -> AddressTranslator {
+    let sections = vec![
+        Section {
+            name: ".text".to_string(),
+            virtual_address: Rva(0x1000),
+            virtual_size: 0x1800,
+            pointer_to_raw_data: FileOffset(0x400),
+            size_of_raw_data: 0x1000,
+            characteristics: 0,
+        },
+        Section {
+            name: ".data".to_string(),
+            virtual_address: Rva(0x3000),
+            virtual_size: 0x200,
+            pointer_to_raw_data: FileOffset(0x1400),
+            size_of_raw_data: 0x200,
+            characteristics: 0,
+        },
+    ];
+    AddressTranslator::new(IMAGE_BASE, SIZE_OF_HEADERS, sections)
+}
+
+#[test]
+fn round_trips() {
+    let translator = translator();
+
+    for (rva, offset) in [
+        (0x10, 0x10),
+        (0x1000, 0x400),
+        (0x1fff, 0x13ff),
+        (0x3123, 0x1523),
+    ] {
+        let va = translator.rva_to_va(Rva(rva));
+        assert_eq!(va, VirtualAddress(IMAGE_BASE + rva as Address));
+        assert_eq!(translator.va_to_rva(va).unwrap(), Rva(rva));
+        assert_eq!(
+            translator.rva_to_offset(Rva(rva)).unwrap(),
+            FileOffset(offset)
+        );
+        assert_eq!(
+            translator.offset_to_rva(FileOffset(offset)).unwrap(),
+            Rva(rva)
+        );
+        assert_eq!(translator.va_to_offset(va).unwrap(), FileOffset(offset));
+        assert_eq!(translator.offset_to_va(FileOffset(offset)).unwrap(), va);
+    }
+
+    assert_eq!(
+        translator.section_of_rva(Rva(0x2400)).unwrap().name,
+        ".text"
+    );
+    assert_eq!(
+        translator
+            .section_of_va(VirtualAddress(IMAGE_BASE + 0x3000))
+            .unwrap()
+            .name,
+        ".data"
+    );
+}
+
+#[test]
+fn out_of_range() {
+    let translator = translator();
+
+    // below the image base, or too far from it to be an RVA
+    assert!(translator
+        .va_to_rva(VirtualAddress(IMAGE_BASE - 1))
+        .is_err());
+    assert!(translator
+        .va_to_rva(VirtualAddress(IMAGE_BASE + 0x1_0000_0000))
+        .is_err());
+    // between sections
+    assert!(translator.section_of_rva(Rva(0x2800)).is_err());
+    assert!(translator.rva_to_offset(Rva(0x2800)).is_err());
+    // mapped in memory but not backed by the file
+    assert!(translator.section_of_rva(Rva(0x2400)).is_ok());
+    assert!(translator.rva_to_offset(Rva(0x2400)).is_err());
+    // after the last section of the file
+    assert!(translator.offset_to_rva(FileOffset(0x1600)).is_err());
+    assert!(translator.offset_to_va(FileOffset(0x1600)).is_err());
+}
@@ -0,0 +1,92 @@
+//! Tricks used against disassemblers, in a synthetic image: calc.exe has none
+
+mod common;
+
+use common::synthetic_image;
+use InsPEctor::disassembler::anti_disassembly::AntiDisassembly;
+use InsPEctor::disassembler::disassemble::{Disassembler, DisassemblerStrategy};
+use InsPEctor::disassembler::disassembly::Disassembly;
+
+/// Map the code at the beginning of .text and disassemble from its first byte
+fn disassemble(code: &[u8], strategy: DisassemblerStrategy) -> Disassembly {
+    Disassembler::extract(synthetic_image("tricks.exe", code, &[]), strategy).unwrap()
+}
+
+/// Every trick at once
+const TRICKS: &[u8] = &[
+    0x74, 0x03, // je 0x140001005
+    0x75, 0x01, // jne 0x140001005
+    0xe8, // junk: first byte of a call
+    0xe8, 0x00, 0x00, 0x00, 0x00, // call 0x14000100a
+    0x58, // pop rax
+    0xeb, 0xff, // jmp 0x14000100c, its own last byte
+    0xc0, // inc eax, with the last byte of the jmp
+    0xc3, // ret
+];
+
+#[test]
+fn tricks_are_reported() {
+    let disassembly = disassemble(TRICKS, DisassemblerStrategy::RecursiveTraversal);
+
+    assert_eq!(
+        disassembly.anti_disassembly,
+        vec![
+            AntiDisassembly::ComplementaryJumps {
+                first: 0x140001000,
+                second: 0x140001002,
+                target: 0x140001005,
+            },
+            AntiDisassembly::CallPop {
+                call: 0x140001005,
+                pop: 0x14000100a,
+            },
+            AntiDisassembly::OverlappingInstruction {
+                branch: 0x14000100b,
+                target: 0x14000100c,
+                instruction: 0x14000100b,
+            },
+        ]
+    );
+}
+
+#[test]
+fn traversal_is_not_fooled() {
+    let disassembly = disassemble(TRICKS, DisassemblerStrategy::RecursiveTraversal);
+    let addresses = disassembly
+        .instructions()
+        .map(|instruction| (instruction.address, instruction.opcode.to_string()))
+        .collect::<Vec<_>>();
+
+    // the junk after the jumps is skipped, and the jmp and the inc share a byte
+    assert_eq!(
+        addresses,
+        vec![
+            (0x140001000, "je".to_string()),
+            (0x140001002, "jne".to_string()),
+            (0x140001005, "call".to_string()),
+            (0x14000100a, "pop".to_string()),
+            (0x14000100b, "jmp".to_string()),
+            (0x14000100c, "inc".to_string()),
+            (0x14000100e, "ret".to_string()),
+        ]
+    );
+    // the call reads the instruction pointer, it does not start a function
+    assert!(!disassembly.functions.contains(&0x14000100a));
+}
+
+#[test]
+fn linear_sweep_decodes_the_junk() {
+    let disassembly = disassemble(TRICKS, DisassemblerStrategy::LinearSweep);
+
+    // the sweep decodes a call from the junk byte, and the jump lands inside it
+    assert!(disassembly
+        .instructions()
+        .any(|instruction| instruction.address == 0x140001004));
+    assert!(disassembly.anti_disassembly.iter().any(|trick| matches!(
+        trick,
+        AntiDisassembly::OverlappingInstruction {
+            instruction: 0x140001004,
+            ..
+        }
+    )));
+}
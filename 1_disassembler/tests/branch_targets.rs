@@ -0,0 +1,162 @@
+//! Targets of relative branches decoded from `binaries/calc.exe`, checked against the addresses
+//! reported by other disassemblers
+
+mod common;
+
+use common::calc;
+use InsPEctor::disassembler::decoder::InstructionDecoder;
+use InsPEctor::disassembler::disassemble::{Disassembler, DisassemblerStrategy};
+use InsPEctor::pe::address::Address;
+use InsPEctor::pe::file_read::FileRead;
+use InsPEctor::x86::branch::BranchKind;
+use InsPEctor::x86::instruction::Instruction;
+use InsPEctor::x86::opcode::X86Opcode;
+
+fn decode_at(file_read: &FileRead, address: Address) -> Instruction {
+    let bytes = file_read
+        .image
+        .read_bounded(address, 15)
+        .expect("the address is inside the image");
+    InstructionDecoder::default()
+        .decode(bytes, address)
+        .expect("the bytes are a valid instruction")
+}
+
+fn assert_target(
+    file_read: &FileRead,
+    address: Address,
+    opcode: X86Opcode,
+    size: usize,
+    target: Address,
+) {
+    let instruction = decode_at(file_read, address);
+    assert_eq!(instruction.opcode, opcode, "opcode at 0x{:x}", address);
+    assert_eq!(
+        instruction.instruction_size, size,
+        "size at 0x{:x}",
+        address
+    );
+    assert_eq!(
+        instruction.get_target().unwrap(),
+        Some(target),
+        "target of {} at 0x{:x}",
+        instruction,
+        address
+    );
+}
+
+#[test]
+fn jmp_rel8() {
+    // eb 05
+    assert_target(&calc(), 0x140001041, X86Opcode::Jmp, 2, 0x140001048);
+}
+
+#[test]
+fn jmp_rel32() {
+    // e9 e2 00 00 00
+    assert_target(&calc(), 0x140001159, X86Opcode::Jmp, 5, 0x140001240);
+    // e9 6e fd ff ff: backward jump at the end of the entrypoint
+    assert_target(&calc(), 0x14000174d, X86Opcode::Jmp, 5, 0x1400014c0);
+}
+
+#[test]
+fn jcc_rel8() {
+    // 74 65
+    assert_target(&calc(), 0x140001024, X86Opcode::Je, 2, 0x14000108b);
+}
+
+#[test]
+fn jcc_rel32() {
+    // 0f 85 85 00 00 00
+    assert_target(&calc(), 0x14000118f, X86Opcode::Jne, 6, 0x14000121a);
+}
+
+#[test]
+fn call_rel32() {
+    // e8 1b fa ff ff: backward call from the entrypoint
+    assert_target(&calc(), 0x140001744, X86Opcode::Call, 5, 0x140001164);
+}
+
+#[test]
+fn jrcxz_rel8() {
+    // e3 48
+    assert_target(&calc(), 0x14000175f, X86Opcode::Jrcxz, 2, 0x1400017a9);
+}
+
+#[test]
+fn loop_rel8() {
+    // calc.exe has no loop instruction: e2 fe jumps back to itself
+    let instruction = InstructionDecoder::default()
+        .decode(&[0xe2, 0xfe], 0x140001000)
+        .unwrap();
+    assert_eq!(instruction.opcode, X86Opcode::Loop);
+    assert_eq!(instruction.get_target().unwrap(), Some(0x140001000));
+}
+
+#[test]
+fn indirect_call_has_no_target() {
+    // ff 15 02 0d 00 00: call qword ptr [rip + 0xd02]
+    let instruction = decode_at(&calc(), 0x1400014e8);
+    assert_eq!(instruction.opcode, X86Opcode::Call);
+    assert_eq!(instruction.get_target().unwrap(), None);
+}
+
+#[test]
+fn branch_kinds() {
+    let decoder = InstructionDecoder::default();
+    let kind = |bytes: &[u8]| decoder.decode(bytes, 0x140001000).unwrap().branch_kind();
+
+    assert_eq!(kind(&[0xeb, 0x05]), Some(BranchKind::UnconditionalDirect));
+    assert_eq!(kind(&[0xff, 0xe0]), Some(BranchKind::UnconditionalIndirect));
+    // jg, jge, jle, jae, js, jns, jo, jp, jnp, jrcxz, loop, loope, loopne
+    for bytes in [
+        [0x7f, 0x00],
+        [0x7d, 0x00],
+        [0x7e, 0x00],
+        [0x73, 0x00],
+        [0x78, 0x00],
+        [0x79, 0x00],
+        [0x70, 0x00],
+        [0x7a, 0x00],
+        [0x7b, 0x00],
+        [0xe3, 0x00],
+        [0xe2, 0x00],
+        [0xe1, 0x00],
+        [0xe0, 0x00],
+    ] {
+        assert_eq!(
+            kind(&bytes),
+            Some(BranchKind::Conditional),
+            "{:02x?}",
+            bytes
+        );
+    }
+    assert_eq!(
+        kind(&[0xe8, 0x00, 0x00, 0x00, 0x00]),
+        Some(BranchKind::CallDirect)
+    );
+    assert_eq!(kind(&[0xff, 0xd0]), Some(BranchKind::CallIndirect));
+    assert_eq!(kind(&[0xc3]), Some(BranchKind::Return));
+    assert_eq!(kind(&[0xcc]), Some(BranchKind::Interrupt));
+    assert_eq!(kind(&[0x0f, 0x05]), Some(BranchKind::Interrupt));
+    assert_eq!(kind(&[0xf4]), Some(BranchKind::Halt));
+    assert_eq!(kind(&[0x90]), None);
+}
+
+#[test]
+fn recursive_traversal_follows_targets() {
+    let disassembly =
+        Disassembler::extract(calc(), DisassemblerStrategy::RecursiveTraversal).unwrap();
+    let addresses = disassembly
+        .instructions()
+        .map(|instruction| instruction.address)
+        .collect::<Vec<Address>>();
+
+    // callee and jump target of the entrypoint
+    assert!(disassembly.functions.contains(&0x140001164));
+    assert!(addresses.contains(&0x140001164));
+    assert!(addresses.contains(&0x1400014c0));
+    // targets of branches inside functions
+    assert!(addresses.contains(&0x14000121a));
+    assert!(addresses.contains(&0x140001240));
+}
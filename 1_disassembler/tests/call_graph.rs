@@ -0,0 +1,171 @@
+//! Call graph of `binaries/calc.exe`, and path queries on synthetic call graphs
+
+mod common;
+
+use common::calc;
+use std::collections::BTreeMap;
+use InsPEctor::cfg::call_graph::{CallEdge, CallGraph, CallGraphNode, CallPaths, MAXIMUM_PATHS};
+use InsPEctor::cfg::program::ProgramGraph;
+use InsPEctor::disassembler::disassemble::{Disassembler, DisassemblerStrategy};
+
+fn calc_call_graph() -> CallGraph {
+    let file_read = calc();
+    let disassembly =
+        Disassembler::extract(file_read, DisassemblerStrategy::RecursiveTraversal).unwrap();
+    CallGraph::build(&ProgramGraph::build(&disassembly))
+}
+
+fn shell_execute() -> CallGraphNode {
+    CallGraphNode::Import {
+        dll: "SHELL32.dll".to_string(),
+        name: "ShellExecuteW".to_string(),
+    }
+}
+
+#[test]
+fn nodes_for_functions_imports_and_indirect_calls() {
+    let call_graph = calc_call_graph();
+
+    assert!(call_graph
+        .nodes
+        .contains(&CallGraphNode::Function(0x140001740)));
+    assert!(call_graph.nodes.contains(&shell_execute()));
+    assert!(call_graph
+        .nodes
+        .contains(&CallGraphNode::Indirect(0x140001085)));
+    assert_eq!(
+        call_graph.imports("ShellExecuteW").collect::<Vec<_>>(),
+        vec![&shell_execute()]
+    );
+}
+
+#[test]
+fn edges_carry_call_sites() {
+    let call_graph = calc_call_graph();
+
+    // call 0x140001164; then jmp 0x1400014c0 as a tail call
+    let callees = call_graph
+        .callees(0x140001740)
+        .map(|edge| (edge.callee.clone(), edge.call_sites.clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        callees,
+        vec![
+            (CallGraphNode::Function(0x140001164), vec![0x140001744]),
+            (CallGraphNode::Function(0x1400014c0), vec![0x14000174d]),
+        ]
+    );
+
+    let shell_execute = shell_execute();
+    let callers = call_graph.callers(&shell_execute).collect::<Vec<_>>();
+    assert_eq!(callers.len(), 1);
+    assert_eq!(callers[0].caller, 0x1400019e4);
+    assert_eq!(callers[0].call_sites, vec![0x140001b01]);
+}
+
+#[test]
+fn paths_from_entrypoint_to_api() {
+    let call_graph = calc_call_graph();
+
+    let paths = call_graph.paths(0x140001740, &shell_execute());
+    assert_eq!(
+        paths.paths,
+        vec![vec![
+            CallGraphNode::Function(0x140001740),
+            CallGraphNode::Function(0x1400014c0),
+            CallGraphNode::Function(0x1400019e4),
+            shell_execute(),
+        ]]
+    );
+    assert!(!paths.truncated);
+    assert!(call_graph
+        .paths(0x140001164, &shell_execute())
+        .paths
+        .is_empty());
+}
+
+/// Call graph where every function of a layer calls both functions of the next layer: the
+/// number of paths doubles with every layer. The last layer calls `ShellExecuteW`, when asked to.
+fn layered_call_graph(layers: u64, reaches_api: bool) -> CallGraph {
+    let function = |layer: u64, i: u64| 0x140001000 + layer * 0x100 + i * 0x10;
+    let mut call_graph = CallGraph::default();
+    call_graph
+        .nodes
+        .insert(CallGraphNode::Function(function(0, 0)));
+    for layer in 0..layers {
+        for i in 0..2 {
+            let caller = function(layer, i);
+            call_graph.nodes.insert(CallGraphNode::Function(caller));
+            let callees = if layer + 1 < layers {
+                vec![
+                    CallGraphNode::Function(function(layer + 1, 0)),
+                    CallGraphNode::Function(function(layer + 1, 1)),
+                ]
+            } else if reaches_api {
+                vec![shell_execute()]
+            } else {
+                vec![]
+            };
+            for callee in callees {
+                call_graph.nodes.insert(callee.clone());
+                call_graph.edges.push(CallEdge {
+                    caller,
+                    callee,
+                    call_sites: vec![caller],
+                });
+            }
+        }
+    }
+    call_graph
+}
+
+#[test]
+fn paths_to_unreachable_target() {
+    // 2^40 paths lead nowhere: the query must not explore them
+    let mut call_graph = layered_call_graph(40, false);
+    assert_eq!(
+        call_graph.paths(0x140001000, &shell_execute()),
+        CallPaths::default()
+    );
+
+    // even when another call of the first function reaches the target
+    call_graph.edges.push(CallEdge {
+        caller: 0x140001000,
+        callee: shell_execute(),
+        call_sites: vec![0x140001008],
+    });
+    assert_eq!(
+        call_graph.paths(0x140001000, &shell_execute()).paths,
+        vec![vec![CallGraphNode::Function(0x140001000), shell_execute()]]
+    );
+}
+
+#[test]
+fn paths_are_bounded() {
+    let call_graph = layered_call_graph(40, true);
+    let paths = call_graph.paths(0x140001000, &shell_execute());
+    assert!(paths.truncated);
+    assert_eq!(paths.paths.len(), MAXIMUM_PATHS);
+    assert!(paths.paths.iter().all(|path| path.len() == 41));
+}
+
+#[test]
+fn exports() {
+    let call_graph = calc_call_graph();
+    let labels = BTreeMap::from([(0x140001740, "entry".to_string())]);
+
+    let dot = call_graph.to_dot(&labels);
+    assert!(dot.starts_with("digraph call_graph {"));
+    assert!(dot.contains("\"0x140001740\" [label=\"entry\", shape=box];"));
+    assert!(
+        dot.contains("\"0x1400019e4\" -> \"SHELL32.dll!ShellExecuteW\" [label=\"0x140001b01\"];")
+    );
+
+    let json = call_graph.to_json(&labels);
+    assert!(json.contains(
+        "{\"id\": \"0x140001740\", \"kind\": \"function\", \"name\": \"entry\", \"address\": \"0x140001740\"}"
+    ));
+    assert!(json.contains(
+        "{\"caller\": \"0x1400019e4\", \"callee\": \"SHELL32.dll!ShellExecuteW\", \"call_sites\": [\"0x140001b01\"]}"
+    ));
+}
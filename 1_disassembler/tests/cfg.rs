@@ -0,0 +1,147 @@
+//! Control flow graphs built from the recursive traversal of `binaries/calc.exe`
+
+mod common;
+
+use common::calc;
+use std::collections::BTreeMap;
+use InsPEctor::cfg::dot::DotExporter;
+use InsPEctor::cfg::edge::{Edge, EdgeKind};
+use InsPEctor::cfg::program::ProgramGraph;
+use InsPEctor::disassembler::disassemble::{Disassembler, DisassemblerStrategy};
+use InsPEctor::pe::address::Address;
+
+fn calc_graph() -> ProgramGraph {
+    let file_read = calc();
+    let disassembly =
+        Disassembler::extract(file_read, DisassemblerStrategy::RecursiveTraversal).unwrap();
+    ProgramGraph::build(&disassembly)
+}
+
+fn successors(program: &ProgramGraph, function: Address, block: Address) -> Vec<Edge> {
+    let mut edges = program
+        .function(function)
+        .unwrap()
+        .successors(block)
+        .copied()
+        .collect::<Vec<Edge>>();
+    edges.sort_by_key(|edge| edge.target);
+    edges
+}
+
+#[test]
+fn entrypoint_calls_and_tail_calls() {
+    let program = calc_graph();
+    let entry = program.function(0x140001740).unwrap();
+
+    // sub rsp, 0x28; call 0x140001164 | add rsp, 0x28; jmp 0x1400014c0
+    assert_eq!(
+        entry.blocks.keys().copied().collect::<Vec<Address>>(),
+        vec![0x140001740, 0x140001749]
+    );
+    assert_eq!(
+        successors(&program, 0x140001740, 0x140001740),
+        vec![
+            Edge::new(0x140001740, 0x140001164, EdgeKind::Call),
+            Edge::new(0x140001740, 0x140001749, EdgeKind::FallThrough),
+        ]
+    );
+    assert_eq!(
+        successors(&program, 0x140001740, 0x140001749),
+        vec![Edge::new(0x140001749, 0x1400014c0, EdgeKind::Call)]
+    );
+}
+
+#[test]
+fn conditional_jumps_have_taken_and_not_taken_edges() {
+    let program = calc_graph();
+    // cmp; je 0x140001527
+    assert_eq!(
+        successors(&program, 0x1400014c0, 0x1400014ff),
+        vec![
+            Edge::new(0x1400014ff, 0x14000150c, EdgeKind::NotTaken),
+            Edge::new(0x1400014ff, 0x140001527, EdgeKind::Taken),
+        ]
+    );
+}
+
+#[test]
+fn loop_target_splits_block() {
+    let program = calc_graph();
+    let function = program.function(0x1400014c0).unwrap();
+
+    // the jump back at 0x140001525 lands after the first instructions of the block that starts
+    // after the call at 0x1400014e8
+    assert!(function.blocks.contains_key(&0x1400014ee));
+    assert!(function.blocks.contains_key(&0x1400014ff));
+    let mut predecessors = function
+        .predecessors(0x1400014ff)
+        .copied()
+        .collect::<Vec<Edge>>();
+    predecessors.sort_by_key(|edge| edge.source);
+    assert_eq!(
+        predecessors,
+        vec![
+            Edge::new(0x1400014ee, 0x1400014ff, EdgeKind::FallThrough),
+            Edge::new(0x140001525, 0x1400014ff, EdgeKind::Taken),
+        ]
+    );
+
+    // every instruction belongs to a single block
+    let mut addresses = function
+        .blocks
+        .values()
+        .flat_map(|block| {
+            block
+                .instructions
+                .iter()
+                .map(|instruction| instruction.address)
+        })
+        .collect::<Vec<Address>>();
+    let count = addresses.len();
+    addresses.dedup();
+    assert_eq!(addresses.len(), count);
+}
+
+#[test]
+fn no_return_call_ends_function() {
+    let program = calc_graph();
+    let function = program.function(0x1400014c0).unwrap();
+
+    // mov ecx, eax; call qword ptr [rip + 0xbb4] (msvcrt.dll!exit)
+    assert_eq!(successors(&program, 0x1400014c0, 0x1400016ac), vec![]);
+    assert!(function.exits().any(|block| block.start == 0x1400016ac));
+}
+
+#[test]
+fn returns_reach_return_sites() {
+    let program = calc_graph();
+    let callee = program.function(0x140001164).unwrap();
+
+    assert!(callee.return_blocks().count() > 0);
+    for block in callee.return_blocks() {
+        assert!(callee
+            .successors(block.start)
+            .any(|edge| *edge == Edge::new(block.start, 0x140001749, EdgeKind::Return)));
+    }
+}
+
+#[test]
+fn dot_export_highlights_entry_exits_and_branches() {
+    let program = calc_graph();
+    let labels = BTreeMap::from([(0x1400014c0, "__scrt_common_main".to_string())]);
+    let dot = DotExporter::function(program.function(0x1400014c0).unwrap(), &labels);
+
+    assert!(dot.starts_with("digraph \"__scrt_common_main\" {"));
+    assert!(dot.contains("\"1400014c0\" [label=\"0x1400014c0: "));
+    assert!(dot.contains("fillcolor=palegreen"));
+    assert!(dot.contains("\"1400016ac\" [label=\"0x1400016ac: mov ecx, eax\\l0x1400016ae: call"));
+    assert!(dot.contains("msvcrt.dll!exit\\l\", fillcolor=lightpink]"));
+    assert!(dot.contains("\"1400014ff\" -> \"140001527\" [color=darkgreen];"));
+    assert!(dot.contains("\"1400014ff\" -> \"14000150c\" [color=red];"));
+
+    let clusters = DotExporter::program(&program, &labels);
+    assert!(clusters.contains("subgraph \"cluster_1400014c0\" {"));
+    assert!(
+        clusters.contains("\"1400014c0_1400014ff\" -> \"1400014c0_140001527\" [color=darkgreen];")
+    );
+}
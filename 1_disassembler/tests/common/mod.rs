@@ -0,0 +1,71 @@
+//! Binaries shared by the integration tests: `binaries/calc.exe`, and synthetic images for what
+//! calc.exe does not contain
+#![allow(dead_code)]
+
+use InsPEctor::pe::address::{Address, AddressTranslator, FileOffset, Rva};
+use InsPEctor::pe::binary_parser::{BinaryParser, IMAGE_SCN_MEM_EXECUTE, IMAGE_SCN_MEM_READ};
+use InsPEctor::pe::file_read::FileRead;
+use InsPEctor::pe::section::{ExecutableRegion, Section};
+use InsPEctor::pe::virtual_image::{Permissions, VirtualImage, PAGE_SIZE};
+
+/// Path of the test binary
+pub const CALC: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/binaries/calc.exe");
+
+/// Image base of the synthetic images
+pub const IMAGE_BASE: Address = 0x140000000;
+/// RVA of the .text section of the synthetic images
+pub const TEXT: u32 = 0x1000;
+/// RVA of the .rdata section of the synthetic images, right after .text
+pub const RDATA: u32 = 0x2000;
+/// Size of every section of the synthetic images
+pub const SECTION_SIZE: u32 = 0x1000;
+/// Entrypoint of the synthetic images: the first byte of .text
+pub const ENTRYPOINT: Address = IMAGE_BASE + TEXT as Address;
+
+/// Parse `binaries/calc.exe`
+pub fn calc() -> FileRead {
+    BinaryParser::read(&CALC.to_string()).expect("calc.exe is parsed")
+}
+
+/// Synthetic image without imports or relocations: the code at the beginning of .text, which is
+/// otherwise filled with int3, and a read-only .rdata filled with zeros. Every patch writes its
+/// bytes at an RVA, in either section.
+pub fn synthetic_image(name: &str, code: &[u8], patches: &[(u32, Vec<u8>)]) -> FileRead {
+    let mut bytes = vec![0; (RDATA + SECTION_SIZE) as usize];
+    bytes[TEXT as usize..RDATA as usize].fill(0xcc);
+    bytes[TEXT as usize..TEXT as usize + code.len()].copy_from_slice(code);
+    for (rva, patch) in patches {
+        bytes[*rva as usize..*rva as usize + patch.len()].copy_from_slice(patch);
+    }
+
+    let section = |name: &str, rva: u32, characteristics: u32| Section {
+        name: name.to_string(),
+        virtual_address: Rva(rva),
+        virtual_size: SECTION_SIZE,
+        pointer_to_raw_data: FileOffset(rva as u64),
+        size_of_raw_data: SECTION_SIZE,
+        characteristics,
+    };
+    let text = section(".text", TEXT, IMAGE_SCN_MEM_EXECUTE | IMAGE_SCN_MEM_READ);
+    let rdata = section(".rdata", RDATA, IMAGE_SCN_MEM_READ);
+    let mut permissions = vec![Permissions::from_section(&rdata); bytes.len() / PAGE_SIZE as usize];
+    permissions[(TEXT as u64 / PAGE_SIZE) as usize] = Permissions::from_section(&text);
+    let image = VirtualImage {
+        image_base: IMAGE_BASE,
+        permissions,
+        bytes: bytes.clone(),
+    };
+
+    FileRead::new(
+        name.to_string(),
+        bytes,
+        image,
+        AddressTranslator::new(IMAGE_BASE, TEXT, vec![text, rdata]),
+        vec![ExecutableRegion::new(
+            ".text".to_string(),
+            ENTRYPOINT,
+            SECTION_SIZE as usize,
+        )],
+        ENTRYPOINT,
+    )
+}
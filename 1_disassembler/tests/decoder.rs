@@ -0,0 +1,82 @@
+//! Structured decoder: translation of the mnemonics and registers of `iced_x86`, and decoding of
+//! instructions from their bytes
+
+use iced_x86::{Mnemonic, Register};
+use InsPEctor::disassembler::decoder::InstructionDecoder;
+use InsPEctor::x86::memory::MemoryOperand;
+use InsPEctor::x86::opcode::X86Opcode;
+use InsPEctor::x86::operands::X86Operand;
+use InsPEctor::x86::prefix::X86Prefix;
+use InsPEctor::x86::register::X86Register;
+
+#[test]
+fn every_mnemonic_is_translated() {
+    for mnemonic in Mnemonic::values().filter(|mnemonic| *mnemonic != Mnemonic::INVALID) {
+        let opcode = X86Opcode::from_iced(mnemonic);
+        assert_ne!(opcode, X86Opcode::Invalid, "{:?}", mnemonic);
+        assert_eq!(
+            opcode.mnemonic(),
+            format!("{:?}", mnemonic).to_lowercase(),
+            "{:?}",
+            mnemonic
+        );
+    }
+    assert_eq!(X86Opcode::from_iced(Mnemonic::INVALID), X86Opcode::Invalid);
+}
+
+#[test]
+fn every_register_is_translated() {
+    for register in Register::values() {
+        let name = format!("{:?}", register);
+        // test registers and reserved values do not exist in 64-bit mode
+        if register == Register::None || name.starts_with("TR") || name.starts_with("DontUse") {
+            assert_eq!(X86Register::from_iced(register), None, "{}", name);
+            continue;
+        }
+        let translated = X86Register::from_iced(register);
+        assert_eq!(translated, X86Register::from_name(&name), "{}", name);
+        assert!(translated.is_some(), "{}", name);
+    }
+}
+
+#[test]
+fn structured_instructions() {
+    let decoder = InstructionDecoder::default();
+
+    // movabs rax, 0x1122334455667788
+    let bytes = [0x48, 0xb8, 0x88, 0x77, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11];
+    let instruction = decoder.decode(&bytes, 0x140001000).unwrap();
+    assert_eq!(instruction.opcode, X86Opcode::Movabs);
+    assert_eq!(
+        instruction.operands,
+        vec![
+            X86Operand::Register(X86Register::Rax),
+            X86Operand::Immediate(0x1122334455667788),
+        ]
+    );
+
+    // rep stosq
+    let instruction = decoder.decode(&[0xf3, 0x48, 0xab], 0x140001000).unwrap();
+    assert_eq!(instruction.opcode, X86Opcode::Stosq);
+    assert_eq!(instruction.prefixes, vec![X86Prefix::Rep]);
+
+    // vaddps ymm1, ymm2, ymmword ptr [rax + rcx*4 + 0x20]
+    let bytes = [0xc5, 0xec, 0x58, 0x4c, 0x88, 0x20];
+    let instruction = decoder.decode(&bytes, 0x140001000).unwrap();
+    assert_eq!(instruction.opcode, X86Opcode::Vaddps);
+    assert_eq!(
+        instruction.operands,
+        vec![
+            X86Operand::Register(X86Register::Ymm1),
+            X86Operand::Register(X86Register::Ymm2),
+            X86Operand::Memory(MemoryOperand {
+                index: Some(X86Register::Rcx),
+                scale: 4,
+                ..MemoryOperand::new(Some(X86Register::Rax), 0x20, 256)
+            }),
+        ]
+    );
+
+    // the bytes do not encode an instruction
+    assert!(decoder.decode(&[0x0f, 0xff], 0x140001000).is_none());
+}
@@ -0,0 +1,70 @@
+//! Export table of a synthetic DLL
+
+use goblin::pe::export::{ExportAddressTableEntry, ExportData, ExportDirectoryTable};
+use InsPEctor::pe::address::Address;
+use InsPEctor::pe::exports::Export;
+use InsPEctor::pe::virtual_image::{Permissions, VirtualImage, PAGE_SIZE};
+
+const IMAGE_BASE: Address = 0x180000000;
+
+/// Image whose first page holds the strings of the export table, each at its offset
+fn image(strings: &[(usize, &str)]) -> VirtualImage {
+    let mut bytes = vec![0; PAGE_SIZE as usize];
+    for (offset, string) in strings {
+        bytes[*offset..*offset + string.len()].copy_from_slice(string.as_bytes());
+    }
+    VirtualImage {
+        image_base: IMAGE_BASE,
+        permissions: vec![Permissions::default()],
+        bytes,
+    }
+}
+
+#[test]
+fn names_ordinals_and_forwarders() {
+    let image = image(&[
+        (0x100, "Open"),
+        (0x110, "OpenW"),
+        (0x120, "Close"),
+        (0x130, "NTDLL.RtlAllocateHeap"),
+        (0x150, "Alloc"),
+    ]);
+    // names are sorted, and two of them share the first ordinal
+    let export_data = ExportData {
+        name: Some("test.dll"),
+        export_directory_table: ExportDirectoryTable {
+            ordinal_base: 5,
+            ..Default::default()
+        },
+        export_name_pointer_table: vec![0x150, 0x120, 0x100, 0x110],
+        export_ordinal_table: vec![3, 1, 0, 0],
+        export_address_table: vec![
+            ExportAddressTableEntry::ExportRVA(0x1000),
+            ExportAddressTableEntry::ExportRVA(0x1010),
+            ExportAddressTableEntry::ExportRVA(0),
+            ExportAddressTableEntry::ForwarderRVA(0x130),
+            ExportAddressTableEntry::ExportRVA(0x1020),
+        ],
+    };
+
+    let exports = Export::parse(&export_data, &image);
+    let summary = exports
+        .iter()
+        .map(|export| (export.to_string(), export.aliases.clone(), export.ordinal))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        summary,
+        vec![
+            ("Open".to_string(), vec!["OpenW".to_string()], 5),
+            ("Close".to_string(), vec![], 6),
+            ("Alloc".to_string(), vec![], 8),
+            // exported only by ordinal
+            ("#9".to_string(), vec![], 9),
+        ]
+    );
+    assert_eq!(
+        exports[2].forwarder.as_deref(),
+        Some("NTDLL.RtlAllocateHeap")
+    );
+    assert!(!exports[0].is_forwarder());
+}
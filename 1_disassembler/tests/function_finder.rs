@@ -0,0 +1,137 @@
+//! Functions found from their prologue, in a synthetic image: every function of calc.exe is
+//! reached by the traversal
+
+mod common;
+
+use common::{synthetic_image, ENTRYPOINT};
+use InsPEctor::disassembler::decoder::InstructionDecoder;
+use InsPEctor::disassembler::disassemble::{
+    Disassembler, DisassemblerOptions, DisassemblerStrategy,
+};
+use InsPEctor::disassembler::disassembly::Disassembly;
+use InsPEctor::pe::address::Address;
+
+/// Map the code at the beginning of .text and disassemble from its first byte, looking for
+/// functions from their prologue
+fn disassemble(code: &[u8], strategy: DisassemblerStrategy) -> Disassembly {
+    Disassembler::extract_with_options(
+        synthetic_image("functions.exe", code, &[]),
+        strategy,
+        &InstructionDecoder::default(),
+        DisassemblerOptions {
+            find_functions: true,
+            ..Default::default()
+        },
+    )
+    .unwrap()
+}
+
+/// The entrypoint returns at once, the functions after it are never called
+fn unreached_functions() -> Vec<u8> {
+    let mut code = vec![0xc3]; // ret
+    code.resize(0x10, 0xcc);
+    code.extend([
+        0x55, // push rbp
+        0x48, 0x89, 0xe5, // mov rbp, rsp
+        0x5d, // pop rbp
+        0xc3, // ret
+    ]);
+    code.resize(0x23, 0xcc);
+    code.extend([
+        0x48, 0x83, 0xec, 0x28, // sub rsp, 0x28
+        0x48, 0x83, 0xc4, 0x28, // add rsp, 0x28
+        0xc3, // ret
+    ]);
+    code.resize(0x40, 0xcc);
+    code.extend([
+        0x48, 0x83, 0xec, 0x28, // sub rsp, 0x28
+        0x0f, 0x01, 0xf8, // swapgs: not user code
+        0xc3, // ret
+    ]);
+    code.resize(0x60, 0xcc);
+    code.extend([
+        0x48, 0x83, 0xec, 0x28, // sub rsp, 0x28
+        0x0f, 0xa2, // cpuid: a system instruction, but user code
+        0x48, 0x83, 0xc4, 0x28, // add rsp, 0x28
+        0xc3, // ret
+    ]);
+    code
+}
+
+#[test]
+fn prologues_start_functions() {
+    let disassembly = disassemble(
+        &unreached_functions(),
+        DisassemblerStrategy::RecursiveTraversal,
+    );
+
+    let confidence = |address: Address| disassembly.function_confidence.get(&address).copied();
+    // aligned, after padding: likely, but not proven like the code reached from the roots
+    assert!((confidence(0x140001010).unwrap() - 0.9).abs() < 1e-9);
+    // after padding, but not aligned
+    assert!((confidence(0x140001023).unwrap() - 0.75).abs() < 1e-9);
+    assert!((confidence(0x140001060).unwrap() - 0.9).abs() < 1e-9);
+    assert!(disassembly.functions.contains(&0x140001010));
+    assert!(disassembly.functions.contains(&0x140001023));
+    assert_eq!(disassembly.labels[&0x140001010], "sub_140001010");
+
+    // both functions are disassembled up to their ret
+    let addresses = disassembly
+        .instructions()
+        .map(|instruction| instruction.address)
+        .collect::<Vec<Address>>();
+    assert!(addresses.contains(&0x140001015));
+    assert!(addresses.contains(&0x14000102b));
+    assert!(addresses.contains(&0x140001064));
+}
+
+#[test]
+fn prologues_are_ignored_unless_asked() {
+    let disassembly = Disassembler::extract(
+        synthetic_image("functions.exe", &unreached_functions(), &[]),
+        DisassemblerStrategy::RecursiveTraversal,
+    )
+    .unwrap();
+
+    assert!(disassembly.function_confidence.is_empty());
+    assert_eq!(disassembly.functions.len(), 1);
+    assert_eq!(disassembly.instructions().count(), 1);
+}
+
+#[test]
+fn privileged_code_is_not_a_function() {
+    let disassembly = disassemble(
+        &unreached_functions(),
+        DisassemblerStrategy::RecursiveTraversal,
+    );
+
+    assert_eq!(disassembly.function_confidence.len(), 3);
+    assert!(!disassembly.functions.contains(&0x140001040));
+    assert!(!disassembly
+        .instructions()
+        .any(|instruction| (0x140001040..0x140001060).contains(&instruction.address)));
+}
+
+#[test]
+fn hybrid_finds_functions_before_scanning_gaps() {
+    let disassembly = disassemble(&unreached_functions(), DisassemblerStrategy::Hybrid);
+
+    // the entrypoint is a root, its confidence is not computed
+    assert!(disassembly.functions.contains(&ENTRYPOINT));
+    assert!(!disassembly.function_confidence.contains_key(&ENTRYPOINT));
+    assert!(disassembly.function_confidence.contains_key(&0x140001010));
+    assert!(disassembly.function_confidence.contains_key(&0x140001023));
+
+    // the code found from a prologue keeps the confidence of the function
+    let region = |start: Address| {
+        disassembly
+            .code_regions
+            .iter()
+            .find(|region| region.start == start)
+            .unwrap()
+    };
+    assert_eq!(region(ENTRYPOINT).confidence, 1.0);
+    assert_eq!(region(0x140001010).end, 0x140001016);
+    assert!((region(0x140001010).confidence - 0.9).abs() < 1e-9);
+    assert!((region(0x140001023).confidence - 0.75).abs() < 1e-9);
+}
@@ -0,0 +1,115 @@
+//! Hybrid strategy on `binaries/calc.exe`, and gap scan of a synthetic image
+
+mod common;
+
+use common::{calc, synthetic_image, ENTRYPOINT, IMAGE_BASE, RDATA};
+use InsPEctor::disassembler::decoder::InstructionDecoder;
+use InsPEctor::disassembler::disassemble::{Disassembler, DisassemblerStrategy};
+use InsPEctor::disassembler::disassembly::{CodeRegion, Disassembly};
+use InsPEctor::disassembler::hybrid::GapScanner;
+use InsPEctor::disassembler::pointer_scan::PointerScanner;
+use InsPEctor::disassembler::recursive_traversal::RecursiveTraversal;
+use InsPEctor::pe::address::Address;
+
+fn disassemble(strategy: DisassemblerStrategy) -> Disassembly {
+    let file_read = calc();
+    Disassembler::extract(file_read, strategy).unwrap()
+}
+
+fn addresses(disassembly: &Disassembly) -> Vec<Address> {
+    disassembly
+        .instructions()
+        .map(|instruction| instruction.address)
+        .collect()
+}
+
+#[test]
+fn gaps_add_functions_referenced_by_pointers() {
+    let recursive = disassemble(DisassemblerStrategy::RecursiveTraversal);
+    let hybrid = disassemble(DisassemblerStrategy::Hybrid);
+
+    // ret 0 (the no-op guard check) is only reached through a pointer
+    assert!(!addresses(&recursive).contains(&0x1400019c0));
+    assert!(addresses(&hybrid).contains(&0x1400019c0));
+    assert!(hybrid.functions.contains(&0x1400019c0));
+    assert!(hybrid.code_regions.contains(&CodeRegion {
+        start: 0x1400019c0,
+        end: 0x1400019c3,
+        confidence: 0.6,
+    }));
+
+    // the traversal results are kept as they are
+    let hybrid_addresses = addresses(&hybrid);
+    assert!(addresses(&recursive)
+        .iter()
+        .all(|address| hybrid_addresses.contains(address)));
+}
+
+#[test]
+fn regions_cover_every_instruction() {
+    let hybrid = disassemble(DisassemblerStrategy::Hybrid);
+
+    for instruction in hybrid.instructions() {
+        let region = hybrid
+            .code_regions
+            .iter()
+            .find(|region| region.start <= instruction.address && instruction.address < region.end)
+            .expect("every instruction is inside a region");
+        let expected = if instruction.address == 0x1400019c0 {
+            0.6
+        } else {
+            1.0
+        };
+        assert_eq!(region.confidence, expected);
+    }
+    // the entrypoint and the functions it calls were reached by the traversal
+    assert!(hybrid
+        .code_regions
+        .iter()
+        .any(|region| region.start == 0x140001740 && region.confidence == 1.0));
+}
+
+#[test]
+fn gap_scan_stays_below_proven_code() {
+    // ret, then a function reached only through a pointer in .rdata: it starts with a
+    // prologue and decodes up to a terminator
+    let mut code = vec![0xc3; 1];
+    code.resize(0x10, 0xcc);
+    code.extend([0x55, 0x48, 0x89, 0xe5, 0x5d, 0xc3]);
+    let candidate = ENTRYPOINT + 0x10;
+    let file_read = synthetic_image(
+        "gap.exe",
+        &code,
+        &[(RDATA, candidate.to_le_bytes().to_vec())],
+    );
+    let decoder = InstructionDecoder::default();
+    let references = PointerScanner::new(&file_read, &decoder).scan();
+    assert!(references
+        .iter()
+        .any(|reference| reference.location == IMAGE_BASE + RDATA as u64
+            && reference.target == candidate));
+
+    let mut traversal = RecursiveTraversal::new(&file_read, &decoder);
+    traversal.add_root(ENTRYPOINT);
+    traversal.run().unwrap();
+    let regions = GapScanner::new(&file_read, &decoder, &references)
+        .scan(&mut traversal, &[])
+        .unwrap();
+
+    // every heuristic matches, yet only the traversal from the roots proves code
+    assert_eq!(
+        regions,
+        vec![
+            CodeRegion {
+                start: ENTRYPOINT,
+                end: ENTRYPOINT + 1,
+                confidence: 1.0,
+            },
+            CodeRegion {
+                start: candidate,
+                end: candidate + 6,
+                confidence: 0.95,
+            },
+        ]
+    );
+}
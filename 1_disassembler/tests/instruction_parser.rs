@@ -0,0 +1,37 @@
+//! Assembly text front end: parsing of the text printed by LLVM
+
+use InsPEctor::disassembler::instruction_parser::InstructionParser;
+use InsPEctor::x86::memory::MemoryOperand;
+use InsPEctor::x86::opcode::X86Opcode;
+use InsPEctor::x86::operands::X86Operand;
+use InsPEctor::x86::register::X86Register;
+
+#[test]
+fn negative_displacement() {
+    let instruction =
+        InstructionParser::parse("mov rax, qword ptr [rbp - 0x10]", 0x140001000, 4).unwrap();
+    assert_eq!(instruction.opcode, X86Opcode::Mov);
+    let memory = instruction.memory_operand().unwrap();
+    assert_eq!(memory.displacement, -0x10);
+    assert_eq!(
+        memory,
+        &MemoryOperand::new(Some(X86Register::Rbp), -0x10, 64)
+    );
+    assert_eq!(
+        instruction.operands[0],
+        X86Operand::Register(X86Register::Rax)
+    );
+}
+
+#[cfg(feature = "nyxstone")]
+#[test]
+fn negative_displacement_from_llvm() {
+    use InsPEctor::disassembler::decoder::{DecoderBackend, InstructionDecoder};
+
+    // mov rax, qword ptr [rbp - 0x10]
+    let decoder = InstructionDecoder::new(DecoderBackend::Text).unwrap();
+    let instruction = decoder
+        .decode(&[0x48, 0x8b, 0x45, 0xf0], 0x140001000)
+        .unwrap();
+    assert_eq!(instruction.memory_operand().unwrap().displacement, -0x10);
+}
@@ -0,0 +1,116 @@
+//! Jump tables emitted by MSVC for `switch` statements, in a synthetic image: calc.exe has none
+
+mod common;
+
+use common::synthetic_image;
+use InsPEctor::cfg::edge::{Edge, EdgeKind};
+use InsPEctor::cfg::program::ProgramGraph;
+use InsPEctor::disassembler::disassemble::{Disassembler, DisassemblerStrategy};
+use InsPEctor::disassembler::disassembly::Disassembly;
+use InsPEctor::pe::address::Address;
+
+/// Map the code at the beginning of .text and the tables at their RVA, then disassemble from
+/// the first byte of .text
+fn disassemble(code: &[u8], tables: &[(u32, Vec<u8>)]) -> Disassembly {
+    let file_read = synthetic_image("switch.exe", code, tables);
+    Disassembler::extract(file_read, DisassemblerStrategy::RecursiveTraversal).unwrap()
+}
+
+/// Table of RVAs
+fn rvas(rvas: &[u32]) -> Vec<u8> {
+    rvas.iter().flat_map(|rva| rva.to_le_bytes()).collect()
+}
+
+/// `mov eax, value; ret` at the offset of .text
+fn case(code: &mut Vec<u8>, offset: usize, value: u8) {
+    code.resize(offset, 0xcc);
+    code.extend([0xb8, value, 0x00, 0x00, 0x00, 0xc3]);
+}
+
+#[test]
+fn dense_switch() {
+    let mut code = vec![
+        0x83, 0xf9, 0x03, // cmp ecx, 3
+        0x77, 0x1a, // ja 0x14000101f
+        0x48, 0x63, 0xc1, // movsxd rax, ecx
+        0x48, 0x8d, 0x15, 0xf1, 0xef, 0xff, 0xff, // lea rdx, [rip - 0x100f] (image base)
+        0x8b, 0x8c, 0x82, 0x00, 0x11, 0x00, 0x00, // mov ecx, dword ptr [rdx + rax*4 + 0x1100]
+        0x48, 0x03, 0xca, // add rcx, rdx
+        0xff, 0xe1, // jmp rcx
+        0xcc, 0xcc, 0xcc, 0xcc, //
+        0xc3, // ret
+    ];
+    case(&mut code, 0x40, 0);
+    case(&mut code, 0x50, 1);
+    case(&mut code, 0x60, 2);
+    // the entry after the last case is code too: only the bound stops the table
+    let table = rvas(&[0x1040, 0x1050, 0x1060, 0x1040, 0x1050]);
+    let disassembly = disassemble(&code, &[(0x1100, table)]);
+
+    let jump_table = &disassembly.jump_tables[&0x140001019];
+    assert_eq!(jump_table.table, 0x140001100);
+    assert_eq!(jump_table.entry_size, 4);
+    assert_eq!(jump_table.index_table, None);
+    assert_eq!(
+        jump_table.targets,
+        vec![0x140001040, 0x140001050, 0x140001060, 0x140001040]
+    );
+
+    // every case is disassembled and is a successor of the jump
+    let addresses = disassembly
+        .instructions()
+        .map(|instruction| instruction.address)
+        .collect::<Vec<Address>>();
+    for target in [0x140001040, 0x140001050, 0x140001060] {
+        assert!(addresses.contains(&target));
+        assert!(addresses.contains(&(target + 5)));
+    }
+
+    let program = ProgramGraph::build(&disassembly);
+    let function = program.function(0x140001000).unwrap();
+    let mut successors = function
+        .successors(0x140001005)
+        .copied()
+        .collect::<Vec<Edge>>();
+    successors.sort_by_key(|edge| edge.target);
+    assert_eq!(
+        successors,
+        vec![
+            Edge::new(0x140001005, 0x140001040, EdgeKind::JumpTableCase),
+            Edge::new(0x140001005, 0x140001050, EdgeKind::JumpTableCase),
+            Edge::new(0x140001005, 0x140001060, EdgeKind::JumpTableCase),
+        ]
+    );
+}
+
+#[test]
+fn sparse_switch_with_index_table() {
+    let mut code = vec![
+        0x83, 0xe9, 0x02, // sub ecx, 2
+        0x83, 0xf9, 0x09, // cmp ecx, 9
+        0x77, 0x1e, // ja 0x140001026
+        0x48, 0x63, 0xc1, // movsxd rax, ecx
+        0x48, 0x8d, 0x15, 0xee, 0xef, 0xff, 0xff, // lea rdx, [rip - 0x1012] (image base)
+        0x0f, 0xb6, 0x84, 0x02, 0x00, 0x12, 0x00,
+        0x00, // movzx eax, byte ptr [rdx + rax + 0x1200]
+        0x8b, 0x8c, 0x82, 0x00, 0x11, 0x00, 0x00, // mov ecx, dword ptr [rdx + rax*4 + 0x1100]
+        0x48, 0x03, 0xca, // add rcx, rdx
+        0xff, 0xe1, // jmp rcx
+        0xc3, // ret
+    ];
+    case(&mut code, 0x40, 0);
+    case(&mut code, 0x50, 1);
+    case(&mut code, 0x60, 2);
+    let index_table = vec![0, 2, 2, 2, 1, 2, 2, 2, 2, 0];
+    let table = rvas(&[0x1040, 0x1050, 0x1060, 0x1040]);
+    let disassembly = disassemble(&code, &[(0x1100, table), (0x1200, index_table)]);
+
+    let jump_table = &disassembly.jump_tables[&0x140001024];
+    assert_eq!(jump_table.table, 0x140001100);
+    assert_eq!(jump_table.index_table, Some(0x140001200));
+    // the index table only selects the first three entries
+    assert_eq!(
+        jump_table.targets,
+        vec![0x140001040, 0x140001050, 0x140001060]
+    );
+}
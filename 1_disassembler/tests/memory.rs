@@ -0,0 +1,79 @@
+//! Effective address of structured memory operands
+
+use InsPEctor::x86::memory::MemoryOperand;
+use InsPEctor::x86::register::X86Register;
+
+/// Values of the registers used by the operands
+fn registers(register: X86Register) -> Option<u64> {
+    match register {
+        X86Register::Rbx => Some(0x1000),
+        X86Register::Rcx => Some(3),
+        X86Register::Ebx => Some(0xffff_fff0),
+        X86Register::Gs => Some(0x7ff0_0000_0000),
+        _ => None,
+    }
+}
+
+#[test]
+fn negative_displacement() {
+    let operand = MemoryOperand {
+        index: Some(X86Register::Rcx),
+        scale: 8,
+        ..MemoryOperand::new(Some(X86Register::Rbx), -0x20, 64)
+    };
+    assert_eq!(
+        operand.effective_address(0x140001000, registers),
+        Some(0x1000 + 3 * 8 - 0x20)
+    );
+    assert_eq!(operand.to_string(), "qword ptr [rbx + rcx*8 - 0x20]");
+    // the value of a register is unknown
+    let operand = MemoryOperand::new(Some(X86Register::Rdx), -8, 64);
+    assert_eq!(operand.effective_address(0x140001000, registers), None);
+}
+
+#[test]
+fn rip_relative() {
+    let operand = MemoryOperand::new(Some(X86Register::Rip), -0x10, 32);
+    assert!(operand.rip_relative);
+    assert_eq!(operand.target(0x140001007), Some(0x140000ff7));
+    // other bases depend on registers: there is no target
+    assert_eq!(
+        MemoryOperand::new(Some(X86Register::Rbx), 0, 32).target(0x140001007),
+        None
+    );
+}
+
+#[test]
+fn address_size_override() {
+    // the sum wraps around at 32 bits
+    let operand = MemoryOperand::new(Some(X86Register::Ebx), 0x20, 32);
+    assert_eq!(
+        operand.effective_address(0x140001000, registers),
+        Some(0x10)
+    );
+}
+
+#[test]
+fn segment_base() {
+    let operand = MemoryOperand {
+        segment: Some(X86Register::Gs),
+        ..MemoryOperand::new(None, 0x60, 64)
+    };
+    assert_eq!(
+        operand.effective_address(0x140001000, registers),
+        Some(0x7ff0_0000_0060)
+    );
+    assert_eq!(operand.to_string(), "qword ptr gs:[0x60]");
+    // without the base of the segment, the address is unknown
+    assert_eq!(operand.target(0x140001000), None);
+
+    // other segments have a base of zero in 64-bit mode
+    let operand = MemoryOperand {
+        segment: Some(X86Register::Ds),
+        ..MemoryOperand::new(Some(X86Register::Rbx), 8, 64)
+    };
+    assert_eq!(
+        operand.effective_address(0x140001000, registers),
+        Some(0x1008)
+    );
+}
@@ -0,0 +1,61 @@
+//! Metadata of the opcode table
+
+use InsPEctor::x86::opcode::{OpcodeCategory, X86Flags, X86Flow, X86Opcode};
+
+#[test]
+fn mnemonics_round_trip() {
+    for opcode in [
+        X86Opcode::Adc,
+        X86Opcode::Cmovne,
+        X86Opcode::Movabs,
+        X86Opcode::Vaddph,
+        X86Opcode::ZeroBytes,
+    ] {
+        assert_eq!(X86Opcode::from_mnemonic(opcode.mnemonic()), opcode);
+    }
+    assert_eq!(X86Opcode::from_mnemonic("notanopcode"), X86Opcode::Invalid);
+}
+
+#[test]
+fn categories() {
+    assert_eq!(X86Opcode::Adc.category(), OpcodeCategory::General);
+    assert_eq!(X86Opcode::Movsb.category(), OpcodeCategory::String);
+    assert_eq!(X86Opcode::Fadd.category(), OpcodeCategory::X87);
+    assert_eq!(X86Opcode::Addps.category(), OpcodeCategory::Sse);
+    assert_eq!(X86Opcode::Vaddps.category(), OpcodeCategory::Avx);
+    assert_eq!(X86Opcode::Vaddph.category(), OpcodeCategory::Avx512);
+    assert_eq!(X86Opcode::Cpuid.category(), OpcodeCategory::System);
+}
+
+#[test]
+fn control_flow() {
+    assert!(X86Opcode::Jmp.is_branch());
+    assert!(X86Opcode::Jne.is_branch());
+    assert_eq!(X86Opcode::Jne.flow(), X86Flow::ConditionalJump);
+    assert!(X86Opcode::Call.is_call());
+    assert!(X86Opcode::Ret.is_return());
+    for opcode in [
+        X86Opcode::Int3,
+        X86Opcode::Int,
+        X86Opcode::Syscall,
+        X86Opcode::Ud2,
+    ] {
+        assert!(opcode.is_trap(), "{}", opcode);
+    }
+    assert_eq!(X86Opcode::Hlt.flow(), X86Flow::Halt);
+    assert_eq!(X86Opcode::Adc.flow(), X86Flow::Sequential);
+    assert!(!X86Opcode::Adc.is_branch() && !X86Opcode::Adc.is_trap());
+}
+
+#[test]
+fn flags() {
+    let adc = X86Opcode::Adc.info();
+    assert_eq!(adc.flags_read, X86Flags::CF);
+    assert!(adc
+        .flags_written
+        .contains(X86Flags(X86Flags::OF.0 | X86Flags::CF.0)));
+    assert!(X86Opcode::Jne.reads_flags() && !X86Opcode::Jne.writes_flags());
+    // string instructions move in the direction of DF
+    assert!(X86Opcode::Movsb.info().flags_read.contains(X86Flags::DF));
+    assert!(!X86Opcode::Addps.reads_flags() && !X86Opcode::Addps.writes_flags());
+}
@@ -0,0 +1,104 @@
+//! Pointers to code stored in data sections
+
+mod common;
+
+use common::{calc, synthetic_image, ENTRYPOINT, RDATA};
+use InsPEctor::disassembler::decoder::InstructionDecoder;
+use InsPEctor::disassembler::disassemble::{
+    Disassembler, DisassemblerOptions, DisassemblerStrategy,
+};
+use InsPEctor::disassembler::disassembly::Disassembly;
+use InsPEctor::disassembler::pointer_scan::DataReference;
+use InsPEctor::pe::file_read::FileRead;
+
+fn disassemble(file_read: FileRead, seed_data_pointers: bool) -> Disassembly {
+    Disassembler::extract_with_options(
+        file_read,
+        DisassemblerStrategy::RecursiveTraversal,
+        &InstructionDecoder::default(),
+        DisassemblerOptions {
+            seed_data_pointers,
+            scan_data_pointers: true,
+            ..Default::default()
+        },
+    )
+    .unwrap()
+}
+
+/// An image without relocations: the entrypoint returns at once, a function is only referenced
+/// from .rdata
+fn image_without_relocations() -> FileRead {
+    let mut code = vec![0xc3]; // ret
+    code.resize(0x10, 0xcc);
+    code.extend([0xb8, 0x01, 0x00, 0x00, 0x00, 0xc3]); // mov eax, 1; ret
+    let pointers = [
+        (0x08, ENTRYPOINT + 0x10), // the function
+        (0x10, ENTRYPOINT + 0x20), // int3 padding
+        (0x18, 0x1234),            // a constant
+    ]
+    .map(|(offset, value)| (RDATA + offset, value.to_le_bytes().to_vec()));
+    synthetic_image("pointers.exe", &code, &pointers)
+}
+
+#[test]
+fn relocated_pointers_are_data_references() {
+    let disassembly = disassemble(calc(), false);
+
+    // the guard check function pointer in .rdata
+    assert!(disassembly.data_references.contains(&DataReference {
+        location: 0x140002288,
+        target: 0x1400019c0,
+        relocated: true,
+    }));
+    assert!(disassembly
+        .data_references
+        .iter()
+        .all(|reference| reference.relocated
+            && (0x140001000..0x140001c40).contains(&reference.target)));
+    // the references are recorded, but the traversal does not start from them
+    assert!(!disassembly.functions.contains(&0x1400019c0));
+}
+
+#[test]
+fn pointers_are_scanned_on_demand() {
+    let disassembly =
+        Disassembler::extract(calc(), DisassemblerStrategy::RecursiveTraversal).unwrap();
+    assert!(disassembly.data_references.is_empty());
+
+    // the hybrid strategy needs them to score the gaps
+    let disassembly = Disassembler::extract(calc(), DisassemblerStrategy::Hybrid).unwrap();
+    assert!(disassembly
+        .data_references
+        .iter()
+        .any(|reference| reference.target == 0x1400019c0));
+}
+
+#[test]
+fn pointers_seed_the_traversal() {
+    let disassembly = disassemble(calc(), true);
+
+    assert!(disassembly.functions.contains(&0x1400019c0));
+    assert!(disassembly
+        .instructions()
+        .any(|instruction| instruction.address == 0x1400019c0));
+    assert_eq!(disassembly.labels[&0x1400019c0], "sub_1400019c0");
+}
+
+#[test]
+fn aligned_values_without_relocations() {
+    let disassembly = disassemble(image_without_relocations(), true);
+
+    // padding and constants are not code
+    assert_eq!(
+        disassembly.data_references,
+        vec![DataReference {
+            location: 0x140002008,
+            target: 0x140001010,
+            relocated: false,
+        }]
+    );
+    assert!(disassembly.functions.contains(&0x140001010));
+    assert!(disassembly
+        .instructions()
+        .any(|instruction| instruction.address == 0x140001015));
+}
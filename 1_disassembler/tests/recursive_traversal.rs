@@ -0,0 +1,119 @@
+//! Worklist of the recursive traversal and calls of functions, on synthetic images
+
+mod common;
+
+use common::{synthetic_image, ENTRYPOINT, IMAGE_BASE, RDATA};
+use InsPEctor::disassembler::decoder::InstructionDecoder;
+use InsPEctor::disassembler::recursive_traversal::RecursiveTraversal;
+use InsPEctor::pe::address::{Address, Rva};
+use InsPEctor::pe::imports::{Import, ImportName};
+use InsPEctor::x86::opcode::X86Opcode;
+
+/// Number of blocks of the chain: nearly the whole .text section
+const BLOCKS: usize = 1300;
+
+/// Every block is `jne` over a `ret` to the next block: each block is only reached through the
+/// branch of the previous one, so a traversal that recursed on branch targets would go as deep
+/// as the chain is long
+fn chain() -> Vec<u8> {
+    let mut code = [0x75, 0x01, 0xc3].repeat(BLOCKS); // jne +1; ret
+    code.push(0xc3); // ret
+    code
+}
+
+#[test]
+fn long_chain_of_branches() {
+    let file_read = synthetic_image("chain.exe", &chain(), &[]);
+    let decoder = InstructionDecoder::default();
+    let mut traversal = RecursiveTraversal::new(&file_read, &decoder);
+    traversal.add_root(ENTRYPOINT);
+
+    let disassembled = traversal.run().unwrap();
+    assert_eq!(disassembled.len(), 2 * BLOCKS + 1);
+    let instructions = traversal.instructions();
+    for block in 0..BLOCKS as Address {
+        assert_eq!(
+            instructions[&(ENTRYPOINT + block * 3)].opcode,
+            X86Opcode::Jne
+        );
+        assert_eq!(
+            instructions[&(ENTRYPOINT + block * 3 + 2)].opcode,
+            X86Opcode::Ret
+        );
+    }
+
+    // every address is visited once
+    assert!(traversal.run().unwrap().is_empty());
+    traversal.add_root(ENTRYPOINT + 3);
+    assert!(traversal.run().unwrap().is_empty());
+}
+
+#[test]
+fn deterministic_order() {
+    let file_read = synthetic_image("chain.exe", &chain(), &[]);
+    let decoder = InstructionDecoder::default();
+    let order = || {
+        let mut traversal = RecursiveTraversal::new(&file_read, &decoder);
+        traversal.add_root(ENTRYPOINT);
+        traversal.add_root(ENTRYPOINT + 3 * 100);
+        traversal.run().unwrap()
+    };
+
+    let first = order();
+    assert_eq!(first, order());
+    // roots are disassembled in the order they are added
+    assert_eq!(first[..2], [ENTRYPOINT, ENTRYPOINT + 2]);
+}
+
+#[test]
+fn calls_are_functions_and_exit_process_does_not_return() {
+    let slot = IMAGE_BASE + RDATA as Address + 0x10;
+    let mut code = vec![0xe8, 0x1b, 0x00, 0x00, 0x00]; // call 0x20
+    code.extend([0xff, 0x15]); // call qword ptr [rip + ...]: ExitProcess
+    code.extend(((slot - (ENTRYPOINT + 0xb)) as u32).to_le_bytes());
+    code.extend([0x90, 0xc3]); // nop; ret: never executed
+    code.resize(0x20, 0xcc);
+    code.extend([0x31, 0xc0, 0xc3]); // xor eax, eax; ret
+    let mut file_read = synthetic_image("exit.exe", &code, &[]);
+    file_read.imports.push(Import {
+        dll: "KERNEL32.dll".to_string(),
+        name: ImportName::Name("ExitProcess".to_string()),
+        iat_slot: Rva(RDATA + 0x10),
+        hint: 0,
+    });
+
+    let decoder = InstructionDecoder::default();
+    let mut traversal = RecursiveTraversal::new(&file_read, &decoder);
+    traversal.add_function(ENTRYPOINT);
+    traversal.run().unwrap();
+
+    // the callee is a function, and the caller continues after it returns
+    assert!(traversal.functions().contains(&(ENTRYPOINT + 0x20)));
+    assert!(traversal.is_visited(ENTRYPOINT + 0x22));
+    assert!(traversal.is_visited(ENTRYPOINT + 5));
+    // nothing executes after ExitProcess
+    assert!(traversal.no_return_calls().contains(&(ENTRYPOINT + 5)));
+    assert!(!traversal.is_visited(ENTRYPOINT + 0xb));
+}
+
+#[test]
+fn fast_fail_wrappers_do_not_return() {
+    let mut code = vec![0xe8, 0x1b, 0x00, 0x00, 0x00]; // call 0x20
+    code.extend([0x90, 0xc3]); // nop; ret: never executed
+    code.resize(0x20, 0xcc);
+    code.extend([0xb9, 0x07, 0x00, 0x00, 0x00, 0xcd, 0x29]); // mov ecx, 7; int 0x29
+    let file_read = synthetic_image("fastfail.exe", &code, &[]);
+
+    let decoder = InstructionDecoder::default();
+    let mut traversal = RecursiveTraversal::new(&file_read, &decoder);
+    traversal.add_function(ENTRYPOINT);
+    traversal.run().unwrap();
+
+    assert_eq!(
+        traversal.functions().iter().copied().collect::<Vec<_>>(),
+        vec![ENTRYPOINT, ENTRYPOINT + 0x20]
+    );
+    assert!(traversal.no_return_calls().contains(&ENTRYPOINT));
+    assert!(!traversal.is_visited(ENTRYPOINT + 5));
+    assert!(traversal.is_visited(ENTRYPOINT + 0x25));
+}
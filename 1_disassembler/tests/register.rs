@@ -0,0 +1,54 @@
+//! Sub-register aliasing of the typed register model
+
+use InsPEctor::x86::register::{RegisterClass, X86Register};
+
+#[test]
+fn general_purpose_aliasing() {
+    for (register, width) in [
+        (X86Register::Al, 8),
+        (X86Register::Ah, 8),
+        (X86Register::Ax, 16),
+        (X86Register::Eax, 32),
+        (X86Register::Rax, 64),
+    ] {
+        assert_eq!(register.width(), width);
+        assert_eq!(register.parent(), X86Register::Rax);
+        assert_eq!(register.class(), RegisterClass::Gpr);
+        assert!(register.overlaps(X86Register::Rax));
+    }
+
+    for (register, width) in [
+        (X86Register::R8b, 8),
+        (X86Register::R8w, 16),
+        (X86Register::R8d, 32),
+        (X86Register::R8, 64),
+    ] {
+        assert_eq!(register.width(), width);
+        assert_eq!(register.parent(), X86Register::R8);
+        assert!(!register.overlaps(X86Register::Rax));
+    }
+
+    assert_eq!(X86Register::Spl.parent(), X86Register::Rsp);
+    // the high and low bytes are separate parts of rax
+    assert_eq!(X86Register::Ah.offset(), 8);
+    assert!(!X86Register::Ah.overlaps(X86Register::Al));
+    assert!(X86Register::Ah.overlaps(X86Register::Ax));
+    assert!(X86Register::Al.overlaps(X86Register::Eax));
+    assert!(X86Register::Xmm1.overlaps(X86Register::Zmm1));
+    assert!(!X86Register::Al.overlaps(X86Register::Cl));
+}
+
+#[test]
+fn names_and_encoding_numbers() {
+    assert_eq!(X86Register::from_name("EAX"), Some(X86Register::Eax));
+    // other disassemblers spell the low byte of r8 as r8l
+    assert_eq!(X86Register::from_name("r8l"), Some(X86Register::R8b));
+    assert_eq!(X86Register::from_name("st(1)"), Some(X86Register::St1));
+    assert_eq!(X86Register::from_name("foo"), None);
+
+    assert_eq!(X86Register::gpr64(0), X86Register::Rax);
+    assert_eq!(X86Register::gpr64(12), X86Register::R12);
+    assert_eq!(X86Register::xmm(3), X86Register::Xmm3);
+    assert_eq!(X86Register::Xmm3.class(), RegisterClass::Vector);
+    assert_eq!(X86Register::Fs.parent(), X86Register::Fs);
+}
@@ -0,0 +1,141 @@
+//! Base relocation directory built by hand
+
+use std::collections::BTreeMap;
+use InsPEctor::pe::address::{Address, Rva};
+use InsPEctor::pe::relocations::{RelocationType, Relocations};
+use InsPEctor::pe::virtual_image::{Permissions, VirtualImage, PAGE_SIZE};
+
+const IMAGE_BASE: Address = 0x140000000;
+const DIRECTORY: u32 = 0x2000;
+
+/// Image of three pages with the directory at the beginning of the last one
+fn image(directory: &[u8]) -> VirtualImage {
+    let mut bytes = vec![0; 0x3000];
+    bytes[DIRECTORY as usize..DIRECTORY as usize + directory.len()].copy_from_slice(directory);
+    VirtualImage {
+        image_base: IMAGE_BASE,
+        permissions: vec![Permissions::default(); bytes.len() / PAGE_SIZE as usize],
+        bytes,
+    }
+}
+
+/// Block of relocations for a page
+fn block(page_rva: u32, entries: &[u16]) -> Vec<u8> {
+    let size = 8 + entries.len() as u32 * 2;
+    let mut block = [page_rva.to_le_bytes(), size.to_le_bytes()].concat();
+    block.extend(entries.iter().flat_map(|entry| entry.to_le_bytes()));
+    block
+}
+
+#[test]
+fn parse() {
+    let directory = [
+        block(
+            0x1000,
+            &[
+                0xa010, // DIR64 at 0x1010
+                0x3020, // HIGHLOW at 0x1020
+                0x4030, // HIGHADJ at 0x1030...
+                0xa040, // ...whose parameter is not a relocation
+                0x0000, // padding
+                0xa050, // DIR64 at 0x1050
+            ],
+        ),
+        // the location overflows the address space
+        block(0xffff_f800, &[0xafff]),
+        // a block smaller than its header stops the parsing
+        [0x00, 0x20, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00].to_vec(),
+        block(0x2000, &[0xa000]),
+    ]
+    .concat();
+    let image = image(&directory);
+    let relocations = Relocations::parse(&image, Rva(DIRECTORY), directory.len() as u32);
+
+    assert_eq!(
+        relocations.entries.into_iter().collect::<Vec<_>>(),
+        vec![
+            (Rva(0x1010), RelocationType::Dir64),
+            (Rva(0x1020), RelocationType::HighLow),
+            (Rva(0x1030), RelocationType::HighAdj(0xa040)),
+            (Rva(0x1050), RelocationType::Dir64),
+        ]
+    );
+}
+
+#[test]
+fn parse_truncated_directory() {
+    // the block claims more entries than the image holds
+    let directory = [0x00, 0x10, 0x00, 0x00, 0xff, 0xff, 0xff, 0x00];
+    let mut image = image(&directory);
+    image.bytes.truncate(DIRECTORY as usize + directory.len());
+
+    let relocations = Relocations::parse(&image, Rva(DIRECTORY), 0x1000);
+    assert!(relocations.is_empty());
+}
+
+#[test]
+fn rebase() {
+    let directory = block(0x1000, &[0xa010, 0x3020, 0x0000]);
+    let mut image = image(&directory);
+    image.bytes[0x1010..0x1018].copy_from_slice(&0x140001000u64.to_le_bytes());
+    image.bytes[0x1020..0x1024].copy_from_slice(&0x40001800u32.to_le_bytes());
+
+    let relocations = Relocations::parse(&image, Rva(DIRECTORY), directory.len() as u32);
+    assert_eq!(
+        relocations
+            .in_range(Rva(0x1000), 0x1000)
+            .collect::<Vec<_>>(),
+        vec![
+            (Rva(0x1010), RelocationType::Dir64),
+            (Rva(0x1020), RelocationType::HighLow),
+        ]
+    );
+
+    image.rebase(0x150000000, &relocations).unwrap();
+    assert_eq!(image.image_base, 0x150000000);
+    assert_eq!(image.read_u64(0x150001010).unwrap(), 0x150001000);
+    assert_eq!(image.read_u32(0x150001020).unwrap(), 0x50001800);
+}
+
+#[test]
+fn rebase_high_adj_and_unsupported_types() {
+    let directory = block(
+        0x1000,
+        &[
+            0x4030, // HIGHADJ at 0x1030...
+            0x9000, // ...with -0x7000 as low 16 bits
+            0x5040, // architecture specific type at 0x1040
+        ],
+    );
+    let mut image = image(&directory);
+    image.bytes[0x1030..0x1032].copy_from_slice(&0x1234u16.to_le_bytes());
+    image.bytes[0x1040..0x1044].copy_from_slice(&0x1234u32.to_le_bytes());
+
+    let relocations = Relocations::parse(&image, Rva(DIRECTORY), directory.len() as u32);
+    assert_eq!(
+        relocations.entries.values().collect::<Vec<_>>(),
+        vec![&RelocationType::HighAdj(0x9000), &RelocationType::Other(5)]
+    );
+
+    image.rebase(0x150000000, &relocations).unwrap();
+    // 0x12339000 + 0x10000000, rounded to its high 16 bits
+    assert_eq!(image.read_u16(0x150001030).unwrap(), 0x2234);
+    assert_eq!(image.read_u32(0x150001040).unwrap(), 0x1234);
+}
+
+#[test]
+fn rebase_fails_before_patching() {
+    let mut image = image(&[]);
+    image.bytes[0x1010..0x1018].copy_from_slice(&0x140001000u64.to_le_bytes());
+    // the second relocation crosses the end of the image
+    let relocations = Relocations {
+        entries: BTreeMap::from([
+            (Rva(0x1010), RelocationType::Dir64),
+            (Rva(0x2ffc), RelocationType::Dir64),
+        ]),
+    };
+
+    assert!(image.rebase(0x150000000, &relocations).is_err());
+    assert_eq!(image.image_base, IMAGE_BASE);
+    assert_eq!(image.read_u64(0x140001010).unwrap(), 0x140001000);
+}
@@ -0,0 +1,92 @@
+//! Superset strategy on `binaries/calc.exe`, and on a synthetic image for the instructions
+//! calc.exe does not contain
+
+mod common;
+
+use common::{calc, synthetic_image, ENTRYPOINT};
+use InsPEctor::disassembler::disassemble::{Disassembler, DisassemblerStrategy};
+use InsPEctor::disassembler::disassembly::Disassembly;
+
+fn disassemble(strategy: DisassemblerStrategy) -> Disassembly {
+    let file_read = calc();
+    Disassembler::extract(file_read, strategy).unwrap()
+}
+
+#[test]
+fn every_byte_has_a_probability() {
+    let superset = disassemble(DisassemblerStrategy::Superset);
+
+    for region in &superset.regions {
+        assert_eq!(region.probabilities.len(), region.region.size);
+        assert!(region
+            .probabilities
+            .iter()
+            .all(|probability| (0.0..=1.0).contains(probability)));
+    }
+    // the other strategies do not compute probabilities
+    let recursive = disassemble(DisassemblerStrategy::RecursiveTraversal);
+    assert_eq!(recursive.instruction_probability(0x140001740), None);
+}
+
+#[test]
+fn traversal_instructions_are_confirmed() {
+    let recursive = disassemble(DisassemblerStrategy::RecursiveTraversal);
+    let superset = disassemble(DisassemblerStrategy::Superset);
+
+    for instruction in recursive.instructions() {
+        assert_eq!(
+            superset.instruction_probability(instruction.address),
+            Some(1.0)
+        );
+        // the bytes inside a confirmed instruction are unlikely starts
+        for byte in instruction.address + 1..instruction.next_address() {
+            assert!(superset.instruction_probability(byte).unwrap() < 0.5);
+        }
+    }
+
+    let comparison = superset.compare(&recursive);
+    assert!(comparison.only_second.is_empty());
+    assert_eq!(comparison.common.len(), 651);
+}
+
+#[test]
+fn unreached_code_is_likely() {
+    let superset = disassemble(DisassemblerStrategy::Superset);
+
+    // mov dword ptr [rip + 0x1edd], eax after a jmp: no branch reaches it
+    assert!(superset.instruction_probability(0x1400016ed).unwrap() >= 0.5);
+    // ret 0 only referenced by a pointer
+    assert!(superset.instruction_probability(0x1400019c0).unwrap() >= 0.5);
+    // int3 padding before a function
+    assert!(superset.instruction_probability(0x140001097).unwrap() < 0.5);
+    assert_eq!(superset.instruction_probability(0x140001098), Some(1.0));
+
+    // a linear sweep agrees with most of the superset
+    let linear = disassemble(DisassemblerStrategy::LinearSweep);
+    let comparison = superset.compare(&linear);
+    assert!(comparison.common.len() > comparison.only_first.len());
+    assert!(comparison.common.len() > comparison.only_second.len());
+}
+
+#[test]
+fn system_instructions_reached_by_the_traversal_are_kept() {
+    let code = [
+        0x31, 0xc0, // xor eax, eax
+        0x0f, 0xa2, // cpuid
+        0x0f, 0x31, // rdtsc
+        0xc3, // ret
+        0xcc, // int3
+        0xfa, // cli: never reached, and not user code
+        0xc3, // ret
+    ];
+    let file_read = synthetic_image("cpuid.exe", &code, &[]);
+    let superset = Disassembler::extract(file_read, DisassemblerStrategy::Superset).unwrap();
+
+    for offset in [0, 2, 4, 6] {
+        assert_eq!(
+            superset.instruction_probability(ENTRYPOINT + offset),
+            Some(1.0)
+        );
+    }
+    assert_eq!(superset.instruction_probability(ENTRYPOINT + 8), Some(0.0));
+}
@@ -0,0 +1,95 @@
+//! Mapping of a file as the loader does it, in a synthetic file
+
+use InsPEctor::pe::address::{Address, FileOffset, Rva};
+use InsPEctor::pe::binary_parser::{
+    IMAGE_SCN_MEM_EXECUTE, IMAGE_SCN_MEM_READ, IMAGE_SCN_MEM_WRITE,
+};
+use InsPEctor::pe::section::Section;
+use InsPEctor::pe::virtual_image::{Permissions, VirtualImage, PAGE_SIZE};
+
+const IMAGE_BASE: Address = 0x140000000;
+
+/// Headers in the first 0x200 bytes, .text with 0x200 bytes in the file but 0x1800 in memory,
+/// then .data. Every byte of the file is 0xaa.
+fn map() -> VirtualImage {
+    let file = vec![0xaa; 0x600];
+    let sections = vec![
+        Section {
+            name: ".text".to_string(),
+            virtual_address: Rva(0x1000),
+            virtual_size: 0x1800,
+            pointer_to_raw_data: FileOffset(0x200),
+            size_of_raw_data: 0x200,
+            characteristics: IMAGE_SCN_MEM_READ | IMAGE_SCN_MEM_EXECUTE,
+        },
+        Section {
+            name: ".data".to_string(),
+            virtual_address: Rva(0x3000),
+            virtual_size: 0x100,
+            pointer_to_raw_data: FileOffset(0x400),
+            size_of_raw_data: 0x200,
+            characteristics: IMAGE_SCN_MEM_READ | IMAGE_SCN_MEM_WRITE,
+        },
+    ];
+    VirtualImage::new(&file, IMAGE_BASE, 0x3100, 0x200, &sections).unwrap()
+}
+
+#[test]
+fn zero_fill_past_raw_data() {
+    let image = map();
+    assert_eq!(image.size(), 0x4000);
+
+    assert_eq!(
+        image.read(IMAGE_BASE + 0x1000, 0x200).unwrap(),
+        [0xaa; 0x200]
+    );
+    // the rest of .text is not backed by the file
+    assert_eq!(image.read(IMAGE_BASE + 0x1200, 0x10).unwrap(), [0; 0x10]);
+    assert_eq!(image.read_u64(IMAGE_BASE + 0x27f8).unwrap(), 0);
+    // only the virtual size of .data is mapped, even if the file has more
+    assert_eq!(
+        image.read(IMAGE_BASE + 0x3000, 0x100).unwrap(),
+        [0xaa; 0x100]
+    );
+    assert_eq!(image.read_u32(IMAGE_BASE + 0x3100).unwrap(), 0);
+    // gap between the headers and .text
+    assert_eq!(image.read_u32(IMAGE_BASE + 0x200).unwrap(), 0);
+
+    assert!(!image.contains(IMAGE_BASE - 1));
+    assert!(!image.contains(IMAGE_BASE + 0x4000));
+    assert!(image.read(IMAGE_BASE + 0x3ff8, 0x10).is_err());
+    assert_eq!(
+        image.read_bounded(IMAGE_BASE + 0x3ff8, 0x10).unwrap().len(),
+        8
+    );
+}
+
+#[test]
+fn page_permissions() {
+    let image = map();
+    let read_only = Permissions {
+        read: true,
+        write: false,
+        execute: false,
+    };
+    let code = Permissions {
+        read: true,
+        write: false,
+        execute: true,
+    };
+    let data = Permissions {
+        read: true,
+        write: true,
+        execute: false,
+    };
+
+    assert_eq!(image.permissions(IMAGE_BASE), Some(read_only));
+    // every page of .text, including the ones that are not backed by the file
+    assert_eq!(image.permissions(IMAGE_BASE + 0x1000), Some(code));
+    assert_eq!(image.permissions(IMAGE_BASE + 0x27ff), Some(code));
+    assert_eq!(
+        image.permissions(IMAGE_BASE + 0x3000 + PAGE_SIZE - 1),
+        Some(data)
+    );
+    assert_eq!(image.permissions(IMAGE_BASE + 0x4000), None);
+}
@@ -0,0 +1,191 @@
+//! Cross-references of `binaries/calc.exe`, and of a synthetic image for the instructions
+//! calc.exe does not contain
+
+mod common;
+
+use common::{calc, synthetic_image, ENTRYPOINT, IMAGE_BASE, RDATA};
+use InsPEctor::disassembler::decoder::InstructionDecoder;
+use InsPEctor::disassembler::disassemble::{
+    Disassembler, DisassemblerOptions, DisassemblerStrategy,
+};
+use InsPEctor::disassembler::disassembly::Disassembly;
+use InsPEctor::disassembler::xrefs::{self, Xref, XrefKind};
+use InsPEctor::pe::address::Address;
+use InsPEctor::pe::address::Rva;
+use InsPEctor::pe::exports::Export;
+
+fn disassemble() -> Disassembly {
+    let file_read = calc();
+    // the pointers stored in data sections are recorded for their cross-references
+    let options = DisassemblerOptions {
+        scan_data_pointers: true,
+        ..Default::default()
+    };
+    Disassembler::extract_with_options(
+        file_read,
+        DisassemblerStrategy::RecursiveTraversal,
+        &InstructionDecoder::default(),
+        options,
+    )
+    .unwrap()
+}
+
+#[test]
+fn code_to_code() {
+    let disassembly = disassemble();
+
+    let callers = disassembly
+        .xrefs
+        .callers(0x1400019e4)
+        .collect::<Vec<Address>>();
+    assert_eq!(callers, vec![0x140001698]);
+    // the entrypoint calls a function and tail calls another one
+    assert_eq!(
+        disassembly.xrefs.to(0x1400014c0),
+        &[Xref {
+            source: 0x14000174d,
+            target: 0x1400014c0,
+            kind: XrefKind::Jump,
+        }]
+    );
+    assert!(disassembly
+        .xrefs
+        .from(0x140001744)
+        .iter()
+        .any(|xref| xref.target == 0x140001164 && xref.kind == XrefKind::Call));
+}
+
+#[test]
+fn code_to_data() {
+    let disassembly = disassemble();
+
+    // a global written then read by the same function
+    let writers = disassembly
+        .xrefs
+        .writers(0x1400035d0)
+        .collect::<Vec<Address>>();
+    let readers = disassembly
+        .xrefs
+        .readers(0x1400035d0)
+        .collect::<Vec<Address>>();
+    assert_eq!(writers, vec![0x14000169d]);
+    assert_eq!(readers, vec![0x1400016c3]);
+    // lock cmpxchg reads the lock before it writes it
+    assert_eq!(
+        disassembly.xrefs.from(0x140001501),
+        &[
+            Xref {
+                source: 0x140001501,
+                target: 0x140003620,
+                kind: XrefKind::Read,
+            },
+            Xref {
+                source: 0x140001501,
+                target: 0x140003620,
+                kind: XrefKind::Write,
+            },
+        ]
+    );
+    // lea rcx, [rip + 0x12dc]
+    assert_eq!(
+        disassembly.xrefs.to(0x1400023c0),
+        &[Xref {
+            source: 0x1400010dd,
+            target: 0x1400023c0,
+            kind: XrefKind::AddressTaken,
+        }]
+    );
+}
+
+#[test]
+fn data_to_code() {
+    let disassembly = disassemble();
+
+    let sources = disassembly
+        .xrefs
+        .to(0x1400019c0)
+        .iter()
+        .filter(|xref| xref.kind == XrefKind::DataPointer)
+        .map(|xref| xref.source)
+        .collect::<Vec<Address>>();
+    assert_eq!(sources, vec![0x140002288, 0x140002298]);
+}
+
+#[test]
+fn symbols_are_resolved() {
+    let disassembly = disassemble();
+
+    // imported functions are called through their IAT slot
+    let slot = xrefs::resolve(&disassembly, "ShellExecuteW").unwrap();
+    assert_eq!(
+        xrefs::resolve(&disassembly, "SHELL32.dll!ShellExecuteW"),
+        Some(slot)
+    );
+    let callers = disassembly.xrefs.callers(slot).collect::<Vec<Address>>();
+    assert_eq!(callers, vec![0x140001b01]);
+
+    assert_eq!(
+        xrefs::resolve(&disassembly, "sub_1400019e4"),
+        Some(0x1400019e4)
+    );
+    assert_eq!(
+        xrefs::resolve(&disassembly, "0x1400019c0"),
+        Some(0x1400019c0)
+    );
+    assert_eq!(xrefs::resolve(&disassembly, "1400019c0"), Some(0x1400019c0));
+    assert_eq!(xrefs::resolve(&disassembly, "NotASymbol"), None);
+
+    // no instruction references _exit, its IAT slot is still known
+    assert!(disassembly.xrefs.to(0x140002278).is_empty());
+    assert_eq!(xrefs::resolve(&disassembly, "_exit"), Some(0x140002278));
+}
+
+#[test]
+fn exports_are_resolved_first() {
+    let mut disassembly = Disassembly::default();
+    disassembly.exports.insert(
+        0x140001100,
+        Export {
+            name: Some("sub_140001000".to_string()),
+            aliases: vec![],
+            ordinal: 1,
+            rva: Rva(0x1100),
+            forwarder: None,
+        },
+    );
+    disassembly
+        .labels
+        .insert(0x140001000, "sub_140001000".to_string());
+
+    assert_eq!(
+        xrefs::resolve(&disassembly, "sub_140001000"),
+        Some(0x140001100)
+    );
+}
+
+#[test]
+fn read_modify_write() {
+    let target = IMAGE_BASE + RDATA as Address;
+    // every instruction is 6 bytes long and addresses the beginning of .rdata
+    let mut code = vec![];
+    for opcode in [
+        [0x01, 0x05], // add dword ptr [rip + ...], eax
+        [0xff, 0x05], // inc dword ptr [rip + ...]
+        [0x89, 0x05], // mov dword ptr [rip + ...], eax
+        [0x39, 0x05], // cmp dword ptr [rip + ...], eax
+    ] {
+        let next = ENTRYPOINT + code.len() as Address + 6;
+        code.extend(opcode);
+        code.extend(((target - next) as u32).to_le_bytes());
+    }
+    code.push(0xc3); // ret
+    let file_read = synthetic_image("xrefs.exe", &code, &[]);
+    let disassembly =
+        Disassembler::extract(file_read, DisassemblerStrategy::RecursiveTraversal).unwrap();
+
+    let writers = disassembly.xrefs.writers(target).collect::<Vec<Address>>();
+    let readers = disassembly.xrefs.readers(target).collect::<Vec<Address>>();
+    assert_eq!(writers, vec![ENTRYPOINT, ENTRYPOINT + 6, ENTRYPOINT + 12]);
+    assert_eq!(readers, vec![ENTRYPOINT, ENTRYPOINT + 6, ENTRYPOINT + 18]);
+    assert_eq!(disassembly.xrefs.from(ENTRYPOINT).len(), 2);
+}